@@ -6,17 +6,48 @@
 //! * When project admin marks the OVERLAY user as validator, then its project id is stored in the user state.
 
 #![cfg_attr(all(not(feature = "std"), not(test)), no_std)]
-use concordium_std::*;
+use concordium_std::{
+    collections::{BTreeMap, BTreeSet},
+    *,
+};
 use core::fmt::Debug;
 
 type ProjectId = String;
 
+/// Maximum length in bytes of the reason attached to a role removal.
+const MAX_REASON_LEN: usize = 256;
+
+/// Maximum number of tags a single user can hold.
+const MAX_TAGS_PER_USER: usize = 10;
+
+/// Maximum length in bytes of a single tag.
+const MAX_TAG_LEN: usize = 32;
+
+/// Maximum number of curators imported by a single `import_curators` call.
+const MAX_IMPORT_CURATORS: usize = 50;
+
+/// Maximum number of user-project pairs curated by a single `curate_bulk` call.
+const MAX_CURATE_BULK: usize = 50;
+
+/// Maximum number of users stored by `snapshot_users`.
+const MAX_SNAPSHOT_USERS: usize = 100;
+
+/// Maximum length in bytes of the prefix searched by `curators_of_project_prefix`.
+const MAX_PROJECT_PREFIX_LEN: usize = 64;
+
+/// Maximum quality score attached to a curation.
+const MAX_CURATION_SCORE: u8 = 100;
+
+/// Maximum number of pending applications for each role.
+const MAX_PENDING_APPLICATIONS: usize = 1000;
+
 /// The state of the OVERLAY users
 #[derive(Serial, DeserialWithState, StateClone)]
 #[concordium(state_parameter = "S")]
 struct State<S> {
     /// Owner/Admin address of this contract module.
-    admin: AccountAddress,
+    /// This could be either an account or a contract (e.g. a multisig or DAO contract).
+    admin: Address,
     /// overlay-projects contract address that will control curator / validator data.
     project_contract_addr: ContractAddress,
     /// OVERLAY user data map.
@@ -25,11 +56,134 @@ struct State<S> {
     curator_list: Vec<AccountAddress>,
     /// All validator account addresses.
     validator_list: Vec<AccountAddress>,
+    /// If true, a project id can not be both curated and validated by the same user.
+    disjoint_project_roles: bool,
+    /// Time of the last admin transfer and the admin address it replaced.
+    last_admin_change: Option<(Timestamp, Address)>,
+    /// If true, the overlay-projects contract address can not be changed anymore.
+    config_locked: bool,
+    /// If true, a user without any roles and projects is removed from the state on role removal.
+    auto_prune_on_remove: bool,
+    /// Template of the user data inserted when a new user is added to the state.
+    default_user_state: UserState,
+    /// If true, `curate` and `validate` reject the admin account as a target user.
+    reject_privileged_targets: bool,
+    /// Maximum number of projects a user can curate and validate in total, unless overridden per user.
+    max_projects_per_user: Option<u32>,
+    /// If true, curators can not be added to or removed from the curator set anymore.
+    curators_frozen: bool,
+    /// If true, `add_curator` and `add_validator` only update users registered by `register_user`.
+    require_known_users: bool,
+    /// User and list counts updated by every mutation for cheap reads, repaired by `recompute_counts`.
+    cached_counts: CachedCounts,
+    /// If false, `view_users` and `view_users_after` can only be called by the admin.
+    users_view_public: bool,
+    /// If false, the contract functions do not log events.
+    events_enabled: bool,
+    /// Project ids that can be curated and validated. If empty, all the project ids are allowed.
+    allowed_projects: StateSet<ProjectId, S>,
+    /// Sequence number incremented by every successful call that changes the state. Calls that leave
+    /// the state unchanged apart from `event_seq` and the admin activity records do not increment it.
+    state_seq: u64,
+    /// If true, `curate` registers an unknown user as a curator instead of rejecting it.
+    curate_auto_create: bool,
+    /// Minimum time between two role changes of the same user. It applies to every function that
+    /// changes the roles: `add_curator`, `remove_curator`, `add_validator`, `remove_validator`,
+    /// `switch_role`, `set_user_roles_batch`, `import_curators` and `force_curate`.
+    /// Calls that leave the roles of the user unchanged are not subject to it.
+    role_cooldown: Duration,
+    /// Accounts that applied for the curator role with `apply_for_role` and are not granted yet.
+    pending_curators: StateSet<AccountAddress, S>,
+    /// Accounts that applied for the validator role with `apply_for_role` and are not granted yet.
+    pending_validators: StateSet<AccountAddress, S>,
+    /// Number of entries in `pending_curators`.
+    pending_curator_count: u32,
+    /// Number of entries in `pending_validators`.
+    pending_validator_count: u32,
+    /// Account that can take over the admin role with `inactive_admin_takeover`.
+    recovery_account: Option<AccountAddress>,
+    /// Time without admin activity after which the recovery account can take over the admin role.
+    admin_inactivity_timeout: Duration,
+    /// Time of the last admin-gated call that updated the state. Only recorded while a recovery account is set.
+    last_admin_activity: Option<Timestamp>,
+    /// Accounts that can read the admin state with `view_admin` without being the admin.
+    observers: StateSet<AccountAddress, S>,
+    /// Weight of each curated project in the score returned by `view_user_score`.
+    curator_weight: u64,
+    /// Weight of each validated project in the score returned by `view_user_score`.
+    validator_weight: u64,
+    /// Copy of the user state taken by the last `snapshot_users` call.
+    last_snapshot: StateMap<AccountAddress, UserState, S>,
+    /// Time of the last `snapshot_users` call, or `None` if no snapshot has been taken yet.
+    last_snapshot_time: Option<Timestamp>,
+    /// Number of the admin-gated calls for each function name.
+    admin_action_counts: StateMap<String, u64, S>,
+    /// If true, `remove_curator` and `remove_validator` reject users that do not exist in the state.
+    strict_removals: bool,
+    /// Sequence number of the next logged event, letting the indexers detect missed events.
+    event_seq: u64,
+    /// Quality scores (0-100) attached by `curate_with_score`, kept apart from `curated_projects` so
+    /// that `view_user` keeps its layout. Only the curated project ids of the user have an entry,
+    /// and the curations recorded without a score have the score 0.
+    curation_scores: StateMap<AccountAddress, Vec<(ProjectId, u8)>, S>,
+}
+
+/// The state of the OVERLAY users as deployed before the admin could be a contract.
+/// This is only used to migrate the state with `migrate` function.
+#[derive(DeserialWithState)]
+#[concordium(state_parameter = "S")]
+struct StateV0<S> {
+    admin: AccountAddress,
+    project_contract_addr: ContractAddress,
+    user: StateMap<AccountAddress, UserStateV0, S>,
+    curator_list: Vec<AccountAddress>,
+    validator_list: Vec<AccountAddress>,
+}
+
+/// The user counts cached in the state.
+/// This is also the response schema for `recompute_counts` function.
+#[derive(Serial, Deserial, SchemaType, Clone, Copy, Default, Debug, PartialEq, Eq)]
+struct CachedCounts {
+    /// Number of users stored in the state.
+    user_count: u64,
+    /// Number of entries in `curator_list`.
+    curator_count: u64,
+    /// Number of entries in `validator_list`.
+    validator_count: u64,
 }
 
 /// The state of a single OVERLAY user
-#[derive(Serial, Deserial, SchemaType, Clone)]
+#[derive(Serial, Deserial, SchemaType, Clone, Default)]
 struct UserState {
+    is_curator: bool,
+    is_validator: bool,
+    curated_projects: Vec<ProjectId>,
+    /// Validated project ids with the overlay-projects contract address that recorded them.
+    validated_projects: Vec<(ProjectId, ContractAddress)>,
+    /// Categories the user specializes in (e.g. DeFi, gaming).
+    tags: Vec<String>,
+    /// Maximum number of projects this user can curate and validate in total.
+    /// If `None`, `max_projects_per_user` of the state applies.
+    max_projects: Option<u32>,
+    /// Time when the curator role was granted. `None` if not a curator or granted before this was recorded.
+    curator_since: Option<Timestamp>,
+    /// Time when the validator role was granted. `None` if not a validator or granted before this was recorded.
+    validator_since: Option<Timestamp>,
+    /// If true, the user has been soft-deleted by `soft_delete_user` and is kept only as an audit stub.
+    deleted: bool,
+    /// Time when the user was inserted into the state. `None` if inserted before this was recorded.
+    created_at: Option<Timestamp>,
+    /// If true, the user has been granted the curator role at least once. Never cleared.
+    was_ever_curator: bool,
+    /// If true, the user has been granted the validator role at least once. Never cleared.
+    was_ever_validator: bool,
+    /// Time of the last change of the roles. `None` if the roles have never been changed since this was recorded.
+    last_role_change: Option<Timestamp>,
+}
+
+/// The state of a single OVERLAY user stored in `StateV0`.
+#[derive(Serial, Deserial)]
+struct UserStateV0 {
     is_curator: bool,
     is_validator: bool,
     curated_projects: Vec<ProjectId>,
@@ -39,7 +193,25 @@ struct UserState {
 /// The parameter schema for `transfer_admin` function.
 #[derive(Serial, Deserial, SchemaType)]
 struct TransferAdminParams {
-    admin: AccountAddress,
+    admin: Address,
+}
+
+/// The parameter schema for `handover` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct HandoverParams {
+    new_admin: Address,
+    new_project_contract: ContractAddress,
+}
+
+/// The parameter schema for `setup_governance` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct SetupGovernanceParams {
+    /// `None` keeps the current admin.
+    new_admin: Option<Address>,
+    /// Replaces the whole observer set.
+    observers: Vec<AccountAddress>,
+    /// `None` keeps the current overlay-projects contract address.
+    project_contract: Option<ContractAddress>,
 }
 
 /// The parameter schema for `add_project_contract` function.
@@ -48,6 +220,113 @@ struct AddProjectContractParams {
     project_contract_addr: ContractAddress,
 }
 
+/// The parameter schema for `cas_project_contract` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct CasProjectContractParams {
+    /// The overlay-projects contract address the caller expects to be set currently.
+    expected: ContractAddress,
+    new: ContractAddress,
+}
+
+/// The parameter schema for `set_disjoint_project_roles` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct SetDisjointProjectRolesParams {
+    disjoint_project_roles: bool,
+}
+
+/// The parameter schema for `set_auto_prune_on_remove` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct SetAutoPruneOnRemoveParams {
+    auto_prune_on_remove: bool,
+}
+
+/// The parameter schema for `set_reject_privileged_targets` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct SetRejectPrivilegedTargetsParams {
+    reject_privileged_targets: bool,
+}
+
+/// The parameter schema for `set_require_known_users` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct SetRequireKnownUsersParams {
+    require_known_users: bool,
+}
+
+/// The parameter schema for `set_strict_removals` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct SetStrictRemovalsParams {
+    strict_removals: bool,
+}
+
+/// The parameter schema for `set_users_view_public` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct SetUsersViewPublicParams {
+    users_view_public: bool,
+}
+
+/// The parameter schema for `set_events_enabled` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct SetEventsEnabledParams {
+    events_enabled: bool,
+}
+
+/// The parameter schema for `set_curate_auto_create` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct SetCurateAutoCreateParams {
+    curate_auto_create: bool,
+}
+
+/// The parameter schema for `set_score_weights` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct SetScoreWeightsParams {
+    curator_weight: u64,
+    validator_weight: u64,
+}
+
+/// The parameter schema for `set_recovery_config` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct SetRecoveryConfigParams {
+    /// `None` disables `inactive_admin_takeover`.
+    recovery_account: Option<AccountAddress>,
+    admin_inactivity_timeout: Duration,
+}
+
+/// The parameter schema for `apply_for_role` function.
+type ApplyForRoleParams = Role;
+
+/// The parameter schema for `dismiss_application` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct DismissApplicationParams {
+    addr: AccountAddress,
+    role: Role,
+}
+
+/// The parameter schema for `set_role_cooldown` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct SetRoleCooldownParams {
+    role_cooldown: Duration,
+}
+
+/// The parameter schema for `set_max_projects_per_user` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct SetMaxProjectsPerUserParams {
+    max_projects_per_user: Option<u32>,
+}
+
+/// The parameter schema for `set_user_max_projects` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct SetUserMaxProjectsParams {
+    addr: AccountAddress,
+    max_projects: Option<u32>,
+}
+
+/// The parameter schema for `set_default_user_state` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct SetDefaultUserStateParams {
+    is_curator: bool,
+    is_validator: bool,
+}
+
 /// Single account address parameter that is commonly used.
 #[derive(Serial, Deserial, SchemaType)]
 struct AddrParams {
@@ -55,14 +334,267 @@ struct AddrParams {
 }
 /// The parameter schema for `add_curator` function.
 type AddCuratorParams = AddrParams;
+/// The parameter schema for `can_add_curator` function.
+type CanAddCuratorParams = AddrParams;
+
+/// The parameter schema for `register_user` function.
+type RegisterUserParams = AddrParams;
+/// The parameter schema for `remove_curator` and `remove_validator` functions.
+#[derive(Serial, Deserial, SchemaType)]
+struct RemoveRoleParams {
+    addr: AccountAddress,
+    /// Optional reason of the removal recorded in the event.
+    reason: Option<String>,
+    /// Remove the role even if the user still has validated projects.
+    /// Only `remove_validator` checks the projects, so `remove_curator` ignores it.
+    force: bool,
+}
+
 /// The parameter schema for `remove_curator` function.
-type RemoveCuratorParams = AddrParams;
+type RemoveCuratorParams = RemoveRoleParams;
 /// The parameter schema for `add_validator` function.
 type AddValidatorParams = AddrParams;
 /// The parameter schema for `remove_validator` function.
-type RemoveValidatorParams = AddrParams;
+type RemoveValidatorParams = RemoveRoleParams;
+/// The parameter schema for `switch_role` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct SwitchRoleParams {
+    addr: AccountAddress,
+    /// If true, the user becomes a curator and stops being a validator, and vice versa.
+    to_curator: bool,
+    /// Remove the validator role even if the user still has validated projects.
+    force: bool,
+}
+/// The parameter schema for `soft_delete_user` function.
+type SoftDeleteUserParams = AddrParams;
 /// The parameter schema for `view_user` function.
 type ViewUserParams = AddrParams;
+/// The parameter schema for `view_user_extended` function.
+type ViewUserExtendedParams = AddrParams;
+/// The parameter schema for `view_user_projects` function.
+type ViewUserProjectsParams = AddrParams;
+
+/// The parameter schema for `view_user_project_breakdown` function.
+type ViewUserProjectBreakdownParams = AddrParams;
+
+/// The parameter schema for `get_user_roles` function.
+type GetUserRolesParams = AddrParams;
+
+/// The parameter schema for `view_user_role` function.
+type ViewUserRoleParams = AddrParams;
+
+/// The parameter schema for `view_user_project_gaps` function.
+type ViewUserProjectGapsParams = AddrParams;
+
+/// The parameter schema for `was_ever_curator` function.
+type WasEverCuratorParams = AddrParams;
+/// The parameter schema for `was_ever_validator` function.
+type WasEverValidatorParams = AddrParams;
+
+/// The parameter schema for `curator_rank` function.
+type CuratorRankParams = AddrParams;
+
+/// The parameter schema for `resync_user` function.
+type ResyncUserParams = AddrParams;
+
+/// The parameter schema for `view_user_score` function.
+type ViewUserScoreParams = AddrParams;
+
+/// The parameter schema for `view_curation_scores` function.
+type ViewCurationScoresParams = AddrParams;
+
+/// The parameter schema for `view_orphan_validations` function.
+type ViewOrphanValidationsParams = AddrParams;
+
+/// The desired roles of a single user used by `set_user_roles_batch` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct UserRolesParams {
+    addr: AccountAddress,
+    is_curator: bool,
+    is_validator: bool,
+}
+
+/// The parameter schema for `set_user_roles_batch` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct SetUserRolesBatchParams {
+    users: Vec<UserRolesParams>,
+    /// If true or `None`, the entries for the admin account are skipped.
+    skip_admin: Option<bool>,
+}
+
+/// The parameter schema for `import_curators` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct ImportCuratorsParams {
+    /// Each entry is a curator address with its curated project ids.
+    curators: Vec<(AccountAddress, Vec<ProjectId>)>,
+    /// If true or `None`, the entry for the admin account is skipped.
+    skip_admin: Option<bool>,
+}
+
+/// The parameter schema for `swap_users` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct SwapUsersParams {
+    a: AccountAddress,
+    b: AccountAddress,
+}
+
+/// The parameter schema for `shared_projects` function.
+type SharedProjectsParams = SwapUsersParams;
+
+/// The parameter schema for `are_curators` function.
+type AreCuratorsParams = Vec<AccountAddress>;
+
+/// The parameter schema for `filter_known_users` function.
+type FilterKnownUsersParams = Vec<AccountAddress>;
+
+/// The parameter schema for `diff_curators` function.
+type DiffCuratorsParams = Vec<AccountAddress>;
+
+/// The parameter schema for `add_user_tag` and `remove_user_tag` functions.
+#[derive(Serial, Deserial, SchemaType)]
+struct UserTagParams {
+    addr: AccountAddress,
+    tag: String,
+}
+
+/// The parameter schema for `add_user_tag` function.
+type AddUserTagParams = UserTagParams;
+
+/// The parameter schema for `remove_user_tag` function.
+type RemoveUserTagParams = UserTagParams;
+
+/// The parameter schema for `users_by_tag` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct UsersByTagParams {
+    tag: String,
+}
+
+/// The parameter schema for `recent_curators` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct RecentCuratorsParams {
+    limit: u64,
+}
+
+/// The parameter schema for `top_curators` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct TopCuratorsParams {
+    limit: u64,
+}
+
+/// The parameter schema for `allow_project` function.
+type AllowProjectParams = ProjectId;
+/// The parameter schema for `disallow_project` function.
+type DisallowProjectParams = ProjectId;
+
+/// The parameter schema for `add_observer` function.
+type AddObserverParams = AddrParams;
+/// The parameter schema for `remove_observer` function.
+type RemoveObserverParams = AddrParams;
+
+/// The parameter schema for `purge_project_id` function.
+type PurgeProjectIdParams = ProjectId;
+
+/// The parameter schema for `trim_user_projects` function.
+/// The maximum number of the curated and validated projects to keep for each user.
+type TrimUserProjectsParams = u32;
+
+/// The parameter schema for `is_project_referenced` function.
+type IsProjectReferencedParams = ProjectId;
+
+/// The parameter schema for `which_projects_referenced` function.
+type WhichProjectsReferencedParams = Vec<ProjectId>;
+
+/// The parameter schema for `curators_of_project_prefix` function.
+type CuratorsOfProjectPrefixParams = String;
+
+/// The parameter schema for `users_created_since` function.
+type UsersCreatedSinceParams = Timestamp;
+
+/// The parameter schema for `changed_since` function.
+/// The `state_seq` last seen by the client.
+type ChangedSinceParams = u64;
+
+/// The parameter schema for `view_users_by_grant_time` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct ViewUsersByGrantTimeParams {
+    limit: u64,
+    /// If false, soft-deleted users are skipped.
+    include_deleted: bool,
+}
+
+/// The parameter schema for `view_users_after` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct ViewUsersAfterParams {
+    /// Cursor returned by the previous page. `None` starts from the first user.
+    after: Option<AccountAddress>,
+    limit: u64,
+    /// If false, soft-deleted users are skipped.
+    include_deleted: bool,
+}
+
+/// The role condition used to filter users.
+#[derive(Serial, Deserial, SchemaType)]
+enum RoleFilter {
+    /// Users registered as a curator.
+    Curator,
+    /// Users registered as a validator.
+    Validator,
+    /// Users registered as a curator or a validator.
+    Any,
+}
+
+/// The parameter schema for `view_users_filtered_paged` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct ViewUsersFilteredPagedParams {
+    role: RoleFilter,
+    /// Number of the matching users to skip.
+    start: u64,
+    limit: u64,
+    /// If false, soft-deleted users are skipped.
+    include_deleted: bool,
+}
+
+/// The parameter schema for `force_curate` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct ForceCurateParams {
+    addr: AccountAddress,
+    project_id: ProjectId,
+    /// If true, the user is also marked as a curator.
+    set_curator: bool,
+}
+
+/// The parameter schema for `curate_batch_users` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct CurateBatchUsersParams {
+    project_id: ProjectId,
+    addrs: Vec<AccountAddress>,
+    /// If true, the whole call is rejected if any of the users would be skipped.
+    strict: bool,
+}
+
+/// The parameter schema for `curate_bulk` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct CurateBulkParams {
+    pairs: Vec<(AccountAddress, ProjectId)>,
+    /// If true, the whole call is rejected if any of the pairs would fail.
+    strict: bool,
+}
+
+/// The parameter schema for `validate_batch_users` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct ValidateBatchUsersParams {
+    project_id: ProjectId,
+    addrs: Vec<AccountAddress>,
+    /// If true, the whole call is rejected if any of the users would be skipped.
+    strict: bool,
+}
+
+/// The parameter schema for `curators_granted_between` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct CuratorsGrantedBetweenParams {
+    from: Timestamp,
+    to: Timestamp,
+}
 
 /// The parameter schema for `curate` function.
 #[derive(Serial, Deserial, SchemaType)]
@@ -71,6 +603,15 @@ struct CurateParams {
     project_id: ProjectId,
 }
 
+/// The parameter schema for `curate_with_score` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct CurateWithScoreParams {
+    addr: AccountAddress,
+    project_id: ProjectId,
+    /// Quality score of the curation, up to `MAX_CURATION_SCORE`.
+    score: u8,
+}
+
 /// The parameter schema for `validate` function.
 #[derive(Serial, Deserial, SchemaType)]
 struct ValidateParams {
@@ -78,6 +619,26 @@ struct ValidateParams {
     project_id: ProjectId,
 }
 
+/// Account address and project id parameter that is commonly used by queries.
+#[derive(Serial, Deserial, SchemaType)]
+struct UserProjectParams {
+    addr: AccountAddress,
+    project_id: ProjectId,
+}
+/// The parameter schema for `has_curated` function.
+type HasCuratedParams = UserProjectParams;
+/// The parameter schema for `has_validated` function.
+type HasValidatedParams = UserProjectParams;
+/// The parameter schema for `self_curated_and_validated` function.
+type SelfCuratedAndValidatedParams = UserProjectParams;
+
+/// The parameter schema for `validated_projects_by_source` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct ValidatedProjectsBySourceParams {
+    addr: AccountAddress,
+    source: ContractAddress,
+}
+
 /// The parameter schema for `upgrade` function.
 #[derive(Debug, Serialize, SchemaType)]
 struct UpgradeParams {
@@ -88,1823 +649,16507 @@ struct UpgradeParams {
 /// The response schema for `view_admin` function.
 #[derive(Serial, Deserial, SchemaType)]
 struct ViewAdminRes {
-    admin: AccountAddress,
+    admin: Address,
     project_contract_addr: ContractAddress,
     curator_list: Vec<AccountAddress>,
     validator_list: Vec<AccountAddress>,
 }
 
-/// The response schema for `view_user` function.
-type ViewUserResponse = UserState;
+/// The response schema for `view_admin_detailed` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct ViewAdminDetailedResponse {
+    /// `curator_list` with the number of the curated projects of each curator.
+    curators: Vec<(AccountAddress, u32)>,
+    /// `validator_list` with the number of the validated projects of each validator.
+    validators: Vec<(AccountAddress, u32)>,
+}
 
-/// The response schema for `view_users` function.
-type ViewUsersResponse = Vec<(AccountAddress, UserState)>;
+/// The response schema for `view_admin_status` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct ViewAdminStatusResponse {
+    admin: Address,
+    caller_is_admin: bool,
+}
 
-/// Custom error definitions of OVERLAY users smart contract.
-#[derive(Debug, PartialEq, Eq, Reject, Serial, SchemaType)]
-enum Error {
-    #[from(ParseError)]
-    ParseParamsError,
-    InvalidCaller,
-    InvalidArgument,
+/// The response schema for `view_activity_span` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct ViewActivitySpanResponse {
+    /// The earliest grant time of the roles currently held by any user.
+    first_grant: Option<Timestamp>,
+    /// The latest grant time of the roles currently held by any user.
+    last_grant: Option<Timestamp>,
 }
 
-type ContractResult<A> = Result<A, Error>;
+/// The response schema for `view_readiness` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct ViewReadinessResponse {
+    /// The admin is not the all-zero account address.
+    admin_set: bool,
+    /// The overlay-projects contract address is not `<0,0>`.
+    project_contract_set: bool,
+    /// All the conditions above are met.
+    ready: bool,
+}
 
-/// The smart contract module init function.
-/// Although anyone can init this module, this function is expected to be called by OVERLAY team.
-#[init(contract = "overlay-users")]
-fn contract_init<S: HasStateApi>(
-    ctx: &impl HasInitContext,
-    state_builder: &mut StateBuilder<S>,
-) -> InitResult<State<S>> {
-    let state = State {
-        admin: ctx.init_origin(),
-        project_contract_addr: ContractAddress::new(0u64, 0u64),
-        user: state_builder.new_map(),
-        curator_list: Vec::new(),
-        validator_list: Vec::new(),
-    };
-    Ok(state)
+/// The response schema for `view_identity` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct ViewIdentityResponse {
+    self_address: ContractAddress,
+    owner: AccountAddress,
 }
 
-/// Transfer admin of this module to another account.
-///
-/// Caller: current admin account.
-/// Reject if:
-/// * Caller is not the current admin account.
-#[receive(
-    contract = "overlay-users",
-    name = "transfer_admin",
-    parameter = "TransferAdminParams",
-    mutable,
-    error = "Error"
-)]
-fn contract_transfer_admin<S: HasStateApi>(
-    ctx: &impl HasReceiveContext,
-    host: &mut impl HasHost<State<S>, StateApiType = S>,
-) -> ContractResult<()> {
-    let params: TransferAdminParams = ctx.parameter_cursor().get()?;
-    let state = host.state_mut();
-    ensure!(ctx.invoker() == state.admin, Error::InvalidCaller);
-    state.admin = params.admin;
-    Ok(())
+/// The response schema for `view_last_admin_change` function.
+type ViewLastAdminChangeResponse = Option<(Timestamp, Address)>;
+
+/// The response schema for `view_user` function.
+type ViewUserResponse = UserState;
+
+/// The response schema for `view_user_extended` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct ViewUserExtendedResponse {
+    /// Whether the requested address is the admin of this module.
+    is_admin: bool,
+    user_state: UserState,
 }
 
-/// Set associated overlay-projects contract address.
-///
-/// Caller: current admin account.
-/// Reject if:
-/// * Caller is not the current admin account.
-#[receive(
-    contract = "overlay-users",
-    name = "add_project_contract",
-    parameter = "AddProjectContractParams",
+/// The response schema for `curate_batch_users` function.
+type CurateBatchUsersResponse = Vec<AccountAddress>;
+
+/// The response schema for `curate_bulk` function.
+type CurateBulkResponse = Vec<(AccountAddress, ProjectId)>;
+
+/// The response schema for `set_user_roles_batch` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct SetUserRolesBatchResponse {
+    /// Number of the entries whose user data has been changed.
+    changed_count: u32,
+    /// True if an entry for the admin account has been skipped.
+    admin_skipped: bool,
+}
+
+/// The response schema for `validate_batch_users` function.
+type ValidateBatchUsersResponse = Vec<AccountAddress>;
+
+/// The response schema for `curators_granted_between` function.
+type CuratorsGrantedBetweenResponse = Vec<AccountAddress>;
+
+/// The response schema for `view_all_role_holders` function.
+type ViewAllRoleHoldersResponse = Vec<AccountAddress>;
+
+/// The response schema for `view_admin_action_counts` function.
+type ViewAdminActionCountsResponse = Vec<(String, u64)>;
+
+/// The response schema for `view_orphan_validations` function.
+type ViewOrphanValidationsResponse = Vec<ProjectId>;
+
+/// The response schema for `view_curation_scores` function.
+/// Each curated project id of the user with the score of its curation.
+type ViewCurationScoresResponse = Vec<(ProjectId, u8)>;
+
+/// The response schema for `diff_curators` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct DiffCuratorsResponse {
+    /// Expected addresses that are not in `curator_list`.
+    missing: Vec<AccountAddress>,
+    /// Addresses in `curator_list` that are not expected.
+    extra: Vec<AccountAddress>,
+}
+
+/// The response schema for `view_user_projects` function.
+type ViewUserProjectsResponse = Vec<ProjectId>;
+
+/// The response schema for `get_user_roles` function.
+/// All the fields have a fixed size, so the serialized response is always 10 bytes.
+#[derive(Serial, Deserial, SchemaType, Default)]
+struct GetUserRolesResponse {
+    is_curator: bool,
+    is_validator: bool,
+    curated_count: u32,
+    validated_count: u32,
+}
+
+/// The response schema for `view_user_project_breakdown` function.
+#[derive(Serial, Deserial, SchemaType, Default)]
+struct ViewUserProjectBreakdownResponse {
+    curated: Vec<ProjectId>,
+    validated: Vec<ProjectId>,
+}
+
+/// The response schema for `view_user_project_gaps` function.
+#[derive(Debug, PartialEq, Eq, Serial, Deserial, SchemaType, Default)]
+struct ViewUserProjectGapsResponse {
+    /// Project ids in `curated_projects` that are absent from `validated_projects`.
+    curated_not_validated: Vec<ProjectId>,
+    /// Project ids in `validated_projects` that are absent from `curated_projects`.
+    validated_not_curated: Vec<ProjectId>,
+}
+
+/// The response schema for `validated_projects_by_source` function.
+type ValidatedProjectsBySourceResponse = Vec<ProjectId>;
+
+/// The response schema for `view_users` function.
+type ViewUsersResponse = Vec<(AccountAddress, UserState)>;
+
+/// The response schema for `view_users_after` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct ViewUsersAfterResponse {
+    users: Vec<(AccountAddress, UserState)>,
+    /// Cursor for the next page. `None` if there are no more users.
+    next: Option<AccountAddress>,
+}
+
+/// The response schema for `view_users_filtered_paged` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct ViewUsersFilteredPagedResponse {
+    users: Vec<(AccountAddress, UserState)>,
+    /// True if more users match the role after this page.
+    has_more: bool,
+}
+
+/// The response schema for `view_user_flags` function.
+/// The `u8` packs the roles of the user:
+/// * bit 0: `is_curator`
+/// * bit 1: `is_validator`
+type ViewUserFlagsResponse = Vec<(AccountAddress, u8)>;
+
+/// The response schema for `are_curators` function.
+type AreCuratorsResponse = Vec<(AccountAddress, bool)>;
+
+/// The response schema for `view_curators_flat` function.
+type ViewCuratorsFlatResponse = Vec<(AccountAddress, u32)>;
+
+/// The response schema for `top_curators` function.
+type TopCuratorsResponse = Vec<(AccountAddress, u32)>;
+
+/// The response schema for `recent_curators` function.
+type RecentCuratorsResponse = Vec<(AccountAddress, UserState)>;
+
+/// The response schema for `view_users_by_grant_time` function.
+type ViewUsersByGrantTimeResponse = Vec<(AccountAddress, UserState)>;
+
+/// The response schema for `view_pending_applications` function.
+#[derive(Debug, PartialEq, Eq, Serial, Deserial, SchemaType)]
+struct ViewPendingApplicationsResponse {
+    /// Accounts that applied for the curator role.
+    curators: Vec<AccountAddress>,
+    /// Accounts that applied for the validator role.
+    validators: Vec<AccountAddress>,
+}
+
+/// The response schema for `pending_count` function.
+#[derive(Debug, PartialEq, Eq, Serial, Deserial, SchemaType)]
+struct PendingCountResponse {
+    /// Number of accounts that applied for the curator role.
+    curators: u32,
+    /// Number of accounts that applied for the validator role.
+    validators: u32,
+}
+
+/// The response schema for `project_curation_counts` function.
+type ProjectCurationCountsResponse = Vec<(ProjectId, u32)>;
+
+/// The response schema for `project_validation_counts` function.
+type ProjectValidationCountsResponse = Vec<(ProjectId, u32)>;
+
+/// The response schema for `which_projects_referenced` function.
+type WhichProjectsReferencedResponse = Vec<(ProjectId, bool)>;
+
+/// The response schema for `curators_of_project_prefix` function.
+type CuratorsOfProjectPrefixResponse = Vec<(AccountAddress, ProjectId)>;
+
+/// The response schema for `view_state_digest` function.
+type ViewStateDigestResponse = [u8; 32];
+
+/// The response schema for `shared_projects` function.
+type SharedProjectsResponse = Vec<ProjectId>;
+
+/// The response schema for `filter_known_users` function.
+type FilterKnownUsersResponse = Vec<AccountAddress>;
+
+/// The response schema for `users_by_tag` function.
+type UsersByTagResponse = Vec<AccountAddress>;
+
+/// The response schema for `view_user_role` function.
+/// The combination of the curator and validator roles of a user.
+#[derive(Debug, PartialEq, Eq, Serial, Deserial, SchemaType)]
+enum UserRole {
+    None,
+    Curator,
+    Validator,
+    Both,
+}
+
+/// The response schema for `audit_state` function.
+/// Each field is true if the invariant holds.
+#[derive(Serial, Deserial, SchemaType)]
+struct AuditStateResponse {
+    /// `curator_list` and `validator_list` contain exactly the users with the role.
+    lists_match_map: bool,
+    /// Neither `curator_list` nor `validator_list` has the same address twice.
+    no_duplicate_list_entries: bool,
+    /// No user has an empty project id in its curated or validated projects.
+    no_empty_project_ids: bool,
+    /// The overlay-projects contract address is not `<0,0>`.
+    project_contract_configured: bool,
+}
+
+/// The response schema for `changed_since` function.
+#[derive(Debug, PartialEq, Eq, Serial, Deserial, SchemaType)]
+struct ChangedSinceResponse {
+    /// True if the state has been updated since the given `state_seq`.
+    stale: bool,
+    /// The current `state_seq`.
+    state_seq: u64,
+}
+
+/// The response schema for `view_drifted_users` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct ViewDriftedUsersResponse {
+    /// Users marked as a curator that are not in `curator_list`.
+    curators: Vec<AccountAddress>,
+    /// Users marked as a validator that are not in `validator_list`.
+    validators: Vec<AccountAddress>,
+}
+
+/// The response schema for `view_limits` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct ViewLimitsResponse {
+    /// `None` if the number of projects per user is not limited.
+    max_projects_per_user: Option<u32>,
+    /// Zero if the role cooldown is disabled.
+    role_cooldown: Duration,
+    curator_weight: u64,
+    validator_weight: u64,
+    /// `None` if no recovery account is set, since the timeout is not used then.
+    admin_inactivity_timeout: Option<Duration>,
+}
+
+/// The response schema for `can_add_curator` function.
+#[derive(Serial, SchemaType)]
+struct CanAddCuratorResponse {
+    allowed: bool,
+    /// The error `add_curator` would reject with. `None` if allowed.
+    reason: Option<Error>,
+}
+
+/// The response schema for `view_stale_list_entries` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct ViewStaleListEntriesResponse {
+    /// Entries of `curator_list` that are unknown or not marked as a curator.
+    stale_curators: Vec<AccountAddress>,
+    /// Entries of `validator_list` that are unknown or not marked as a validator.
+    stale_validators: Vec<AccountAddress>,
+}
+
+/// The response schema for `dedup_lists` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct DedupListsResponse {
+    /// Number of duplicated entries removed from `curator_list`.
+    curator_duplicates: u32,
+    /// Number of duplicated entries removed from `validator_list`.
+    validator_duplicates: u32,
+}
+
+/// The response schema for `resync_user` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct ResyncUserResponse {
+    /// True if the user has been added to or removed from `curator_list`.
+    curator_list_adjusted: bool,
+    /// True if the user has been added to or removed from `validator_list`.
+    validator_list_adjusted: bool,
+}
+
+/// The response schema for `backfill_lists` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct BackfillListsResponse {
+    /// Number of entries in the rebuilt `curator_list`.
+    curator_count: u32,
+    /// Number of entries in the rebuilt `validator_list`.
+    validator_count: u32,
+}
+
+/// The response schema for `view_stats` function.
+#[derive(Serial, Deserial, SchemaType, Default)]
+struct ViewStatsResponse {
+    /// Number of users stored in the state.
+    user_count: u64,
+    /// Number of users marked as a curator.
+    curator_count: u64,
+    /// Number of users marked as a validator.
+    validator_count: u64,
+    /// Number of users marked as both a curator and a validator.
+    dual_role_count: u64,
+    /// Total number of curated projects over all users.
+    total_curations: u64,
+    /// Total number of validated projects over all users.
+    total_validations: u64,
+}
+
+/// The response schema for `view_role_histogram` function.
+#[derive(Debug, PartialEq, Eq, Serial, Deserial, SchemaType, Default)]
+struct ViewRoleHistogramResponse {
+    /// Number of users with neither role.
+    none: u64,
+    /// Number of users marked only as a curator.
+    curator_only: u64,
+    /// Number of users marked only as a validator.
+    validator_only: u64,
+    /// Number of users marked as both a curator and a validator.
+    both: u64,
+}
+
+/// The event schema of a role removal.
+#[derive(Debug, PartialEq, Eq, Serial, SchemaType)]
+struct RemoveRoleEvent {
+    /// Sequence number of the event.
+    event_seq: u64,
+    addr: AccountAddress,
+    reason: Option<String>,
+}
+
+/// The role of a user.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serial, Deserial, SchemaType)]
+enum Role {
+    Curator,
+    Validator,
+}
+
+/// The event schema summarizing the role changes of a batch operation.
+#[derive(Debug, PartialEq, Eq, Serial, SchemaType)]
+struct BatchRoleChangeEvent {
+    /// Sequence number of the event.
+    event_seq: u64,
+    /// Number of users granted the curator role.
+    curators_added: u32,
+    /// Number of users whose curator role has been removed.
+    curators_removed: u32,
+    /// Number of users granted the validator role.
+    validators_added: u32,
+    /// Number of users whose validator role has been removed.
+    validators_removed: u32,
+}
+
+/// Events logged by this contract.
+#[derive(Debug, PartialEq, Eq, Serial, SchemaType)]
+enum Event {
+    /// The curator role has been removed from the user.
+    RemoveCurator(RemoveRoleEvent),
+    /// The validator role has been removed from the user.
+    RemoveValidator(RemoveRoleEvent),
+    /// The roles of multiple users have been changed by a batch operation.
+    BatchRoleChange(BatchRoleChangeEvent),
+}
+
+/// Custom error definitions of OVERLAY users smart contract.
+#[derive(Debug, PartialEq, Eq, Reject, Serial, SchemaType)]
+enum Error {
+    #[from(ParseError)]
+    ParseParamsError,
+    InvalidCaller,
+    InvalidArgument,
+    ProjectRoleConflict,
+    UserNotFound,
+    NotACurator,
+    NotAValidator,
+    ConfigLocked,
+    TooManyTags,
+    TooManyProjects,
+    CuratorsFrozen,
+    PreconditionFailed,
+    HasActiveProjects,
+    ProjectNotAllowed,
+    #[from(LogError)]
+    LogError,
+    CooldownActive,
+    AdminActive,
+    UserDeleted,
+    NoSnapshot,
+    TooManyPendingApplications,
+}
+
+type ContractResult<A> = Result<A, Error>;
+
+/// The smart contract module init function.
+/// Although anyone can init this module, this function is expected to be called by OVERLAY team.
+#[init(contract = "overlay-users", event = "Event")]
+fn contract_init<S: HasStateApi>(
+    ctx: &impl HasInitContext,
+    state_builder: &mut StateBuilder<S>,
+) -> InitResult<State<S>> {
+    let state = State {
+        admin: Address::Account(ctx.init_origin()),
+        project_contract_addr: ContractAddress::new(0u64, 0u64),
+        user: state_builder.new_map(),
+        curator_list: Vec::new(),
+        validator_list: Vec::new(),
+        disjoint_project_roles: false,
+        last_admin_change: None,
+        config_locked: false,
+        auto_prune_on_remove: false,
+        default_user_state: UserState::default(),
+        reject_privileged_targets: false,
+        max_projects_per_user: None,
+        curators_frozen: false,
+        require_known_users: false,
+        cached_counts: CachedCounts::default(),
+        users_view_public: true,
+        events_enabled: true,
+        allowed_projects: state_builder.new_set(),
+        state_seq: 0,
+        curate_auto_create: false,
+        role_cooldown: Duration::from_millis(0),
+        pending_curators: state_builder.new_set(),
+        pending_validators: state_builder.new_set(),
+        pending_curator_count: 0,
+        pending_validator_count: 0,
+        recovery_account: None,
+        admin_inactivity_timeout: Duration::from_millis(0),
+        last_admin_activity: None,
+        observers: state_builder.new_set(),
+        curator_weight: 1,
+        validator_weight: 1,
+        last_snapshot: state_builder.new_map(),
+        last_snapshot_time: None,
+        admin_action_counts: state_builder.new_map(),
+        strict_removals: false,
+        event_seq: 0,
+        curation_scores: state_builder.new_map(),
+    };
+    Ok(state)
+}
+
+/// Transfer admin of this module to another account or contract.
+/// The transfer time and the previous admin account are recorded as the last admin change,
+/// and the recovery configuration is cleared so that the new admin has to set it again.
+///
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
+#[receive(
+    contract = "overlay-users",
+    name = "transfer_admin",
+    parameter = "TransferAdminParams",
     mutable,
     error = "Error"
 )]
-fn contract_add_project_contract<S: HasStateApi>(
+fn contract_transfer_admin<S: HasStateApi>(
     ctx: &impl HasReceiveContext,
     host: &mut impl HasHost<State<S>, StateApiType = S>,
 ) -> ContractResult<()> {
-    let params: AddProjectContractParams = ctx.parameter_cursor().get()?;
+    let params: TransferAdminParams = ctx.parameter_cursor().get()?;
     let state = host.state_mut();
-    ensure!(ctx.invoker() == state.admin, Error::InvalidCaller);
-    state.project_contract_addr = params.project_contract_addr;
+    ensure!(is_admin(ctx, &state.admin), Error::InvalidCaller);
+    record_admin_activity(ctx, state, "transfer_admin");
+    state.last_admin_change = Some((ctx.metadata().slot_time(), state.admin));
+    state.admin = params.admin;
+    clear_recovery_config(state);
+    state.state_seq += 1;
     Ok(())
 }
 
-/// Update inputted user account as a curator.
-/// If the requested user address dose not exist in the state, default user data would be created.
+/// Transfer admin of this module and set associated overlay-projects contract address
+/// in one transaction, so the deployment is never left half handed over.
+/// The admin transfer is recorded and the recovery configuration is cleared in the same way as `transfer_admin`.
 ///
 /// Caller: current admin account.
 /// Reject if:
 /// * Caller is not the current admin account.
+/// * The configuration has been locked by `lock_config` function.
 #[receive(
     contract = "overlay-users",
-    name = "add_curator",
-    parameter = "AddCuratorParams",
-    mutable
+    name = "handover",
+    parameter = "HandoverParams",
+    mutable,
+    error = "Error"
 )]
-fn contract_add_curator<S: HasStateApi>(
+fn contract_handover<S: HasStateApi>(
     ctx: &impl HasReceiveContext,
-    host: &mut impl HasHost<State<S>>,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
 ) -> ContractResult<()> {
-    let params: AddCuratorParams = ctx.parameter_cursor().get()?;
+    let params: HandoverParams = ctx.parameter_cursor().get()?;
     let state = host.state_mut();
-    ensure!(ctx.invoker() == state.admin, Error::InvalidCaller);
-    state
-        .user
-        .entry(params.addr)
-        .and_modify(|user_state| user_state.is_curator = true)
-        .or_insert_with(|| UserState {
-            is_curator: true,
-            is_validator: false,
-            curated_projects: Vec::new(),
-            validated_projects: Vec::new(),
-        });
-    if !state.curator_list.contains(&params.addr) {
-        state.curator_list.push(params.addr);
-    }
+    ensure!(is_admin(ctx, &state.admin), Error::InvalidCaller);
+    record_admin_activity(ctx, state, "handover");
+    ensure!(!state.config_locked, Error::ConfigLocked);
+    state.project_contract_addr = params.new_project_contract;
+    state.last_admin_change = Some((ctx.metadata().slot_time(), state.admin));
+    state.admin = params.new_admin;
+    clear_recovery_config(state);
+    state.state_seq += 1;
     Ok(())
 }
 
-/// Unmark inputted user account as a curator.
+/// Set up the governance of this module in one transaction: replace the observer set, and
+/// transfer admin and set associated overlay-projects contract address if they are inputted.
+/// The admin transfer takes effect immediately, and is recorded and clears the recovery configuration
+/// in the same way as `transfer_admin`.
 ///
 /// Caller: current admin account.
 /// Reject if:
 /// * Caller is not the current admin account.
+/// * The project contract is inputted and the configuration has been locked by `lock_config` function.
 #[receive(
     contract = "overlay-users",
-    name = "remove_curator",
-    parameter = "RemoveCuratorParams",
-    mutable
+    name = "setup_governance",
+    parameter = "SetupGovernanceParams",
+    mutable,
+    error = "Error"
 )]
-fn contract_remove_curator<S: HasStateApi>(
+fn contract_setup_governance<S: HasStateApi>(
     ctx: &impl HasReceiveContext,
-    host: &mut impl HasHost<State<S>>,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
 ) -> ContractResult<()> {
-    let params: RemoveCuratorParams = ctx.parameter_cursor().get()?;
+    let params: SetupGovernanceParams = ctx.parameter_cursor().get()?;
     let state = host.state_mut();
-    ensure!(ctx.invoker() == state.admin, Error::InvalidCaller);
-    state.user.entry(params.addr).and_modify(|user_state| {
-        user_state.is_curator = false;
-    });
-    state.curator_list.retain(|x| *x != params.addr);
+    ensure!(is_admin(ctx, &state.admin), Error::InvalidCaller);
+    record_admin_activity(ctx, state, "setup_governance");
+    ensure!(
+        params.project_contract.is_none() || !state.config_locked,
+        Error::ConfigLocked
+    );
+    let current_observers: Vec<AccountAddress> = state.observers.iter().map(|addr| *addr).collect();
+    for addr in current_observers.iter() {
+        state.observers.remove(addr);
+    }
+    let mut changed = false;
+    for addr in params.observers {
+        changed |= !current_observers.contains(&addr);
+        state.observers.insert(addr);
+    }
+    changed |= state.observers.iter().count() != current_observers.len();
+    if let Some(project_contract) = params.project_contract {
+        changed |= update_field(&mut state.project_contract_addr, project_contract);
+    }
+    if let Some(new_admin) = params.new_admin {
+        state.last_admin_change = Some((ctx.metadata().slot_time(), state.admin));
+        state.admin = new_admin;
+        clear_recovery_config(state);
+        changed = true;
+    }
+    if changed {
+        state.state_seq += 1;
+    }
     Ok(())
 }
 
-/// Update inputted user account as a validator.
-/// If the requested user address dose not exist in the state, default user data would be created.
+/// Set the account that can take over the admin role when the admin has been inactive
+/// for `admin_inactivity_timeout`. The admin activity is recorded from this call on.
 ///
 /// Caller: current admin account.
 /// Reject if:
 /// * Caller is not the current admin account.
 #[receive(
     contract = "overlay-users",
-    name = "add_validator",
-    parameter = "AddValidatorParams",
-    mutable
+    name = "set_recovery_config",
+    parameter = "SetRecoveryConfigParams",
+    mutable,
+    error = "Error"
 )]
-fn contract_add_validator<S: HasStateApi>(
+fn contract_set_recovery_config<S: HasStateApi>(
     ctx: &impl HasReceiveContext,
-    host: &mut impl HasHost<State<S>>,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
 ) -> ContractResult<()> {
-    let params: AddValidatorParams = ctx.parameter_cursor().get()?;
+    let params: SetRecoveryConfigParams = ctx.parameter_cursor().get()?;
     let state = host.state_mut();
-    ensure!(ctx.invoker() == state.admin, Error::InvalidCaller);
-    state
-        .user
-        .entry(params.addr)
-        .and_modify(|user_state| user_state.is_validator = true)
-        .or_insert_with(|| UserState {
-            is_curator: false,
-            is_validator: true,
-            curated_projects: Vec::new(),
-            validated_projects: Vec::new(),
-        });
-    if !state.validator_list.contains(&params.addr) {
-        state.validator_list.push(params.addr);
+    ensure!(is_admin(ctx, &state.admin), Error::InvalidCaller);
+    let changed = update_field(&mut state.recovery_account, params.recovery_account)
+        | update_field(
+            &mut state.admin_inactivity_timeout,
+            params.admin_inactivity_timeout,
+        );
+    state.last_admin_activity = None;
+    record_admin_activity(ctx, state, "set_recovery_config");
+    if changed {
+        state.state_seq += 1;
     }
     Ok(())
 }
 
-/// Unmark inputted user account as a validator.
+/// Transfer admin of this module to the recovery account when the admin has been inactive.
+/// The transfer is recorded and the recovery configuration is cleared in the same way as `transfer_admin`.
 ///
-/// Caller: current admin account.
+/// Caller: recovery account.
 /// Reject if:
-/// * Caller is not the current admin account.
+/// * Caller is not the recovery account.
+/// * The admin has made an admin-gated call within `admin_inactivity_timeout` (`AdminActive`).
 #[receive(
     contract = "overlay-users",
-    name = "remove_validator",
-    parameter = "RemoveValidatorParams",
-    mutable
+    name = "inactive_admin_takeover",
+    mutable,
+    error = "Error"
 )]
-fn contract_remove_validator<S: HasStateApi>(
+fn contract_inactive_admin_takeover<S: HasStateApi>(
     ctx: &impl HasReceiveContext,
-    host: &mut impl HasHost<State<S>>,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
 ) -> ContractResult<()> {
-    let params: RemoveValidatorParams = ctx.parameter_cursor().get()?;
     let state = host.state_mut();
-    ensure!(ctx.invoker() == state.admin, Error::InvalidCaller);
-
-    state.user.entry(params.addr).and_modify(|user_state| {
-        user_state.is_validator = false;
-    });
-    state.validator_list.retain(|x| *x != params.addr);
+    let recovery_account = match state.recovery_account {
+        Some(recovery_account) if ctx.sender().matches_account(&recovery_account) => {
+            recovery_account
+        },
+        _ => return Err(Error::InvalidCaller),
+    };
+    let now = ctx.metadata().slot_time();
+    let last_admin_activity = state.last_admin_activity.ok_or(Error::AdminActive)?;
+    let inactive_until = last_admin_activity
+        .timestamp_millis()
+        .saturating_add(state.admin_inactivity_timeout.millis());
+    ensure!(now.timestamp_millis() >= inactive_until, Error::AdminActive);
+    state.last_admin_change = Some((now, state.admin));
+    state.admin = Address::Account(recovery_account);
+    clear_recovery_config(state);
+    state.state_seq += 1;
     Ok(())
 }
 
-/// Add project id to the user curated projects state.
+/// Set associated overlay-projects contract address.
 ///
-/// Caller: associated overlay-projects smart contract
+/// Caller: current admin account.
 /// Reject if:
-/// * Caller is not the associated overlay-projects smart contract address
-/// * The inputted user is not registered as a curator.
-///
-/// This function is designed to be called by the following smart contract functions.
-/// * overlay-projects.curate_project
+/// * Caller is not the current admin account.
+/// * The configuration has been locked by `lock_config` function.
 #[receive(
     contract = "overlay-users",
-    name = "curate",
-    parameter = "CurateParams",
-    mutable
+    name = "add_project_contract",
+    parameter = "AddProjectContractParams",
+    mutable,
+    error = "Error"
 )]
-fn contract_curate<S: HasStateApi>(
+fn contract_add_project_contract<S: HasStateApi>(
     ctx: &impl HasReceiveContext,
-    host: &mut impl HasHost<State<S>>,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
 ) -> ContractResult<()> {
-    let params: CurateParams = ctx.parameter_cursor().get()?;
+    let params: AddProjectContractParams = ctx.parameter_cursor().get()?;
     let state = host.state_mut();
-    ensure!(
-        ctx.sender() == Address::Contract(state.project_contract_addr),
-        Error::InvalidCaller
-    );
-    let target_user = state.user.get_mut(&params.addr);
-    ensure!(target_user.is_some(), Error::InvalidArgument);
-    let mut target_user = target_user.unwrap();
-    ensure!(target_user.is_curator, Error::InvalidArgument);
-    if !target_user.curated_projects.contains(&params.project_id) {
-        target_user.curated_projects.push(params.project_id);
+    ensure!(is_admin(ctx, &state.admin), Error::InvalidCaller);
+    record_admin_activity(ctx, state, "add_project_contract");
+    ensure!(!state.config_locked, Error::ConfigLocked);
+    if update_field(
+        &mut state.project_contract_addr,
+        params.project_contract_addr,
+    ) {
+        state.state_seq += 1;
     }
     Ok(())
 }
 
-/// Add project id to the user validated projects state.
+/// Set associated overlay-projects contract address only if the current address is the expected one.
+/// This prevents concurrent reconfigurations from silently overwriting each other.
 ///
-/// Caller: associated overlay-projects smart contract
+/// Caller: current admin account.
 /// Reject if:
-/// * Caller is not the associated overlay-projects smart contract address
-/// * The inputted user is not registered as a validator.
-///
-/// This function is designed to be called by the following smart contract functions.
-/// * overlay-projects.validate_project
+/// * Caller is not the current admin account.
+/// * The configuration has been locked by `lock_config` function.
+/// * The current overlay-projects contract address is not `expected` (`PreconditionFailed`).
 #[receive(
     contract = "overlay-users",
-    name = "validate",
-    parameter = "ValidateParams",
-    mutable
+    name = "cas_project_contract",
+    parameter = "CasProjectContractParams",
+    mutable,
+    error = "Error"
 )]
-fn contract_validate<S: HasStateApi>(
+fn contract_cas_project_contract<S: HasStateApi>(
     ctx: &impl HasReceiveContext,
-    host: &mut impl HasHost<State<S>>,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
 ) -> ContractResult<()> {
-    let params: ValidateParams = ctx.parameter_cursor().get()?;
+    let params: CasProjectContractParams = ctx.parameter_cursor().get()?;
     let state = host.state_mut();
+    ensure!(is_admin(ctx, &state.admin), Error::InvalidCaller);
+    record_admin_activity(ctx, state, "cas_project_contract");
+    ensure!(!state.config_locked, Error::ConfigLocked);
     ensure!(
-        ctx.sender() == Address::Contract(state.project_contract_addr),
-        Error::InvalidCaller
+        state.project_contract_addr == params.expected,
+        Error::PreconditionFailed
     );
-    let target_user = state.user.get_mut(&params.addr);
-    ensure!(target_user.is_some(), Error::InvalidArgument);
-    let mut target_user = target_user.unwrap();
-    ensure!(target_user.is_validator, Error::InvalidArgument);
-    if !target_user.validated_projects.contains(&params.project_id) {
-        target_user.validated_projects.push(params.project_id);
+    if update_field(&mut state.project_contract_addr, params.new) {
+        state.state_seq += 1;
     }
     Ok(())
 }
 
-/// Smart contract module upgrade function.
-/// For more information see https://developer.concordium.software/en/mainnet/smart-contracts/guides/upgradeable-contract.html#guide-upgradable-contract
+/// Lock the configuration so that the overlay-projects contract address can not be changed anymore.
+/// Once locked, the configuration can only be unlocked by `reset_governance_flags`.
+///
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
 #[receive(
     contract = "overlay-users",
-    name = "upgrade",
-    parameter = "UpgradeParams",
-    mutable
+    name = "lock_config",
+    mutable,
+    error = "Error"
 )]
-fn contract_upgrade<S: HasStateApi>(
+fn contract_lock_config<S: HasStateApi>(
     ctx: &impl HasReceiveContext,
     host: &mut impl HasHost<State<S>, StateApiType = S>,
-) -> ReceiveResult<()> {
-    ensure!(ctx.sender().matches_account(&ctx.owner()));
-    let params: UpgradeParams = ctx.parameter_cursor().get()?;
-    host.upgrade(params.module)?;
-    if let Some((func, parameter)) = params.migrate {
-        host.invoke_contract_raw(
-            &ctx.self_address(),
-            parameter.as_parameter(),
-            func.as_entrypoint_name(),
-            Amount::zero(),
-        )?;
+) -> ContractResult<()> {
+    let state = host.state_mut();
+    ensure!(is_admin(ctx, &state.admin), Error::InvalidCaller);
+    record_admin_activity(ctx, state, "lock_config");
+    if update_field(&mut state.config_locked, true) {
+        state.state_seq += 1;
     }
     Ok(())
 }
 
-/// View the admin state.
+/// Freeze the curator set so that curators can not be added or removed anymore.
+/// Existing curators can still curate projects. Once frozen, the curator set can only be unfrozen by
+/// `reset_governance_flags`.
 ///
-/// Caller: Admin account only.
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
 #[receive(
     contract = "overlay-users",
-    name = "view_admin",
-    return_value = "ViewAdminRes"
+    name = "freeze_curators",
+    mutable,
+    error = "Error"
 )]
-fn contract_view_admin<S: HasStateApi>(
+fn contract_freeze_curators<S: HasStateApi>(
     ctx: &impl HasReceiveContext,
-    host: &impl HasHost<State<S>, StateApiType = S>,
-) -> ContractResult<ViewAdminRes> {
-    let state = host.state();
-    ensure!(ctx.invoker() == state.admin, Error::InvalidCaller);
-    Ok(ViewAdminRes {
-        admin: state.admin,
-        project_contract_addr: state.project_contract_addr,
-        curator_list: state.curator_list.clone(),
-        validator_list: state.validator_list.clone(),
-    })
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<()> {
+    let state = host.state_mut();
+    ensure!(is_admin(ctx, &state.admin), Error::InvalidCaller);
+    record_admin_activity(ctx, state, "freeze_curators");
+    if update_field(&mut state.curators_frozen, true) {
+        state.state_seq += 1;
+    }
+    Ok(())
 }
 
-/// View the user state.
-/// If the requested user address dose not exist in the state, it returns the default data.
+/// Reset the governance flags to recover from a tangled governance state.
+/// Unlocks the configuration and unfreezes the curator set in one call.
+/// The contract has neither a pending admin nor a pause flag, so there is nothing else to reset.
 ///
-/// Caller: Any accounts / Any contracts
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
+#[receive(
+    contract = "overlay-users",
+    name = "reset_governance_flags",
+    mutable,
+    error = "Error"
+)]
+fn contract_reset_governance_flags<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<()> {
+    let state = host.state_mut();
+    ensure!(is_admin(ctx, &state.admin), Error::InvalidCaller);
+    record_admin_activity(ctx, state, "reset_governance_flags");
+    let changed = update_field(&mut state.config_locked, false)
+        | update_field(&mut state.curators_frozen, false);
+    if changed {
+        state.state_seq += 1;
+    }
+    Ok(())
+}
+
+/// Set whether a user may hold the same project id as both curated and validated.
 ///
-/// This function is designed to be called by the following smart contract functions.
-/// * overlay-projects.curate_project
-/// * overlay-projects.curate_project_admin
-/// * overlay-projects.validate_project
-/// * overlay-projects.validate_project_admin
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
 #[receive(
     contract = "overlay-users",
-    name = "view_user",
-    parameter = "ViewUserParams",
-    return_value = "UserState"
+    name = "set_disjoint_project_roles",
+    parameter = "SetDisjointProjectRolesParams",
+    mutable,
+    error = "Error"
 )]
-fn contract_view_user<S: HasStateApi>(
+fn contract_set_disjoint_project_roles<S: HasStateApi>(
     ctx: &impl HasReceiveContext,
-    host: &impl HasHost<State<S>, StateApiType = S>,
-) -> ContractResult<ViewUserResponse> {
-    let params: ViewUserParams = ctx.parameter_cursor().get()?;
-    let state = host.state();
-    let user_state = state
-        .user
-        .get(&params.addr)
-        .map(|user_state_ref| user_state_ref.clone())
-        .unwrap_or(UserState {
-            is_curator: false,
-            is_validator: false,
-            curated_projects: Vec::new(),
-            validated_projects: Vec::new(),
-        });
-    Ok(user_state)
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<()> {
+    let params: SetDisjointProjectRolesParams = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+    ensure!(is_admin(ctx, &state.admin), Error::InvalidCaller);
+    record_admin_activity(ctx, state, "set_disjoint_project_roles");
+    if update_field(
+        &mut state.disjoint_project_roles,
+        params.disjoint_project_roles,
+    ) {
+        state.state_seq += 1;
+    }
+    Ok(())
 }
 
-/// View the all user state.
+/// Set whether `remove_curator` and `remove_validator` delete users left without roles and projects.
 ///
-/// Caller: Any accounts / Any contracts
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
 #[receive(
     contract = "overlay-users",
-    name = "view_users",
-    return_value = "ViewUsersResponse"
+    name = "set_auto_prune_on_remove",
+    parameter = "SetAutoPruneOnRemoveParams",
+    mutable,
+    error = "Error"
 )]
-fn contract_view_users<S: HasStateApi>(
-    _ctx: &impl HasReceiveContext,
-    host: &impl HasHost<State<S>, StateApiType = S>,
-) -> ContractResult<ViewUsersResponse> {
-    let users = &host.state().user;
-    let users_response = users
-        .iter()
-        .map(|(account_address_ref, user_state_ref)| {
-            (account_address_ref.clone(), user_state_ref.clone())
-        })
-        .collect();
-    Ok(users_response)
+fn contract_set_auto_prune_on_remove<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<()> {
+    let params: SetAutoPruneOnRemoveParams = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+    ensure!(is_admin(ctx, &state.admin), Error::InvalidCaller);
+    record_admin_activity(ctx, state, "set_auto_prune_on_remove");
+    if update_field(&mut state.auto_prune_on_remove, params.auto_prune_on_remove) {
+        state.state_seq += 1;
+    }
+    Ok(())
 }
 
-/// implements Debug for State inside test functions.
-/// this implementation will be build only when `concordium-std/wasm-test` feature is active.
-/// (e.g. when launched by `cargo concordium test`)
-#[concordium_cfg_test]
-impl<S: HasStateApi> Debug for State<S> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "admin: {:?}, project_contract_addr: {:?}, ",
-            self.admin, self.project_contract_addr
-        )?;
-        for (address, state) in self.user.iter() {
-            write!(f, "user_address: {:?}, user_state: {:?}, ", address, state)?;
-        }
-        write!(
-            f,
-            "curator_list: {:?}, validator_list: {:?}",
-            self.curator_list, self.validator_list
-        )
+/// Set whether `curate` and `validate` reject the admin account as a target user.
+///
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
+#[receive(
+    contract = "overlay-users",
+    name = "set_reject_privileged_targets",
+    parameter = "SetRejectPrivilegedTargetsParams",
+    mutable,
+    error = "Error"
+)]
+fn contract_set_reject_privileged_targets<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<()> {
+    let params: SetRejectPrivilegedTargetsParams = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+    ensure!(is_admin(ctx, &state.admin), Error::InvalidCaller);
+    record_admin_activity(ctx, state, "set_reject_privileged_targets");
+    if update_field(
+        &mut state.reject_privileged_targets,
+        params.reject_privileged_targets,
+    ) {
+        state.state_seq += 1;
     }
+    Ok(())
 }
 
-/// implements PartialEq for `claim_eq` inside test functions.
-/// this implementation will be build only when `concordium-std/wasm-test` feature is active.
-/// (e.g. when launched by `cargo concordium test`)
-#[concordium_cfg_test]
-impl<S: HasStateApi> PartialEq for State<S> {
-    fn eq(&self, other: &Self) -> bool {
-        if self.admin != other.admin {
-            return false;
-        }
-        if self.project_contract_addr != other.project_contract_addr {
-            return false;
-        }
-        if self.user.iter().count() != other.user.iter().count() {
-            return false;
-        }
-        for (my_user_address, my_user_state) in self.user.iter() {
-            let other_user_state = other.user.get(&my_user_address);
-            if other_user_state.is_none() {
-                return false;
-            }
-            let other_user_state = other_user_state.unwrap();
-            if my_user_state.clone() != other_user_state.clone() {
-                return false;
-            }
-        }
-        if self.curator_list != other.curator_list {
-            return false;
-        }
-        if self.validator_list != other.validator_list {
-            return false;
-        }
-        true
+/// Set whether `add_curator` and `add_validator` reject users that are not registered yet.
+///
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
+#[receive(
+    contract = "overlay-users",
+    name = "set_require_known_users",
+    parameter = "SetRequireKnownUsersParams",
+    mutable,
+    error = "Error"
+)]
+fn contract_set_require_known_users<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<()> {
+    let params: SetRequireKnownUsersParams = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+    ensure!(is_admin(ctx, &state.admin), Error::InvalidCaller);
+    record_admin_activity(ctx, state, "set_require_known_users");
+    if update_field(&mut state.require_known_users, params.require_known_users) {
+        state.state_seq += 1;
+    }
+    Ok(())
+}
+
+/// Set whether `remove_curator` and `remove_validator` reject users that do not exist in the state
+/// instead of doing nothing.
+///
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
+#[receive(
+    contract = "overlay-users",
+    name = "set_strict_removals",
+    parameter = "SetStrictRemovalsParams",
+    mutable,
+    error = "Error"
+)]
+fn contract_set_strict_removals<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<()> {
+    let params: SetStrictRemovalsParams = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+    ensure!(is_admin(ctx, &state.admin), Error::InvalidCaller);
+    record_admin_activity(ctx, state, "set_strict_removals");
+    if update_field(&mut state.strict_removals, params.strict_removals) {
+        state.state_seq += 1;
+    }
+    Ok(())
+}
+
+/// Set whether `view_users` and `view_users_after` can be called by anyone or only by the admin.
+///
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
+#[receive(
+    contract = "overlay-users",
+    name = "set_users_view_public",
+    parameter = "SetUsersViewPublicParams",
+    mutable,
+    error = "Error"
+)]
+fn contract_set_users_view_public<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<()> {
+    let params: SetUsersViewPublicParams = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+    ensure!(is_admin(ctx, &state.admin), Error::InvalidCaller);
+    record_admin_activity(ctx, state, "set_users_view_public");
+    if update_field(&mut state.users_view_public, params.users_view_public) {
+        state.state_seq += 1;
+    }
+    Ok(())
+}
+
+/// Set whether the contract functions log events.
+/// The state is updated in the same way regardless of this setting.
+///
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
+#[receive(
+    contract = "overlay-users",
+    name = "set_events_enabled",
+    parameter = "SetEventsEnabledParams",
+    mutable,
+    error = "Error"
+)]
+fn contract_set_events_enabled<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<()> {
+    let params: SetEventsEnabledParams = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+    ensure!(is_admin(ctx, &state.admin), Error::InvalidCaller);
+    record_admin_activity(ctx, state, "set_events_enabled");
+    if update_field(&mut state.events_enabled, params.events_enabled) {
+        state.state_seq += 1;
     }
+    Ok(())
+}
+
+/// Set whether `curate` registers an unknown user as a curator instead of rejecting it.
+///
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
+#[receive(
+    contract = "overlay-users",
+    name = "set_curate_auto_create",
+    parameter = "SetCurateAutoCreateParams",
+    mutable,
+    error = "Error"
+)]
+fn contract_set_curate_auto_create<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<()> {
+    let params: SetCurateAutoCreateParams = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+    ensure!(is_admin(ctx, &state.admin), Error::InvalidCaller);
+    record_admin_activity(ctx, state, "set_curate_auto_create");
+    if update_field(&mut state.curate_auto_create, params.curate_auto_create) {
+        state.state_seq += 1;
+    }
+    Ok(())
+}
+
+/// Set the weights of the curated and validated projects in the score returned by `view_user_score`.
+///
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
+#[receive(
+    contract = "overlay-users",
+    name = "set_score_weights",
+    parameter = "SetScoreWeightsParams",
+    mutable,
+    error = "Error"
+)]
+fn contract_set_score_weights<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<()> {
+    let params: SetScoreWeightsParams = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+    ensure!(is_admin(ctx, &state.admin), Error::InvalidCaller);
+    record_admin_activity(ctx, state, "set_score_weights");
+    let changed = update_field(&mut state.curator_weight, params.curator_weight)
+        | update_field(&mut state.validator_weight, params.validator_weight);
+    if changed {
+        state.state_seq += 1;
+    }
+    Ok(())
+}
+
+/// Set the minimum time between two role changes of the same user.
+/// A zero duration disables the cooldown.
+///
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
+#[receive(
+    contract = "overlay-users",
+    name = "set_role_cooldown",
+    parameter = "SetRoleCooldownParams",
+    mutable,
+    error = "Error"
+)]
+fn contract_set_role_cooldown<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<()> {
+    let params: SetRoleCooldownParams = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+    ensure!(is_admin(ctx, &state.admin), Error::InvalidCaller);
+    record_admin_activity(ctx, state, "set_role_cooldown");
+    if update_field(&mut state.role_cooldown, params.role_cooldown) {
+        state.state_seq += 1;
+    }
+    Ok(())
+}
+
+/// Set the maximum number of projects a user can curate and validate in total.
+/// `None` removes the limit. Users with their own `max_projects` are not affected.
+///
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
+#[receive(
+    contract = "overlay-users",
+    name = "set_max_projects_per_user",
+    parameter = "SetMaxProjectsPerUserParams",
+    mutable,
+    error = "Error"
+)]
+fn contract_set_max_projects_per_user<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<()> {
+    let params: SetMaxProjectsPerUserParams = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+    ensure!(is_admin(ctx, &state.admin), Error::InvalidCaller);
+    record_admin_activity(ctx, state, "set_max_projects_per_user");
+    if update_field(
+        &mut state.max_projects_per_user,
+        params.max_projects_per_user,
+    ) {
+        state.state_seq += 1;
+    }
+    Ok(())
+}
+
+/// Set the maximum number of projects the inputted user can curate and validate in total.
+/// `None` makes the user fall back to `max_projects_per_user`.
+///
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
+/// * The user does not exist in the state.
+#[receive(
+    contract = "overlay-users",
+    name = "set_user_max_projects",
+    parameter = "SetUserMaxProjectsParams",
+    mutable,
+    error = "Error"
+)]
+fn contract_set_user_max_projects<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<()> {
+    let params: SetUserMaxProjectsParams = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+    ensure!(is_admin(ctx, &state.admin), Error::InvalidCaller);
+    record_admin_activity(ctx, state, "set_user_max_projects");
+    let changed = {
+        let mut user_state = state
+            .user
+            .get_mut(&params.addr)
+            .ok_or(Error::UserNotFound)?;
+        update_field(&mut user_state.max_projects, params.max_projects)
+    };
+    if changed {
+        state.state_seq += 1;
+    }
+    Ok(())
+}
+
+/// Set the role flags of the user data inserted when a new user is added to the state.
+/// The project lists of the new user data are always empty.
+///
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
+#[receive(
+    contract = "overlay-users",
+    name = "set_default_user_state",
+    parameter = "SetDefaultUserStateParams",
+    mutable,
+    error = "Error"
+)]
+fn contract_set_default_user_state<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<()> {
+    let params: SetDefaultUserStateParams = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+    ensure!(is_admin(ctx, &state.admin), Error::InvalidCaller);
+    record_admin_activity(ctx, state, "set_default_user_state");
+    let changed = state.default_user_state.is_curator != params.is_curator
+        || state.default_user_state.is_validator != params.is_validator;
+    state.default_user_state = UserState {
+        is_curator: params.is_curator,
+        is_validator: params.is_validator,
+        ..UserState::default()
+    };
+    if changed {
+        state.state_seq += 1;
+    }
+    Ok(())
+}
+
+/// Register inputted user account without any roles.
+/// Registering a user that already exists in the state does nothing.
+/// Once registered, `add_curator` and `add_validator` only update the role flags of the user.
+///
+/// Caller: current admin account, or the inputted user account itself.
+/// Reject if:
+/// * Caller is neither the current admin account nor the inputted user account.
+/// * The user has been soft-deleted (`UserDeleted`).
+#[receive(
+    contract = "overlay-users",
+    name = "register_user",
+    parameter = "RegisterUserParams",
+    mutable,
+    error = "Error"
+)]
+fn contract_register_user<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<()> {
+    let params: RegisterUserParams = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+    ensure!(
+        is_admin(ctx, &state.admin) || ctx.sender().matches_account(&params.addr),
+        Error::InvalidCaller
+    );
+    ensure_not_deleted(state, &params.addr)?;
+    if state.user.get(&params.addr).is_none() {
+        let user_state = UserState {
+            is_curator: false,
+            is_validator: false,
+            created_at: Some(ctx.metadata().slot_time()),
+            ..state.default_user_state.clone()
+        };
+        state.user.insert(params.addr, user_state);
+        state.cached_counts.user_count += 1;
+        state.state_seq += 1;
+    }
+    Ok(())
+}
+
+/// Apply for the inputted role as the calling account.
+/// The application is kept until the role is granted or the admin dismisses it with `dismiss_application`.
+///
+/// Caller: Any accounts
+/// Reject if:
+/// * Caller is a contract.
+/// * The caller already holds the role (`InvalidArgument`).
+/// * The user has been soft-deleted (`UserDeleted`).
+/// * `MAX_PENDING_APPLICATIONS` accounts have already applied for the role (`TooManyPendingApplications`).
+#[receive(
+    contract = "overlay-users",
+    name = "apply_for_role",
+    parameter = "ApplyForRoleParams",
+    mutable,
+    error = "Error"
+)]
+fn contract_apply_for_role<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<()> {
+    let role: ApplyForRoleParams = ctx.parameter_cursor().get()?;
+    let applicant = match ctx.sender() {
+        Address::Account(addr) => addr,
+        Address::Contract(_) => return Err(Error::InvalidCaller),
+    };
+    let state = host.state_mut();
+    ensure_not_deleted(state, &applicant)?;
+    let has_role = state
+        .user
+        .get(&applicant)
+        .map_or(false, |user_state| match role {
+            Role::Curator => user_state.is_curator,
+            Role::Validator => user_state.is_validator,
+        });
+    ensure!(!has_role, Error::InvalidArgument);
+    let (pending, pending_count) = match role {
+        Role::Curator => (
+            &mut state.pending_curators,
+            &mut state.pending_curator_count,
+        ),
+        Role::Validator => (
+            &mut state.pending_validators,
+            &mut state.pending_validator_count,
+        ),
+    };
+    if !pending.contains(&applicant) {
+        ensure!(
+            (*pending_count as usize) < MAX_PENDING_APPLICATIONS,
+            Error::TooManyPendingApplications
+        );
+        pending.insert(applicant);
+        *pending_count += 1;
+        state.state_seq += 1;
+    }
+    Ok(())
+}
+
+/// Dismiss the pending application of the inputted user for the role without granting it.
+/// Dismissing an application that does not exist does nothing.
+///
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
+#[receive(
+    contract = "overlay-users",
+    name = "dismiss_application",
+    parameter = "DismissApplicationParams",
+    mutable,
+    error = "Error"
+)]
+fn contract_dismiss_application<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<()> {
+    let params: DismissApplicationParams = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+    ensure!(is_admin(ctx, &state.admin), Error::InvalidCaller);
+    record_admin_activity(ctx, state, "dismiss_application");
+    if clear_application(state, params.role, &params.addr) {
+        state.state_seq += 1;
+    }
+    Ok(())
+}
+
+/// Update inputted user account as a curator.
+/// If the requested user address dose not exist in the state, default user data would be created.
+///
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
+/// * The curator set is frozen.
+/// * `require_known_users` is set and the user does not exist in the state (`UserNotFound`).
+/// * The user is not a curator and its roles have been changed within `role_cooldown` (`CooldownActive`).
+/// * The user has been soft-deleted (`UserDeleted`).
+#[receive(
+    contract = "overlay-users",
+    name = "add_curator",
+    parameter = "AddCuratorParams",
+    mutable
+)]
+fn contract_add_curator<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>>,
+) -> ContractResult<()> {
+    let params: AddCuratorParams = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+    ensure!(is_admin(ctx, &state.admin), Error::InvalidCaller);
+    record_admin_activity(ctx, state, "add_curator");
+    check_add_role(ctx, state, Role::Curator, &params.addr)?;
+    let now = ctx.metadata().slot_time();
+    if grant_curator(state, &params.addr, now) {
+        state.state_seq += 1;
+    }
+    Ok(())
+}
+
+/// Check whether `add_curator` would accept the inputted user account when called by the caller,
+/// without updating the state. Returns the error `add_curator` would reject with, if any.
+///
+/// Caller: Any accounts / Any contracts
+#[receive(
+    contract = "overlay-users",
+    name = "can_add_curator",
+    parameter = "CanAddCuratorParams",
+    return_value = "CanAddCuratorResponse"
+)]
+fn contract_can_add_curator<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<CanAddCuratorResponse> {
+    let params: CanAddCuratorParams = ctx.parameter_cursor().get()?;
+    let state = host.state();
+    let result = if is_admin(ctx, &state.admin) {
+        check_add_role(ctx, state, Role::Curator, &params.addr)
+    } else {
+        Err(Error::InvalidCaller)
+    };
+    Ok(CanAddCuratorResponse {
+        allowed: result.is_ok(),
+        reason: result.err(),
+    })
+}
+
+/// Unmark inputted user account as a curator.
+/// If `auto_prune_on_remove` is set and the user is left without roles and projects,
+/// the user is removed from the state.
+/// The removal is logged as a `RemoveCurator` event with the optional reason.
+///
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
+/// * The curator set is frozen.
+/// * The reason is longer than `MAX_REASON_LEN` bytes.
+/// * `strict_removals` is set and the user does not exist in the state (`UserNotFound`).
+/// * The user is a curator and its roles have been changed within `role_cooldown` (`CooldownActive`).
+#[receive(
+    contract = "overlay-users",
+    name = "remove_curator",
+    parameter = "RemoveCuratorParams",
+    mutable,
+    enable_logger
+)]
+fn contract_remove_curator<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>>,
+    logger: &mut impl HasLogger,
+) -> ContractResult<()> {
+    let params: RemoveCuratorParams = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+    ensure!(is_admin(ctx, &state.admin), Error::InvalidCaller);
+    record_admin_activity(ctx, state, "remove_curator");
+    ensure!(!state.curators_frozen, Error::CuratorsFrozen);
+    if let Some(reason) = &params.reason {
+        ensure!(reason.len() <= MAX_REASON_LEN, Error::InvalidArgument);
+    }
+    ensure!(
+        !state.strict_removals || state.user.get(&params.addr).is_some(),
+        Error::UserNotFound
+    );
+    let now = ctx.metadata().slot_time();
+    let was_curator = state
+        .user
+        .get(&params.addr)
+        .map_or(false, |user_state| user_state.is_curator);
+    if was_curator {
+        ensure_role_cooldown_elapsed(state, &params.addr, now)?;
+    }
+    state.user.entry(params.addr).and_modify(|user_state| {
+        if user_state.is_curator {
+            user_state.last_role_change = Some(now);
+        }
+        user_state.is_curator = false;
+        user_state.curator_since = None;
+    });
+    let listed_count = state.curator_list.len();
+    state.curator_list.retain(|x| *x != params.addr);
+    let mut changed = was_curator || state.curator_list.len() != listed_count;
+    if state.auto_prune_on_remove && prune_user(&mut state.user, &params.addr) {
+        state.cached_counts.user_count = state.cached_counts.user_count.saturating_sub(1);
+        changed = true;
+    }
+    refresh_list_counts(state);
+    log_event(logger, state, |event_seq| {
+        Event::RemoveCurator(RemoveRoleEvent {
+            event_seq,
+            addr: params.addr,
+            reason: params.reason,
+        })
+    })?;
+    if changed {
+        state.state_seq += 1;
+    }
+    Ok(())
+}
+
+/// Update inputted user account as a validator.
+/// If the requested user address dose not exist in the state, default user data would be created.
+///
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
+/// * `require_known_users` is set and the user does not exist in the state (`UserNotFound`).
+/// * The user is not a validator and its roles have been changed within `role_cooldown` (`CooldownActive`).
+/// * The user has been soft-deleted (`UserDeleted`).
+#[receive(
+    contract = "overlay-users",
+    name = "add_validator",
+    parameter = "AddValidatorParams",
+    mutable
+)]
+fn contract_add_validator<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>>,
+) -> ContractResult<()> {
+    let params: AddValidatorParams = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+    ensure!(is_admin(ctx, &state.admin), Error::InvalidCaller);
+    record_admin_activity(ctx, state, "add_validator");
+    check_add_role(ctx, state, Role::Validator, &params.addr)?;
+    let now = ctx.metadata().slot_time();
+    let default_user_state = state.default_user_state.clone();
+    let is_new_user = state.user.get(&params.addr).is_none();
+    let is_new_curator = default_user_state.is_curator && is_new_user;
+    let was_validator = state
+        .user
+        .get(&params.addr)
+        .map_or(false, |user_state| user_state.is_validator);
+    let mut changed = !was_validator;
+    state
+        .user
+        .entry(params.addr)
+        .and_modify(|user_state| {
+            if !user_state.is_validator {
+                user_state.last_role_change = Some(now);
+            }
+            user_state.set_validator(true, now);
+        })
+        .or_insert_with(|| {
+            let mut user_state = UserState::from_template(&default_user_state, now);
+            user_state.set_validator(true, now);
+            user_state.last_role_change = Some(now);
+            user_state
+        });
+    if !state.validator_list.contains(&params.addr) {
+        state.validator_list.push(params.addr);
+        changed = true;
+    }
+    if is_new_curator && !state.curator_list.contains(&params.addr) {
+        state.curator_list.push(params.addr);
+    }
+    if is_new_user {
+        state.cached_counts.user_count += 1;
+    }
+    refresh_list_counts(state);
+    changed |= clear_application(state, Role::Validator, &params.addr);
+    if changed {
+        state.state_seq += 1;
+    }
+    Ok(())
+}
+
+/// Unmark inputted user account as a validator.
+/// If `auto_prune_on_remove` is set and the user is left without roles and projects,
+/// the user is removed from the state.
+/// The removal is logged as a `RemoveValidator` event with the optional reason.
+///
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
+/// * The reason is longer than `MAX_REASON_LEN` bytes.
+/// * `strict_removals` is set and the user does not exist in the state (`UserNotFound`).
+/// * `force` is not set and the user still has validated projects.
+/// * The user is a validator and its roles have been changed within `role_cooldown` (`CooldownActive`).
+#[receive(
+    contract = "overlay-users",
+    name = "remove_validator",
+    parameter = "RemoveValidatorParams",
+    mutable,
+    enable_logger
+)]
+fn contract_remove_validator<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>>,
+    logger: &mut impl HasLogger,
+) -> ContractResult<()> {
+    let params: RemoveValidatorParams = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+    ensure!(is_admin(ctx, &state.admin), Error::InvalidCaller);
+    record_admin_activity(ctx, state, "remove_validator");
+    if let Some(reason) = &params.reason {
+        ensure!(reason.len() <= MAX_REASON_LEN, Error::InvalidArgument);
+    }
+    ensure!(
+        !state.strict_removals || state.user.get(&params.addr).is_some(),
+        Error::UserNotFound
+    );
+    if !params.force {
+        if let Some(user_state) = state.user.get(&params.addr) {
+            ensure!(
+                user_state.validated_projects.is_empty(),
+                Error::HasActiveProjects
+            );
+        }
+    }
+    let now = ctx.metadata().slot_time();
+    let was_validator = state
+        .user
+        .get(&params.addr)
+        .map_or(false, |user_state| user_state.is_validator);
+    if was_validator {
+        ensure_role_cooldown_elapsed(state, &params.addr, now)?;
+    }
+
+    state.user.entry(params.addr).and_modify(|user_state| {
+        if user_state.is_validator {
+            user_state.last_role_change = Some(now);
+        }
+        user_state.is_validator = false;
+        user_state.validator_since = None;
+    });
+    let listed_count = state.validator_list.len();
+    state.validator_list.retain(|x| *x != params.addr);
+    let mut changed = was_validator || state.validator_list.len() != listed_count;
+    if state.auto_prune_on_remove && prune_user(&mut state.user, &params.addr) {
+        state.cached_counts.user_count = state.cached_counts.user_count.saturating_sub(1);
+        changed = true;
+    }
+    refresh_list_counts(state);
+    log_event(logger, state, |event_seq| {
+        Event::RemoveValidator(RemoveRoleEvent {
+            event_seq,
+            addr: params.addr,
+            reason: params.reason,
+        })
+    })?;
+    if changed {
+        state.state_seq += 1;
+    }
+    Ok(())
+}
+
+/// Switch inputted user account between the curator and validator roles in one transaction.
+/// The target role is granted and the other role is removed, updating both lists.
+/// The removal of a held role is logged as a `RemoveCurator` or `RemoveValidator` event.
+///
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
+/// * The curator set is frozen.
+/// * The user does not exist in the state (`UserNotFound`).
+/// * The user already holds the target role (`InvalidArgument`).
+/// * The user has been soft-deleted (`UserDeleted`).
+/// * `force` is not set and the user is a validator with validated projects switching to the curator role.
+/// * The roles of the user have been changed within `role_cooldown` (`CooldownActive`).
+#[receive(
+    contract = "overlay-users",
+    name = "switch_role",
+    parameter = "SwitchRoleParams",
+    mutable,
+    enable_logger,
+    error = "Error"
+)]
+fn contract_switch_role<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+    logger: &mut impl HasLogger,
+) -> ContractResult<()> {
+    let params: SwitchRoleParams = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+    ensure!(is_admin(ctx, &state.admin), Error::InvalidCaller);
+    record_admin_activity(ctx, state, "switch_role");
+    ensure!(!state.curators_frozen, Error::CuratorsFrozen);
+    let now = ctx.metadata().slot_time();
+    let removed_role_held = {
+        let user_state = state.user.get(&params.addr).ok_or(Error::UserNotFound)?;
+        ensure!(!user_state.deleted, Error::UserDeleted);
+        let (already_held, removed_role_held) = if params.to_curator {
+            (user_state.is_curator, user_state.is_validator)
+        } else {
+            (user_state.is_validator, user_state.is_curator)
+        };
+        ensure!(!already_held, Error::InvalidArgument);
+        ensure!(
+            !params.to_curator
+                || !user_state.is_validator
+                || params.force
+                || user_state.validated_projects.is_empty(),
+            Error::HasActiveProjects
+        );
+        removed_role_held
+    };
+    ensure_role_cooldown_elapsed(state, &params.addr, now)?;
+    state.user.entry(params.addr).and_modify(|user_state| {
+        user_state.set_curator(params.to_curator, now);
+        user_state.set_validator(!params.to_curator, now);
+        user_state.last_role_change = Some(now);
+    });
+    let (to_list, from_list) = if params.to_curator {
+        (&mut state.curator_list, &mut state.validator_list)
+    } else {
+        (&mut state.validator_list, &mut state.curator_list)
+    };
+    from_list.retain(|x| *x != params.addr);
+    if !to_list.contains(&params.addr) {
+        to_list.push(params.addr);
+    }
+    refresh_list_counts(state);
+    clear_application(
+        state,
+        if params.to_curator {
+            Role::Curator
+        } else {
+            Role::Validator
+        },
+        &params.addr,
+    );
+    if removed_role_held {
+        log_event(logger, state, |event_seq| {
+            let event = RemoveRoleEvent {
+                event_seq,
+                addr: params.addr,
+                reason: None,
+            };
+            if params.to_curator {
+                Event::RemoveValidator(event)
+            } else {
+                Event::RemoveCurator(event)
+            }
+        })?;
+    }
+    state.state_seq += 1;
+    Ok(())
+}
+
+/// Soft-delete inputted user account.
+/// The user entry is kept as an audit stub marked as `deleted`,
+/// while the roles and the projects are cleared and the user is removed from the role lists.
+///
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
+/// * The user does not exist in the state.
+#[receive(
+    contract = "overlay-users",
+    name = "soft_delete_user",
+    parameter = "SoftDeleteUserParams",
+    mutable,
+    error = "Error"
+)]
+fn contract_soft_delete_user<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<()> {
+    let params: SoftDeleteUserParams = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+    ensure!(is_admin(ctx, &state.admin), Error::InvalidCaller);
+    record_admin_activity(ctx, state, "soft_delete_user");
+    let was_deleted = {
+        let mut user_state = state
+            .user
+            .get_mut(&params.addr)
+            .ok_or(Error::UserNotFound)?;
+        let was_deleted = user_state.deleted;
+        user_state.deleted = true;
+        user_state.is_curator = false;
+        user_state.is_validator = false;
+        user_state.curator_since = None;
+        user_state.validator_since = None;
+        user_state.curated_projects.clear();
+        user_state.validated_projects.clear();
+        was_deleted
+    };
+    state.curator_list.retain(|x| *x != params.addr);
+    state.validator_list.retain(|x| *x != params.addr);
+    refresh_list_counts(state);
+    clear_application(state, Role::Curator, &params.addr);
+    clear_application(state, Role::Validator, &params.addr);
+    sync_curation_scores(state, &params.addr);
+    if !was_deleted {
+        state.state_seq += 1;
+    }
+    Ok(())
+}
+
+/// Add the project id to the projects allowed to be curated and validated.
+/// Once `allowed_projects` is not empty, only the project ids in it are accepted.
+///
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
+#[receive(
+    contract = "overlay-users",
+    name = "allow_project",
+    parameter = "AllowProjectParams",
+    mutable,
+    error = "Error"
+)]
+fn contract_allow_project<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<()> {
+    let project_id: AllowProjectParams = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+    ensure!(is_admin(ctx, &state.admin), Error::InvalidCaller);
+    record_admin_activity(ctx, state, "allow_project");
+    if state.allowed_projects.insert(project_id) {
+        state.state_seq += 1;
+    }
+    Ok(())
+}
+
+/// Remove the project id from the projects allowed to be curated and validated.
+/// Removing the last project id allows all the project ids again.
+///
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
+#[receive(
+    contract = "overlay-users",
+    name = "disallow_project",
+    parameter = "DisallowProjectParams",
+    mutable,
+    error = "Error"
+)]
+fn contract_disallow_project<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<()> {
+    let project_id: DisallowProjectParams = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+    ensure!(is_admin(ctx, &state.admin), Error::InvalidCaller);
+    record_admin_activity(ctx, state, "disallow_project");
+    if state.allowed_projects.remove(&project_id) {
+        state.state_seq += 1;
+    }
+    Ok(())
+}
+
+/// Add the account to the observers that can read the admin state with `view_admin`.
+///
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
+#[receive(
+    contract = "overlay-users",
+    name = "add_observer",
+    parameter = "AddObserverParams",
+    mutable,
+    error = "Error"
+)]
+fn contract_add_observer<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<()> {
+    let params: AddObserverParams = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+    ensure!(is_admin(ctx, &state.admin), Error::InvalidCaller);
+    record_admin_activity(ctx, state, "add_observer");
+    if state.observers.insert(params.addr) {
+        state.state_seq += 1;
+    }
+    Ok(())
+}
+
+/// Remove the account from the observers.
+///
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
+#[receive(
+    contract = "overlay-users",
+    name = "remove_observer",
+    parameter = "RemoveObserverParams",
+    mutable,
+    error = "Error"
+)]
+fn contract_remove_observer<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<()> {
+    let params: RemoveObserverParams = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+    ensure!(is_admin(ctx, &state.admin), Error::InvalidCaller);
+    record_admin_activity(ctx, state, "remove_observer");
+    if state.observers.remove(&params.addr) {
+        state.state_seq += 1;
+    }
+    Ok(())
+}
+
+/// Remove the project id from the curated and validated projects of every user.
+/// The roles of the users are kept even if they are left without projects.
+/// Returns the number of the users whose projects have been changed.
+///
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
+#[receive(
+    contract = "overlay-users",
+    name = "purge_project_id",
+    parameter = "PurgeProjectIdParams",
+    return_value = "u32",
+    mutable,
+    error = "Error"
+)]
+fn contract_purge_project_id<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<u32> {
+    let project_id: PurgeProjectIdParams = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+    ensure!(is_admin(ctx, &state.admin), Error::InvalidCaller);
+    record_admin_activity(ctx, state, "purge_project_id");
+    // the map can not be mutated while iterating it, so collect the affected users first.
+    let affected: Vec<AccountAddress> = state
+        .user
+        .iter()
+        .filter(|(_, user_state)| {
+            user_state.has_curated(&project_id) || user_state.has_validated(&project_id)
+        })
+        .map(|(addr, _)| *addr)
+        .collect();
+    for addr in affected.iter() {
+        state.user.entry(*addr).and_modify(|user_state| {
+            user_state.curated_projects.retain(|x| *x != project_id);
+            user_state
+                .validated_projects
+                .retain(|(validated, _)| *validated != project_id);
+        });
+        sync_curation_scores(state, addr);
+    }
+    if !affected.is_empty() {
+        state.state_seq += 1;
+    }
+    Ok(affected.len() as u32)
+}
+
+/// Truncate the curated and validated projects of every user to the last `cap` entries each.
+/// The projects are regarded as appended in chronological order, so the oldest entries are removed.
+/// Returns the total number of the removed entries.
+///
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
+#[receive(
+    contract = "overlay-users",
+    name = "trim_user_projects",
+    parameter = "TrimUserProjectsParams",
+    return_value = "u32",
+    mutable,
+    error = "Error"
+)]
+fn contract_trim_user_projects<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<u32> {
+    let cap: TrimUserProjectsParams = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+    ensure!(is_admin(ctx, &state.admin), Error::InvalidCaller);
+    record_admin_activity(ctx, state, "trim_user_projects");
+    let cap = cap as usize;
+    // the map can not be mutated while iterating it, so collect the affected users first.
+    let affected: Vec<AccountAddress> = state
+        .user
+        .iter()
+        .filter(|(_, user_state)| {
+            user_state.curated_projects.len() > cap || user_state.validated_projects.len() > cap
+        })
+        .map(|(addr, _)| *addr)
+        .collect();
+    let mut removed = 0;
+    for addr in affected.iter() {
+        state.user.entry(*addr).and_modify(|user_state| {
+            let curated_excess = user_state.curated_projects.len().saturating_sub(cap);
+            user_state.curated_projects.drain(..curated_excess);
+            let validated_excess = user_state.validated_projects.len().saturating_sub(cap);
+            user_state.validated_projects.drain(..validated_excess);
+            removed += curated_excess + validated_excess;
+        });
+        sync_curation_scores(state, addr);
+    }
+    if removed > 0 {
+        state.state_seq += 1;
+    }
+    Ok(removed as u32)
+}
+
+/// Remove duplicated addresses from `curator_list` and `validator_list`.
+/// The first occurrence of each address is kept, so the list order is preserved.
+///
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
+#[receive(
+    contract = "overlay-users",
+    name = "dedup_lists",
+    return_value = "DedupListsResponse",
+    mutable,
+    error = "Error"
+)]
+fn contract_dedup_lists<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<DedupListsResponse> {
+    let state = host.state_mut();
+    ensure!(is_admin(ctx, &state.admin), Error::InvalidCaller);
+    record_admin_activity(ctx, state, "dedup_lists");
+    let response = DedupListsResponse {
+        curator_duplicates: dedup_list(&mut state.curator_list),
+        validator_duplicates: dedup_list(&mut state.validator_list),
+    };
+    refresh_list_counts(state);
+    if response.curator_duplicates > 0 || response.validator_duplicates > 0 {
+        state.state_seq += 1;
+    }
+    Ok(response)
+}
+
+/// Rebuild `curator_list` and `validator_list` from the user state.
+/// The user state is regarded as authoritative; the current list entries are discarded.
+///
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
+#[receive(
+    contract = "overlay-users",
+    name = "backfill_lists",
+    return_value = "BackfillListsResponse",
+    mutable,
+    error = "Error"
+)]
+fn contract_backfill_lists<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<BackfillListsResponse> {
+    let state = host.state_mut();
+    ensure!(is_admin(ctx, &state.admin), Error::InvalidCaller);
+    record_admin_activity(ctx, state, "backfill_lists");
+    let mut curator_list = Vec::new();
+    let mut validator_list = Vec::new();
+    for (addr, user_state) in state.user.iter() {
+        if user_state.is_curator {
+            curator_list.push(*addr);
+        }
+        if user_state.is_validator {
+            validator_list.push(*addr);
+        }
+    }
+    let changed = update_field(&mut state.curator_list, curator_list)
+        | update_field(&mut state.validator_list, validator_list);
+    refresh_list_counts(state);
+    if changed {
+        state.state_seq += 1;
+    }
+    Ok(BackfillListsResponse {
+        curator_count: state.curator_list.len() as u32,
+        validator_count: state.validator_list.len() as u32,
+    })
+}
+
+/// Make the presence of the inputted user in `curator_list` and `validator_list` match its role flags.
+/// The user is added to a list if it holds the role and is missing from the list, and removed
+/// from a list if it is listed without holding the role. An unknown user holds no roles.
+/// Unlike `backfill_lists`, only the inputted user is checked.
+///
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
+#[receive(
+    contract = "overlay-users",
+    name = "resync_user",
+    parameter = "ResyncUserParams",
+    return_value = "ResyncUserResponse",
+    mutable,
+    error = "Error"
+)]
+fn contract_resync_user<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<ResyncUserResponse> {
+    let params: ResyncUserParams = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+    ensure!(is_admin(ctx, &state.admin), Error::InvalidCaller);
+    record_admin_activity(ctx, state, "resync_user");
+    let (is_curator, is_validator) = state
+        .user
+        .get(&params.addr)
+        .map(|user_state| (user_state.is_curator, user_state.is_validator))
+        .unwrap_or((false, false));
+    let response = ResyncUserResponse {
+        curator_list_adjusted: resync_list_entry(&mut state.curator_list, &params.addr, is_curator),
+        validator_list_adjusted: resync_list_entry(
+            &mut state.validator_list,
+            &params.addr,
+            is_validator,
+        ),
+    };
+    refresh_list_counts(state);
+    if response.curator_list_adjusted || response.validator_list_adjusted {
+        state.state_seq += 1;
+    }
+    Ok(response)
+}
+
+/// Store a copy of the whole user state in `last_snapshot`, replacing the previous snapshot.
+/// Returns the number of the stored users.
+/// Note that every user is copied on each call, so its cost grows with the number of
+/// the users and their projects. This is why the number of the users is bounded.
+///
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
+/// * More than `MAX_SNAPSHOT_USERS` users exist in the state (`InvalidArgument`).
+#[receive(
+    contract = "overlay-users",
+    name = "snapshot_users",
+    return_value = "u32",
+    mutable,
+    error = "Error"
+)]
+fn contract_snapshot_users<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<u32> {
+    let state = host.state_mut();
+    ensure!(is_admin(ctx, &state.admin), Error::InvalidCaller);
+    record_admin_activity(ctx, state, "snapshot_users");
+    let user_count = state.user.iter().count();
+    ensure!(user_count <= MAX_SNAPSHOT_USERS, Error::InvalidArgument);
+    state.last_snapshot.clear();
+    for (addr, user_state) in state.user.iter() {
+        state.last_snapshot.insert(*addr, user_state.clone());
+    }
+    state.last_snapshot_time = Some(ctx.metadata().slot_time());
+    state.state_seq += 1;
+    Ok(user_count as u32)
+}
+
+/// Replace the whole user state with `last_snapshot`, and rebuild `curator_list` and
+/// `validator_list` from it. The snapshot is kept, so it can be restored again.
+/// The curation scores of the projects that are not curated in the snapshot are dropped.
+///
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
+/// * No snapshot has been taken by `snapshot_users` function (`NoSnapshot`).
+#[receive(
+    contract = "overlay-users",
+    name = "restore_snapshot",
+    mutable,
+    error = "Error"
+)]
+fn contract_restore_snapshot<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<()> {
+    let state = host.state_mut();
+    ensure!(is_admin(ctx, &state.admin), Error::InvalidCaller);
+    record_admin_activity(ctx, state, "restore_snapshot");
+    ensure!(state.last_snapshot_time.is_some(), Error::NoSnapshot);
+    let mut changed = state.user.iter().count() != state.last_snapshot.iter().count()
+        || state.last_snapshot.iter().any(|(addr, user_state)| {
+            state.user.get(&*addr).map_or(true, |current| {
+                to_bytes(&*current) != to_bytes(&*user_state)
+            })
+        });
+    state.user.clear();
+    let mut curator_list = Vec::new();
+    let mut validator_list = Vec::new();
+    let mut user_count = 0;
+    for (addr, user_state) in state.last_snapshot.iter() {
+        user_count += 1;
+        if user_state.is_curator {
+            curator_list.push(*addr);
+        }
+        if user_state.is_validator {
+            validator_list.push(*addr);
+        }
+        state.user.insert(*addr, user_state.clone());
+    }
+    changed |= update_field(&mut state.curator_list, curator_list)
+        | update_field(&mut state.validator_list, validator_list);
+    state.cached_counts.user_count = user_count;
+    refresh_list_counts(state);
+    let scored_users: Vec<AccountAddress> = state
+        .curation_scores
+        .iter()
+        .map(|(addr, _)| *addr)
+        .collect();
+    for addr in scored_users.iter() {
+        sync_curation_scores(state, addr);
+    }
+    if changed {
+        state.state_seq += 1;
+    }
+    Ok(())
+}
+
+/// Mark multiple user accounts as curators together with their curated projects.
+/// The inputted project ids are appended to the curated projects of each user, skipping duplicates.
+/// If a requested user address dose not exist in the state, it is created in the same way as `add_curator`.
+/// Unless `skip_admin` is false, the entry for the admin account is skipped.
+/// Returns whether the admin account has been skipped.
+/// The granted roles are logged as a single `BatchRoleChange` event in the same way as
+/// `set_user_roles_batch`.
+///
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
+/// * The curator set is frozen.
+/// * More than `MAX_IMPORT_CURATORS` curators are inputted (`InvalidArgument`).
+/// * An inputted user has been soft-deleted (`UserDeleted`).
+/// * The roles of a new curator have been changed within `role_cooldown` (`CooldownActive`).
+#[receive(
+    contract = "overlay-users",
+    name = "import_curators",
+    parameter = "ImportCuratorsParams",
+    return_value = "bool",
+    mutable,
+    enable_logger,
+    error = "Error"
+)]
+fn contract_import_curators<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+    logger: &mut impl HasLogger,
+) -> ContractResult<bool> {
+    let params: ImportCuratorsParams = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+    ensure!(is_admin(ctx, &state.admin), Error::InvalidCaller);
+    record_admin_activity(ctx, state, "import_curators");
+    ensure!(!state.curators_frozen, Error::CuratorsFrozen);
+    ensure!(
+        params.curators.len() <= MAX_IMPORT_CURATORS,
+        Error::InvalidArgument
+    );
+    let now = ctx.metadata().slot_time();
+    let default_user_state = state.default_user_state.clone();
+    let skip_admin = params.skip_admin.unwrap_or(true);
+    let mut admin_skipped = false;
+    let mut curators_added = 0u32;
+    let mut validators_added = 0u32;
+    let mut changed = false;
+    for (addr, project_ids) in params.curators {
+        if skip_admin && state.admin == Address::Account(addr) {
+            admin_skipped = true;
+            continue;
+        }
+        ensure_not_deleted(state, &addr)?;
+        let was_curator = state
+            .user
+            .get(&addr)
+            .map_or(false, |user_state| user_state.is_curator);
+        if !was_curator {
+            ensure_role_cooldown_elapsed(state, &addr, now)?;
+            curators_added += 1;
+        }
+        let is_new_user = state.user.get(&addr).is_none();
+        let is_new_validator = default_user_state.is_validator && is_new_user;
+        if is_new_validator {
+            validators_added += 1;
+        }
+        let mut user_state = state
+            .user
+            .entry(addr)
+            .or_insert_with(|| UserState::from_template(&default_user_state, now));
+        if !was_curator {
+            user_state.last_role_change = Some(now);
+        }
+        user_state.set_curator(true, now);
+        for project_id in project_ids {
+            if !user_state.has_curated(&project_id) {
+                user_state.curated_projects.push(project_id);
+                changed = true;
+            }
+        }
+        if !state.curator_list.contains(&addr) {
+            state.curator_list.push(addr);
+            changed = true;
+        }
+        if is_new_validator && !state.validator_list.contains(&addr) {
+            state.validator_list.push(addr);
+        }
+        if is_new_user {
+            state.cached_counts.user_count += 1;
+        }
+        changed |= !was_curator | clear_application(state, Role::Curator, &addr);
+    }
+    refresh_list_counts(state);
+    if curators_added > 0 || validators_added > 0 {
+        log_event(logger, state, |event_seq| {
+            Event::BatchRoleChange(BatchRoleChangeEvent {
+                event_seq,
+                curators_added,
+                curators_removed: 0,
+                validators_added,
+                validators_removed: 0,
+            })
+        })?;
+    }
+    if changed {
+        state.state_seq += 1;
+    }
+    Ok(admin_skipped)
+}
+
+/// Set the curator / validator flags of multiple users to the inputted values.
+/// If a requested user address dose not exist in the state, user data with the inputted flags
+/// would be created. `curator_list` and `validator_list` are updated to follow the flags.
+/// Unless `skip_admin` is false, the entries for the admin account are skipped.
+/// Returns the number of entries whose user data has been changed and whether the admin account
+/// has been skipped.
+/// Instead of an event per user, a single `BatchRoleChange` event with the number of the users
+/// granted / removed each role is logged if any user has been changed.
+///
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
+/// * An inputted user has been soft-deleted (`UserDeleted`).
+/// * The roles of a changed user have been changed within `role_cooldown` (`CooldownActive`).
+#[receive(
+    contract = "overlay-users",
+    name = "set_user_roles_batch",
+    parameter = "SetUserRolesBatchParams",
+    return_value = "SetUserRolesBatchResponse",
+    mutable,
+    enable_logger,
+    error = "Error"
+)]
+fn contract_set_user_roles_batch<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+    logger: &mut impl HasLogger,
+) -> ContractResult<SetUserRolesBatchResponse> {
+    let params: SetUserRolesBatchParams = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+    ensure!(is_admin(ctx, &state.admin), Error::InvalidCaller);
+    record_admin_activity(ctx, state, "set_user_roles_batch");
+    let now = ctx.metadata().slot_time();
+    let mut changed_count = 0u32;
+    let mut curators_added = 0u32;
+    let mut curators_removed = 0u32;
+    let mut validators_added = 0u32;
+    let mut validators_removed = 0u32;
+    let skip_admin = params.skip_admin.unwrap_or(true);
+    let mut admin_skipped = false;
+    for roles in params.users {
+        if skip_admin && state.admin == Address::Account(roles.addr) {
+            admin_skipped = true;
+            continue;
+        }
+        ensure_not_deleted(state, &roles.addr)?;
+        let current_roles = state
+            .user
+            .get(&roles.addr)
+            .map(|user_state| (user_state.is_curator, user_state.is_validator));
+        if current_roles == Some((roles.is_curator, roles.is_validator)) {
+            continue;
+        }
+        ensure_role_cooldown_elapsed(state, &roles.addr, now)?;
+        changed_count += 1;
+        let (was_curator, was_validator) = current_roles.unwrap_or((false, false));
+        match (was_curator, roles.is_curator) {
+            (false, true) => curators_added += 1,
+            (true, false) => curators_removed += 1,
+            _ => {},
+        }
+        match (was_validator, roles.is_validator) {
+            (false, true) => validators_added += 1,
+            (true, false) => validators_removed += 1,
+            _ => {},
+        }
+        if state.user.get(&roles.addr).is_none() {
+            state.cached_counts.user_count += 1;
+        }
+        state
+            .user
+            .entry(roles.addr)
+            .and_modify(|user_state| {
+                user_state.set_curator(roles.is_curator, now);
+                user_state.set_validator(roles.is_validator, now);
+                user_state.last_role_change = Some(now);
+            })
+            .or_insert_with(|| {
+                let mut user_state = UserState {
+                    is_curator: false,
+                    is_validator: false,
+                    created_at: Some(now),
+                    ..state.default_user_state.clone()
+                };
+                user_state.set_curator(roles.is_curator, now);
+                user_state.set_validator(roles.is_validator, now);
+                user_state.last_role_change = Some(now);
+                user_state
+            });
+        if roles.is_curator {
+            if !state.curator_list.contains(&roles.addr) {
+                state.curator_list.push(roles.addr);
+            }
+            clear_application(state, Role::Curator, &roles.addr);
+        } else {
+            state.curator_list.retain(|x| *x != roles.addr);
+        }
+        if roles.is_validator {
+            if !state.validator_list.contains(&roles.addr) {
+                state.validator_list.push(roles.addr);
+            }
+            clear_application(state, Role::Validator, &roles.addr);
+        } else {
+            state.validator_list.retain(|x| *x != roles.addr);
+        }
+    }
+    refresh_list_counts(state);
+    if changed_count > 0 {
+        log_event(logger, state, |event_seq| {
+            Event::BatchRoleChange(BatchRoleChangeEvent {
+                event_seq,
+                curators_added,
+                curators_removed,
+                validators_added,
+                validators_removed,
+            })
+        })?;
+        state.state_seq += 1;
+    }
+    Ok(SetUserRolesBatchResponse {
+        changed_count,
+        admin_skipped,
+    })
+}
+
+/// Swap the user data of the two inputted accounts.
+/// The memberships of `curator_list` and `validator_list` are swapped as well.
+/// If only one of the accounts exists in the state, its user data is moved to the other account.
+///
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
+/// * The two inputted accounts are the same.
+#[receive(
+    contract = "overlay-users",
+    name = "swap_users",
+    parameter = "SwapUsersParams",
+    mutable,
+    error = "Error"
+)]
+fn contract_swap_users<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<()> {
+    let params: SwapUsersParams = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+    ensure!(is_admin(ctx, &state.admin), Error::InvalidCaller);
+    record_admin_activity(ctx, state, "swap_users");
+    ensure!(params.a != params.b, Error::InvalidArgument);
+    let user_a = state
+        .user
+        .get(&params.a)
+        .map(|user_state| user_state.clone());
+    let user_b = state
+        .user
+        .get(&params.b)
+        .map(|user_state| user_state.clone());
+    let scores_a = state
+        .curation_scores
+        .get(&params.a)
+        .map(|scores| scores.clone());
+    let scores_b = state
+        .curation_scores
+        .get(&params.b)
+        .map(|scores| scores.clone());
+    let curator_list = state.curator_list.clone();
+    let validator_list = state.validator_list.clone();
+    let users_changed = to_bytes(&user_a) != to_bytes(&user_b) || scores_a != scores_b;
+    for (addr, user_state) in [(params.b, user_a), (params.a, user_b)] {
+        match user_state {
+            Some(user_state) => {
+                state.user.insert(addr, user_state);
+            },
+            None => state.user.remove(&addr),
+        }
+    }
+    for (addr, scores) in [(params.b, scores_a), (params.a, scores_b)] {
+        match scores {
+            Some(scores) => {
+                state.curation_scores.insert(addr, scores);
+            },
+            None => state.curation_scores.remove(&addr),
+        }
+    }
+    swap_in_list(&mut state.curator_list, &params.a, &params.b);
+    swap_in_list(&mut state.validator_list, &params.a, &params.b);
+    if users_changed || state.curator_list != curator_list || state.validator_list != validator_list
+    {
+        state.state_seq += 1;
+    }
+    Ok(())
+}
+
+/// Add the tag to the inputted user.
+/// Adding a tag the user already holds does nothing.
+///
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
+/// * The user does not exist in the state.
+/// * The tag is longer than `MAX_TAG_LEN` bytes.
+/// * The user already holds `MAX_TAGS_PER_USER` tags.
+#[receive(
+    contract = "overlay-users",
+    name = "add_user_tag",
+    parameter = "AddUserTagParams",
+    mutable,
+    error = "Error"
+)]
+fn contract_add_user_tag<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<()> {
+    let params: AddUserTagParams = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+    ensure!(is_admin(ctx, &state.admin), Error::InvalidCaller);
+    record_admin_activity(ctx, state, "add_user_tag");
+    ensure!(params.tag.len() <= MAX_TAG_LEN, Error::InvalidArgument);
+    let mut user_state = state
+        .user
+        .get_mut(&params.addr)
+        .ok_or(Error::UserNotFound)?;
+    if user_state.tags.contains(&params.tag) {
+        return Ok(());
+    }
+    ensure!(
+        user_state.tags.len() < MAX_TAGS_PER_USER,
+        Error::TooManyTags
+    );
+    user_state.tags.push(params.tag);
+    state.state_seq += 1;
+    Ok(())
+}
+
+/// Remove the tag from the inputted user.
+/// Removing a tag the user does not hold does nothing.
+///
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
+/// * The user does not exist in the state.
+#[receive(
+    contract = "overlay-users",
+    name = "remove_user_tag",
+    parameter = "RemoveUserTagParams",
+    mutable,
+    error = "Error"
+)]
+fn contract_remove_user_tag<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<()> {
+    let params: RemoveUserTagParams = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+    ensure!(is_admin(ctx, &state.admin), Error::InvalidCaller);
+    record_admin_activity(ctx, state, "remove_user_tag");
+    let changed = {
+        let mut user_state = state
+            .user
+            .get_mut(&params.addr)
+            .ok_or(Error::UserNotFound)?;
+        let tag_count = user_state.tags.len();
+        user_state.tags.retain(|tag| *tag != params.tag);
+        user_state.tags.len() != tag_count
+    };
+    if changed {
+        state.state_seq += 1;
+    }
+    Ok(())
+}
+
+/// Add project id to the user curated projects state.
+///
+/// Caller: associated overlay-projects smart contract
+/// Reject if:
+/// * Caller is not the associated overlay-projects smart contract address
+/// * The inputted user does not exist in the state (`UserNotFound`).
+/// * The inputted user exists but is not registered as a curator (`NotACurator`).
+/// * `disjoint_project_roles` is set and the user has already validated the project.
+/// * `reject_privileged_targets` is set and the inputted user is the admin account.
+/// * The project is new to the user and the user has already reached its project limit.
+/// * `allowed_projects` is not empty and does not contain the project id (`ProjectNotAllowed`).
+/// * The user has been soft-deleted (`UserDeleted`).
+///
+/// If `curate_auto_create` is set, an unknown user is registered as a curator as `add_curator` does
+/// instead of being rejected, unless the curator set is frozen or `require_known_users` is set.
+///
+/// This function is designed to be called by the following smart contract functions.
+/// * overlay-projects.curate_project
+#[receive(
+    contract = "overlay-users",
+    name = "curate",
+    parameter = "CurateParams",
+    mutable
+)]
+fn contract_curate<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>>,
+) -> ContractResult<()> {
+    let params: CurateParams = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+    ensure!(
+        ctx.sender() == Address::Contract(state.project_contract_addr),
+        Error::InvalidCaller
+    );
+    let changed = auto_create_curator(state, &params.addr, ctx.metadata().slot_time())
+        | curate_project(state, &params.addr, &params.project_id)?;
+    if changed {
+        state.state_seq += 1;
+    }
+    Ok(())
+}
+
+/// Add project id to the user curated projects state with the quality score of the curation,
+/// in the same way as `curate`. The score can be read with `view_curation_scores`.
+/// If the user has already curated the project, the recorded score is kept.
+///
+/// Caller: associated overlay-projects smart contract
+/// Reject if:
+/// * The score is greater than `MAX_CURATION_SCORE` (`InvalidArgument`).
+/// * Any of the conditions of `curate` applies.
+#[receive(
+    contract = "overlay-users",
+    name = "curate_with_score",
+    parameter = "CurateWithScoreParams",
+    mutable,
+    error = "Error"
+)]
+fn contract_curate_with_score<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<()> {
+    let params: CurateWithScoreParams = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+    ensure!(
+        ctx.sender() == Address::Contract(state.project_contract_addr),
+        Error::InvalidCaller
+    );
+    ensure!(params.score <= MAX_CURATION_SCORE, Error::InvalidArgument);
+    let mut changed = auto_create_curator(state, &params.addr, ctx.metadata().slot_time());
+    if curate_project(state, &params.addr, &params.project_id)? {
+        state
+            .curation_scores
+            .entry(params.addr)
+            .or_insert_with(Vec::new)
+            .push((params.project_id, params.score));
+        changed = true;
+    }
+    if changed {
+        state.state_seq += 1;
+    }
+    Ok(())
+}
+
+/// Add project id to the user curated projects state regardless of the curator role.
+/// This is intended for data correction, e.g. recording a curation of a former curator.
+/// It bypasses every precondition of `curate` other than the user existence, such as
+/// `disjoint_project_roles` and the project limits, so the caller is responsible for the consistency.
+///
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
+/// * The inputted user does not exist in the state (`UserNotFound`).
+/// * `set_curator` is set while `curators_frozen` is set.
+/// * The user has been soft-deleted (`UserDeleted`).
+/// * `set_curator` is set and the roles of the user have been changed within `role_cooldown` (`CooldownActive`).
+#[receive(
+    contract = "overlay-users",
+    name = "force_curate",
+    parameter = "ForceCurateParams",
+    mutable,
+    error = "Error"
+)]
+fn contract_force_curate<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<()> {
+    let params: ForceCurateParams = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+    ensure!(is_admin(ctx, &state.admin), Error::InvalidCaller);
+    record_admin_activity(ctx, state, "force_curate");
+    ensure!(
+        !params.set_curator || !state.curators_frozen,
+        Error::CuratorsFrozen
+    );
+    let grants_curator = params.set_curator
+        && !state
+            .user
+            .get(&params.addr)
+            .map_or(false, |user_state| user_state.is_curator);
+    let grant_time = if grants_curator {
+        let now = ctx.metadata().slot_time();
+        ensure_role_cooldown_elapsed(state, &params.addr, now)?;
+        Some(now)
+    } else {
+        None
+    };
+    let mut changed = grants_curator;
+    {
+        let mut target_user = state
+            .user
+            .get_mut(&params.addr)
+            .ok_or(Error::UserNotFound)?;
+        ensure!(!target_user.deleted, Error::UserDeleted);
+        if grant_time.is_some() {
+            target_user.last_role_change = grant_time;
+        }
+        if !target_user.has_curated(&params.project_id) {
+            target_user.curated_projects.push(params.project_id);
+            changed = true;
+        }
+        if params.set_curator {
+            target_user.set_curator(true, ctx.metadata().slot_time());
+        }
+    }
+    if params.set_curator {
+        if !state.curator_list.contains(&params.addr) {
+            state.curator_list.push(params.addr);
+            refresh_list_counts(state);
+            changed = true;
+        }
+        changed |= clear_application(state, Role::Curator, &params.addr);
+    }
+    if changed {
+        state.state_seq += 1;
+    }
+    Ok(())
+}
+
+/// Add project id to the curated projects state of each inputted user.
+/// Users that `curate` would reject (e.g. non-curators or unknown users) are skipped
+/// and returned in the input order.
+/// If `strict` is set, all the users are checked before updating the state and the whole call
+/// is rejected with the error of the first user that `curate` would reject.
+///
+/// Caller: associated overlay-projects smart contract
+/// Reject if:
+/// * Caller is not the associated overlay-projects smart contract address
+/// * `strict` is set and `curate` would reject any of the users.
+#[receive(
+    contract = "overlay-users",
+    name = "curate_batch_users",
+    parameter = "CurateBatchUsersParams",
+    return_value = "CurateBatchUsersResponse",
+    mutable,
+    error = "Error"
+)]
+fn contract_curate_batch_users<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<CurateBatchUsersResponse> {
+    let params: CurateBatchUsersParams = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+    ensure!(
+        ctx.sender() == Address::Contract(state.project_contract_addr),
+        Error::InvalidCaller
+    );
+    if params.strict {
+        for addr in params.addrs.iter() {
+            check_curate_project(state, addr, &params.project_id)?;
+        }
+    }
+    let mut skipped = Vec::new();
+    let mut changed = false;
+    for addr in params.addrs {
+        match curate_project(state, &addr, &params.project_id) {
+            Ok(curated) => changed |= curated,
+            Err(error) => {
+                ensure!(!params.strict, error);
+                skipped.push(addr);
+            },
+        }
+    }
+    if changed {
+        state.state_seq += 1;
+    }
+    Ok(skipped)
+}
+
+/// Add each inputted project id to the curated projects state of the paired user.
+/// Pairs that `curate` would reject (e.g. non-curators or unknown users) are skipped
+/// and returned in the input order.
+/// If `strict` is set, all the pairs are checked before updating the state and the whole call
+/// is rejected with the error of the first pair that `curate` would reject.
+///
+/// Caller: associated overlay-projects smart contract
+/// Reject if:
+/// * Caller is not the associated overlay-projects smart contract address
+/// * More than `MAX_CURATE_BULK` pairs are inputted (`InvalidArgument`).
+/// * `strict` is set and `curate` would reject any of the pairs.
+#[receive(
+    contract = "overlay-users",
+    name = "curate_bulk",
+    parameter = "CurateBulkParams",
+    return_value = "CurateBulkResponse",
+    mutable,
+    error = "Error"
+)]
+fn contract_curate_bulk<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<CurateBulkResponse> {
+    let params: CurateBulkParams = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+    ensure!(
+        ctx.sender() == Address::Contract(state.project_contract_addr),
+        Error::InvalidCaller
+    );
+    ensure!(
+        params.pairs.len() <= MAX_CURATE_BULK,
+        Error::InvalidArgument
+    );
+    if params.strict {
+        for (addr, project_id) in params.pairs.iter() {
+            check_curate_project(state, addr, project_id)?;
+        }
+    }
+    let mut failed = Vec::new();
+    let mut changed = false;
+    for (addr, project_id) in params.pairs {
+        match curate_project(state, &addr, &project_id) {
+            Ok(curated) => changed |= curated,
+            Err(error) => {
+                ensure!(!params.strict, error);
+                failed.push((addr, project_id));
+            },
+        }
+    }
+    if changed {
+        state.state_seq += 1;
+    }
+    Ok(failed)
+}
+
+/// Add project id to the user validated projects state.
+/// Returns the number of the validated projects of the user after the operation.
+///
+/// Caller: associated overlay-projects smart contract
+/// Reject if:
+/// * Caller is not the associated overlay-projects smart contract address
+/// * The inputted user does not exist in the state (`UserNotFound`).
+/// * The inputted user exists but is not registered as a validator (`NotAValidator`).
+/// * `disjoint_project_roles` is set and the user has already curated the project.
+/// * `reject_privileged_targets` is set and the inputted user is the admin account.
+/// * The project is new to the user and the user has already reached its project limit.
+/// * `allowed_projects` is not empty and does not contain the project id (`ProjectNotAllowed`).
+/// * The user has been soft-deleted (`UserDeleted`).
+///
+/// This function is designed to be called by the following smart contract functions.
+/// * overlay-projects.validate_project
+#[receive(
+    contract = "overlay-users",
+    name = "validate",
+    parameter = "ValidateParams",
+    return_value = "u32",
+    mutable
+)]
+fn contract_validate<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>>,
+) -> ContractResult<u32> {
+    let params: ValidateParams = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+    ensure!(
+        ctx.sender() == Address::Contract(state.project_contract_addr),
+        Error::InvalidCaller
+    );
+    if validate_project(state, &params.addr, &params.project_id)? {
+        state.state_seq += 1;
+    }
+    let validated_count = state
+        .user
+        .get(&params.addr)
+        .map_or(0, |user_state| user_state.validated_projects.len());
+    Ok(validated_count as u32)
+}
+
+/// Add project id to the validated projects state of each inputted user.
+/// Users that `validate` would reject (e.g. non-validators or unknown users) are skipped
+/// and returned in the input order.
+/// If `strict` is set, all the users are checked before updating the state and the whole call
+/// is rejected with the error of the first user that `validate` would reject.
+///
+/// Caller: associated overlay-projects smart contract
+/// Reject if:
+/// * Caller is not the associated overlay-projects smart contract address
+/// * `strict` is set and `validate` would reject any of the users.
+#[receive(
+    contract = "overlay-users",
+    name = "validate_batch_users",
+    parameter = "ValidateBatchUsersParams",
+    return_value = "ValidateBatchUsersResponse",
+    mutable,
+    error = "Error"
+)]
+fn contract_validate_batch_users<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<ValidateBatchUsersResponse> {
+    let params: ValidateBatchUsersParams = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+    ensure!(
+        ctx.sender() == Address::Contract(state.project_contract_addr),
+        Error::InvalidCaller
+    );
+    if params.strict {
+        for addr in params.addrs.iter() {
+            check_validate_project(state, addr, &params.project_id)?;
+        }
+    }
+    let mut skipped = Vec::new();
+    let mut changed = false;
+    for addr in params.addrs {
+        match validate_project(state, &addr, &params.project_id) {
+            Ok(validated) => changed |= validated,
+            Err(error) => {
+                ensure!(!params.strict, error);
+                skipped.push(addr);
+            },
+        }
+    }
+    if changed {
+        state.state_seq += 1;
+    }
+    Ok(skipped)
+}
+
+/// Smart contract module upgrade function.
+/// For more information see https://developer.concordium.software/en/mainnet/smart-contracts/guides/upgradeable-contract.html#guide-upgradable-contract
+#[receive(
+    contract = "overlay-users",
+    name = "upgrade",
+    parameter = "UpgradeParams",
+    mutable
+)]
+fn contract_upgrade<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ReceiveResult<()> {
+    ensure!(ctx.sender().matches_account(&ctx.owner()));
+    let params: UpgradeParams = ctx.parameter_cursor().get()?;
+    host.upgrade(params.module)?;
+    if let Some((func, parameter)) = params.migrate {
+        host.invoke_contract_raw(
+            &ctx.self_address(),
+            parameter.as_parameter(),
+            func.as_entrypoint_name(),
+            Amount::zero(),
+        )?;
+    }
+    Ok(())
+}
+
+/// Migrate the state from the `StateV0` layout to the current layout.
+/// The account admin of the old state is kept as an account admin and
+/// the validated projects are attributed to the current overlay-projects contract.
+///
+/// Caller: this contract itself (via the `migrate` parameter of `upgrade` function).
+#[receive(contract = "overlay-users", name = "migrate", mutable, low_level)]
+fn contract_migrate<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<S, StateApiType = S>,
+) -> ReceiveResult<()> {
+    ensure!(ctx.sender().matches_contract(&ctx.self_address()));
+    let (state_api, state_builder) = host.state_and_builder();
+    let old_state: StateV0<S> = state_api.read_root()?;
+    let new_state = migrate_state_v0(old_state, state_builder);
+    state_api.write_root(&new_state);
+    Ok(())
+}
+
+/// View the admin state.
+///
+/// Caller: Admin account or observer accounts.
+#[receive(
+    contract = "overlay-users",
+    name = "view_admin",
+    return_value = "ViewAdminRes"
+)]
+fn contract_view_admin<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<ViewAdminRes> {
+    let state = host.state();
+    ensure!(
+        is_admin(ctx, &state.admin) || state.observers.contains(&ctx.invoker()),
+        Error::InvalidCaller
+    );
+    Ok(ViewAdminRes {
+        admin: state.admin,
+        project_contract_addr: state.project_contract_addr,
+        curator_list: state.curator_list.clone(),
+        validator_list: state.validator_list.clone(),
+    })
+}
+
+/// View the admin-configured limits together.
+///
+/// Caller: Any accounts / Any contracts
+#[receive(
+    contract = "overlay-users",
+    name = "view_limits",
+    return_value = "ViewLimitsResponse"
+)]
+fn contract_view_limits<S: HasStateApi>(
+    _ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<ViewLimitsResponse> {
+    let state = host.state();
+    Ok(ViewLimitsResponse {
+        max_projects_per_user: state.max_projects_per_user,
+        role_cooldown: state.role_cooldown,
+        curator_weight: state.curator_weight,
+        validator_weight: state.validator_weight,
+        admin_inactivity_timeout: state
+            .recovery_account
+            .map(|_| state.admin_inactivity_timeout),
+    })
+}
+
+/// View the number of the admin-gated calls for each function name, in ascending order of the names.
+/// Calls rejected before the admin check are not counted.
+///
+/// Caller: Any accounts / Any contracts
+#[receive(
+    contract = "overlay-users",
+    name = "view_admin_action_counts",
+    return_value = "ViewAdminActionCountsResponse"
+)]
+fn contract_view_admin_action_counts<S: HasStateApi>(
+    _ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<ViewAdminActionCountsResponse> {
+    let mut response: ViewAdminActionCountsResponse = host
+        .state()
+        .admin_action_counts
+        .iter()
+        .map(|(action, count)| (action.clone(), *count))
+        .collect();
+    response.sort();
+    Ok(response)
+}
+
+/// View `curator_list` and `validator_list` with the number of the relevant projects of each member.
+/// A listed member that does not exist in the user state is reported with 0 projects.
+///
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
+#[receive(
+    contract = "overlay-users",
+    name = "view_admin_detailed",
+    return_value = "ViewAdminDetailedResponse"
+)]
+fn contract_view_admin_detailed<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<ViewAdminDetailedResponse> {
+    let state = host.state();
+    ensure!(is_admin(ctx, &state.admin), Error::InvalidCaller);
+    let project_count = |addr: &AccountAddress, count: fn(&UserState) -> usize| {
+        let count = state
+            .user
+            .get(addr)
+            .map(|user_state| count(&user_state))
+            .unwrap_or(0);
+        (*addr, count as u32)
+    };
+    Ok(ViewAdminDetailedResponse {
+        curators: state
+            .curator_list
+            .iter()
+            .map(|addr| project_count(addr, |user_state| user_state.curated_projects.len()))
+            .collect(),
+        validators: state
+            .validator_list
+            .iter()
+            .map(|addr| project_count(addr, |user_state| user_state.validated_projects.len()))
+            .collect(),
+    })
+}
+
+/// Recalculate the cached counts from the user state and the lists, overwriting the cached values.
+/// Returns the corrected counts.
+///
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
+#[receive(
+    contract = "overlay-users",
+    name = "recompute_counts",
+    return_value = "CachedCounts",
+    mutable,
+    error = "Error"
+)]
+fn contract_recompute_counts<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<CachedCounts> {
+    let state = host.state_mut();
+    ensure!(is_admin(ctx, &state.admin), Error::InvalidCaller);
+    record_admin_activity(ctx, state, "recompute_counts");
+    state.cached_counts = CachedCounts {
+        user_count: state.user.iter().count() as u64,
+        curator_count: state.curator_list.len() as u64,
+        validator_count: state.validator_list.len() as u64,
+    };
+    state.state_seq += 1;
+    Ok(state.cached_counts)
+}
+
+/// View the cached user counts without iterating the user state.
+///
+/// Caller: Any accounts / Any contracts
+#[receive(
+    contract = "overlay-users",
+    name = "view_cached_counts",
+    return_value = "CachedCounts"
+)]
+fn contract_view_cached_counts<S: HasStateApi>(
+    _ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<CachedCounts> {
+    Ok(host.state().cached_counts)
+}
+
+/// View the admin and whether the caller has the admin rights.
+/// The caller is compared in the same way as the functions restricted to the admin.
+///
+/// Caller: Any accounts / Any contracts
+#[receive(
+    contract = "overlay-users",
+    name = "view_admin_status",
+    return_value = "ViewAdminStatusResponse"
+)]
+fn contract_view_admin_status<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<ViewAdminStatusResponse> {
+    let state = host.state();
+    Ok(ViewAdminStatusResponse {
+        admin: state.admin,
+        caller_is_admin: is_admin(ctx, &state.admin),
+    })
+}
+
+/// View whether the contract has been configured to be used.
+///
+/// Caller: Any accounts / Any contracts
+#[receive(
+    contract = "overlay-users",
+    name = "view_readiness",
+    return_value = "ViewReadinessResponse"
+)]
+fn contract_view_readiness<S: HasStateApi>(
+    _ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<ViewReadinessResponse> {
+    let state = host.state();
+    let admin_set = state.admin != Address::Account(AccountAddress([0u8; 32]));
+    let project_contract_set = state.project_contract_addr != ContractAddress::new(0, 0);
+    Ok(ViewReadinessResponse {
+        admin_set,
+        project_contract_set,
+        ready: admin_set && project_contract_set,
+    })
+}
+
+/// View the address of this contract instance and its owner as seen by the contract.
+///
+/// Caller: Any accounts / Any contracts
+#[receive(
+    contract = "overlay-users",
+    name = "view_identity",
+    return_value = "ViewIdentityResponse"
+)]
+fn contract_view_identity<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    _host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<ViewIdentityResponse> {
+    Ok(ViewIdentityResponse {
+        self_address: ctx.self_address(),
+        owner: ctx.owner(),
+    })
+}
+
+/// View when the admin was last transferred and which account it was transferred from.
+/// Returns `None` if the admin has never been transferred.
+///
+/// Caller: Any accounts / Any contracts
+#[receive(
+    contract = "overlay-users",
+    name = "view_last_admin_change",
+    return_value = "ViewLastAdminChangeResponse"
+)]
+fn contract_view_last_admin_change<S: HasStateApi>(
+    _ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<ViewLastAdminChangeResponse> {
+    Ok(host.state().last_admin_change)
+}
+
+/// View the user state.
+/// If the requested user address dose not exist in the state, it returns the default data.
+/// A soft-deleted user is returned with `deleted` set and without roles and projects,
+/// so the parameter stays compatible with the overlay-projects contract.
+///
+/// Caller: Any accounts / Any contracts
+///
+/// This function is designed to be called by the following smart contract functions.
+/// * overlay-projects.curate_project
+/// * overlay-projects.curate_project_admin
+/// * overlay-projects.validate_project
+/// * overlay-projects.validate_project_admin
+#[receive(
+    contract = "overlay-users",
+    name = "view_user",
+    parameter = "ViewUserParams",
+    return_value = "UserState"
+)]
+fn contract_view_user<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<ViewUserResponse> {
+    let params: ViewUserParams = ctx.parameter_cursor().get()?;
+    let state = host.state();
+    let user_state = state
+        .user
+        .get(&params.addr)
+        .map(|user_state_ref| user_state_ref.clone())
+        .unwrap_or_default();
+    Ok(user_state)
+}
+
+/// View the user state together with whether the user is the admin of this module.
+/// If the requested user address dose not exist in the state, it returns the default user data,
+/// which is also the case for the admin account unless it has been registered as a user.
+///
+/// Caller: Any accounts / Any contracts
+#[receive(
+    contract = "overlay-users",
+    name = "view_user_extended",
+    parameter = "ViewUserExtendedParams",
+    return_value = "ViewUserExtendedResponse"
+)]
+fn contract_view_user_extended<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<ViewUserExtendedResponse> {
+    let params: ViewUserExtendedParams = ctx.parameter_cursor().get()?;
+    let state = host.state();
+    let user_state = state
+        .user
+        .get(&params.addr)
+        .map(|user_state_ref| user_state_ref.clone())
+        .unwrap_or_default();
+    Ok(ViewUserExtendedResponse {
+        is_admin: state.admin == Address::Account(params.addr),
+        user_state,
+    })
+}
+
+/// View all the project ids the user has curated or validated.
+/// Project ids are deduplicated; curated projects come first, followed by validated-only projects.
+/// If the requested user address dose not exist in the state, it returns an empty list.
+///
+/// Caller: Any accounts / Any contracts
+#[receive(
+    contract = "overlay-users",
+    name = "view_user_projects",
+    parameter = "ViewUserProjectsParams",
+    return_value = "ViewUserProjectsResponse"
+)]
+fn contract_view_user_projects<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<ViewUserProjectsResponse> {
+    let params: ViewUserProjectsParams = ctx.parameter_cursor().get()?;
+    let mut projects: ViewUserProjectsResponse = Vec::new();
+    if let Some(user_state) = host.state().user.get(&params.addr) {
+        for project_id in user_state.curated_projects.iter().chain(
+            user_state
+                .validated_projects
+                .iter()
+                .map(|(project_id, _)| project_id),
+        ) {
+            if !projects.contains(project_id) {
+                projects.push(project_id.clone());
+            }
+        }
+    }
+    Ok(projects)
+}
+
+/// View the roles and the project counts of the user in a fixed-size response.
+/// This is intended to be read by other contracts with `invoke_contract_read_only`.
+/// If the requested user address dose not exist in the state, it returns no roles and zero counts.
+///
+/// Caller: Any accounts / Any contracts
+#[receive(
+    contract = "overlay-users",
+    name = "get_user_roles",
+    parameter = "GetUserRolesParams",
+    return_value = "GetUserRolesResponse"
+)]
+fn contract_get_user_roles<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<GetUserRolesResponse> {
+    let params: GetUserRolesParams = ctx.parameter_cursor().get()?;
+    let response = host
+        .state()
+        .user
+        .get(&params.addr)
+        .map(|user_state| GetUserRolesResponse {
+            is_curator: user_state.is_curator,
+            is_validator: user_state.is_validator,
+            curated_count: user_state.curated_projects.len() as u32,
+            validated_count: user_state.validated_projects.len() as u32,
+        })
+        .unwrap_or_default();
+    Ok(response)
+}
+
+/// View the roles of the user as a single enum.
+/// If the requested user address dose not exist in the state, it returns `None`.
+///
+/// Caller: Any accounts / Any contracts
+#[receive(
+    contract = "overlay-users",
+    name = "view_user_role",
+    parameter = "ViewUserRoleParams",
+    return_value = "UserRole"
+)]
+fn contract_view_user_role<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<UserRole> {
+    let params: ViewUserRoleParams = ctx.parameter_cursor().get()?;
+    let roles = host
+        .state()
+        .user
+        .get(&params.addr)
+        .map(|user_state| (user_state.is_curator, user_state.is_validator));
+    let user_role = match roles {
+        Some((true, true)) => UserRole::Both,
+        Some((true, false)) => UserRole::Curator,
+        Some((false, true)) => UserRole::Validator,
+        Some((false, false)) | None => UserRole::None,
+    };
+    Ok(user_role)
+}
+
+/// View whether the user has ever been granted the curator role, even if it has been removed since.
+/// If the requested user address dose not exist in the state, it returns false.
+///
+/// Caller: Any accounts / Any contracts
+#[receive(
+    contract = "overlay-users",
+    name = "was_ever_curator",
+    parameter = "WasEverCuratorParams",
+    return_value = "bool"
+)]
+fn contract_was_ever_curator<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<bool> {
+    let params: WasEverCuratorParams = ctx.parameter_cursor().get()?;
+    let was_ever_curator = host
+        .state()
+        .user
+        .get(&params.addr)
+        .map_or(false, |user_state| user_state.was_ever_curator);
+    Ok(was_ever_curator)
+}
+
+/// View whether the user has ever been granted the validator role, even if it has been removed since.
+/// If the requested user address dose not exist in the state, it returns false.
+///
+/// Caller: Any accounts / Any contracts
+#[receive(
+    contract = "overlay-users",
+    name = "was_ever_validator",
+    parameter = "WasEverValidatorParams",
+    return_value = "bool"
+)]
+fn contract_was_ever_validator<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<bool> {
+    let params: WasEverValidatorParams = ctx.parameter_cursor().get()?;
+    let was_ever_validator = host
+        .state()
+        .user
+        .get(&params.addr)
+        .map_or(false, |user_state| user_state.was_ever_validator);
+    Ok(was_ever_validator)
+}
+
+/// View the project ids the user has curated and validated separately.
+/// If the requested user address dose not exist in the state, it returns empty lists.
+///
+/// Caller: Any accounts / Any contracts
+#[receive(
+    contract = "overlay-users",
+    name = "view_user_project_breakdown",
+    parameter = "ViewUserProjectBreakdownParams",
+    return_value = "ViewUserProjectBreakdownResponse"
+)]
+fn contract_view_user_project_breakdown<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<ViewUserProjectBreakdownResponse> {
+    let params: ViewUserProjectBreakdownParams = ctx.parameter_cursor().get()?;
+    let response = host
+        .state()
+        .user
+        .get(&params.addr)
+        .map(|user_state| ViewUserProjectBreakdownResponse {
+            curated: user_state.curated_projects.clone(),
+            validated: user_state
+                .validated_projects
+                .iter()
+                .map(|(project_id, _)| project_id.clone())
+                .collect(),
+        })
+        .unwrap_or_default();
+    Ok(response)
+}
+
+/// View the project ids the user has curated but not validated, and validated but not curated.
+/// If the requested user address dose not exist in the state, it returns empty lists.
+///
+/// Caller: Any accounts / Any contracts
+#[receive(
+    contract = "overlay-users",
+    name = "view_user_project_gaps",
+    parameter = "ViewUserProjectGapsParams",
+    return_value = "ViewUserProjectGapsResponse"
+)]
+fn contract_view_user_project_gaps<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<ViewUserProjectGapsResponse> {
+    let params: ViewUserProjectGapsParams = ctx.parameter_cursor().get()?;
+    let response = host
+        .state()
+        .user
+        .get(&params.addr)
+        .map(|user_state| ViewUserProjectGapsResponse {
+            curated_not_validated: user_state
+                .curated_projects
+                .iter()
+                .filter(|project_id| !user_state.has_validated(project_id))
+                .cloned()
+                .collect(),
+            validated_not_curated: user_state
+                .validated_projects
+                .iter()
+                .map(|(project_id, _)| project_id)
+                .filter(|project_id| !user_state.has_curated(project_id))
+                .cloned()
+                .collect(),
+        })
+        .unwrap_or_default();
+    Ok(response)
+}
+
+/// View the validated project ids of the user that are not curated by any user.
+/// The response follows the order of the validated projects of the user.
+/// If the requested user address dose not exist in the state, it returns an empty list.
+/// Note that all the users are scanned to collect the curated project ids.
+///
+/// Caller: Any accounts / Any contracts
+#[receive(
+    contract = "overlay-users",
+    name = "view_orphan_validations",
+    parameter = "ViewOrphanValidationsParams",
+    return_value = "ViewOrphanValidationsResponse"
+)]
+fn contract_view_orphan_validations<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<ViewOrphanValidationsResponse> {
+    let params: ViewOrphanValidationsParams = ctx.parameter_cursor().get()?;
+    let state = host.state();
+    let validated_projects = match state.user.get(&params.addr) {
+        Some(user_state) => user_state.validated_projects.clone(),
+        None => return Ok(Vec::new()),
+    };
+    let mut curated_project_ids = BTreeSet::new();
+    for (_, user_state) in state.user.iter() {
+        curated_project_ids.extend(user_state.curated_projects.iter().cloned());
+    }
+    let response = validated_projects
+        .into_iter()
+        .map(|(project_id, _)| project_id)
+        .filter(|project_id| !curated_project_ids.contains(project_id))
+        .collect();
+    Ok(response)
+}
+
+/// View the project ids curated or validated by both of the inputted users, in ascending order.
+/// If either of the requested user addresses does not exist in the state, it returns an empty list.
+///
+/// Caller: Any accounts / Any contracts
+#[receive(
+    contract = "overlay-users",
+    name = "shared_projects",
+    parameter = "SharedProjectsParams",
+    return_value = "SharedProjectsResponse"
+)]
+fn contract_shared_projects<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<SharedProjectsResponse> {
+    let params: SharedProjectsParams = ctx.parameter_cursor().get()?;
+    let users = &host.state().user;
+    let project_ids_of = |addr: &AccountAddress| -> BTreeSet<ProjectId> {
+        users
+            .get(addr)
+            .map(|user_state| {
+                user_state
+                    .curated_projects
+                    .iter()
+                    .chain(
+                        user_state
+                            .validated_projects
+                            .iter()
+                            .map(|(project_id, _)| project_id),
+                    )
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+    let a_project_ids = project_ids_of(&params.a);
+    let b_project_ids = project_ids_of(&params.b);
+    Ok(a_project_ids
+        .intersection(&b_project_ids)
+        .cloned()
+        .collect())
+}
+
+/// View the project ids the user has validated through the inputted overlay-projects contract.
+/// If the requested user address dose not exist in the state, it returns an empty list.
+///
+/// Caller: Any accounts / Any contracts
+#[receive(
+    contract = "overlay-users",
+    name = "validated_projects_by_source",
+    parameter = "ValidatedProjectsBySourceParams",
+    return_value = "ValidatedProjectsBySourceResponse"
+)]
+fn contract_validated_projects_by_source<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<ValidatedProjectsBySourceResponse> {
+    let params: ValidatedProjectsBySourceParams = ctx.parameter_cursor().get()?;
+    let projects = host
+        .state()
+        .user
+        .get(&params.addr)
+        .map(|user_state| {
+            user_state
+                .validated_projects
+                .iter()
+                .filter(|(_, source)| *source == params.source)
+                .map(|(project_id, _)| project_id.clone())
+                .collect()
+        })
+        .unwrap_or_default();
+    Ok(projects)
+}
+
+/// View the all user state except the soft-deleted users.
+///
+/// Caller: Any accounts / Any contracts if `users_view_public` is set, otherwise current admin account.
+/// Reject if:
+/// * `users_view_public` is not set and caller is not the current admin account.
+#[receive(
+    contract = "overlay-users",
+    name = "view_users",
+    return_value = "ViewUsersResponse"
+)]
+fn contract_view_users<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<ViewUsersResponse> {
+    collect_users(ctx, host.state(), false)
+}
+
+/// View the all user state including the soft-deleted users.
+///
+/// Caller: Any accounts / Any contracts if `users_view_public` is set, otherwise current admin account.
+/// Reject if:
+/// * `users_view_public` is not set and caller is not the current admin account.
+#[receive(
+    contract = "overlay-users",
+    name = "view_users_with_deleted",
+    return_value = "ViewUsersResponse"
+)]
+fn contract_view_users_with_deleted<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<ViewUsersResponse> {
+    collect_users(ctx, host.state(), true)
+}
+
+/// View at most `limit` users whose address is strictly greater than `after`.
+/// The user state is ordered by the 32-byte address, so paging with the returned cursor
+/// neither skips nor repeats users even if the state is changed between the pages.
+///
+/// Caller: Any accounts / Any contracts if `users_view_public` is set, otherwise current admin account.
+/// Reject if:
+/// * `users_view_public` is not set and caller is not the current admin account.
+/// * `limit` is zero.
+#[receive(
+    contract = "overlay-users",
+    name = "view_users_after",
+    parameter = "ViewUsersAfterParams",
+    return_value = "ViewUsersAfterResponse"
+)]
+fn contract_view_users_after<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<ViewUsersAfterResponse> {
+    let params: ViewUsersAfterParams = ctx.parameter_cursor().get()?;
+    let state = host.state();
+    ensure!(
+        state.users_view_public || is_admin(ctx, &state.admin),
+        Error::InvalidCaller
+    );
+    ensure!(params.limit > 0, Error::InvalidArgument);
+    let mut users = Vec::new();
+    let mut has_more = false;
+    for (addr, user_state) in state.user.iter() {
+        if params.after.map_or(false, |after| *addr <= after)
+            || (!params.include_deleted && user_state.deleted)
+        {
+            continue;
+        }
+        if users.len() as u64 == params.limit {
+            has_more = true;
+            break;
+        }
+        users.push((*addr, user_state.clone()));
+    }
+    let next = if has_more {
+        users.last().map(|(addr, _)| *addr)
+    } else {
+        None
+    };
+    Ok(ViewUsersAfterResponse { users, next })
+}
+
+/// View at most `limit` users matching the role, skipping the first `start` matching users.
+/// The users are ordered by address, and `has_more` tells whether more users match after the page.
+///
+/// Caller: Any accounts / Any contracts if `users_view_public` is set, otherwise current admin account.
+/// Reject if:
+/// * `users_view_public` is not set and caller is not the current admin account.
+#[receive(
+    contract = "overlay-users",
+    name = "view_users_filtered_paged",
+    parameter = "ViewUsersFilteredPagedParams",
+    return_value = "ViewUsersFilteredPagedResponse"
+)]
+fn contract_view_users_filtered_paged<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<ViewUsersFilteredPagedResponse> {
+    let params: ViewUsersFilteredPagedParams = ctx.parameter_cursor().get()?;
+    let state = host.state();
+    ensure!(
+        state.users_view_public || is_admin(ctx, &state.admin),
+        Error::InvalidCaller
+    );
+    let limit: usize = params.limit.try_into().unwrap_or(usize::MAX);
+    let mut users: Vec<(AccountAddress, UserState)> = state
+        .user
+        .iter()
+        .filter(|(_, user_state)| {
+            user_state.matches_role(&params.role) && (params.include_deleted || !user_state.deleted)
+        })
+        .skip(params.start.try_into().unwrap_or(usize::MAX))
+        .take(limit.saturating_add(1))
+        .map(|(addr, user_state)| (*addr, user_state.clone()))
+        .collect();
+    // one extra user has been taken to know whether there is a next page.
+    let has_more = users.len() > limit;
+    users.truncate(limit);
+    Ok(ViewUsersFilteredPagedResponse { users, has_more })
+}
+
+/// View the all user addresses with their roles packed into a single byte.
+/// See `ViewUserFlagsResponse` for the bit layout.
+///
+/// Caller: Any accounts / Any contracts
+#[receive(
+    contract = "overlay-users",
+    name = "view_user_flags",
+    return_value = "ViewUserFlagsResponse"
+)]
+fn contract_view_user_flags<S: HasStateApi>(
+    _ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<ViewUserFlagsResponse> {
+    let response = host
+        .state()
+        .user
+        .iter()
+        .map(|(addr, user_state)| {
+            let flags = u8::from(user_state.is_curator) | u8::from(user_state.is_validator) << 1;
+            (*addr, flags)
+        })
+        .collect();
+    Ok(response)
+}
+
+/// View the curators whose curator role was granted between `from` and `to`, both inclusive.
+/// Curators without the grant time (e.g. granted before the time was recorded) are excluded.
+///
+/// Caller: Any accounts / Any contracts
+#[receive(
+    contract = "overlay-users",
+    name = "curators_granted_between",
+    parameter = "CuratorsGrantedBetweenParams",
+    return_value = "CuratorsGrantedBetweenResponse"
+)]
+fn contract_curators_granted_between<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<CuratorsGrantedBetweenResponse> {
+    let params: CuratorsGrantedBetweenParams = ctx.parameter_cursor().get()?;
+    let response = host
+        .state()
+        .user
+        .iter()
+        .filter(|(_, user_state)| {
+            user_state.is_curator
+                && user_state
+                    .curator_since
+                    .map_or(false, |since| params.from <= since && since <= params.to)
+        })
+        .map(|(addr, _)| *addr)
+        .collect();
+    Ok(response)
+}
+
+/// View the users whose role flags are not reflected in `curator_list` or `validator_list`.
+/// These are the users `backfill_lists` would add to the lists.
+///
+/// Caller: Any accounts / Any contracts
+#[receive(
+    contract = "overlay-users",
+    name = "view_drifted_users",
+    return_value = "ViewDriftedUsersResponse"
+)]
+fn contract_view_drifted_users<S: HasStateApi>(
+    _ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<ViewDriftedUsersResponse> {
+    let state = host.state();
+    let mut response = ViewDriftedUsersResponse {
+        curators: Vec::new(),
+        validators: Vec::new(),
+    };
+    for (addr, user_state) in state.user.iter() {
+        if user_state.is_curator && !state.curator_list.contains(&addr) {
+            response.curators.push(*addr);
+        }
+        if user_state.is_validator && !state.validator_list.contains(&addr) {
+            response.validators.push(*addr);
+        }
+    }
+    Ok(response)
+}
+
+/// View the entries of `curator_list` and `validator_list` whose users are unknown or do not
+/// hold the role. These are the entries a repair would remove from the lists.
+/// The response follows the order of the lists.
+///
+/// Caller: Any accounts / Any contracts
+#[receive(
+    contract = "overlay-users",
+    name = "view_stale_list_entries",
+    return_value = "ViewStaleListEntriesResponse"
+)]
+fn contract_view_stale_list_entries<S: HasStateApi>(
+    _ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<ViewStaleListEntriesResponse> {
+    let state = host.state();
+    let stale_curators = state
+        .curator_list
+        .iter()
+        .filter(|addr| {
+            !state
+                .user
+                .get(addr)
+                .map_or(false, |user_state| user_state.is_curator)
+        })
+        .copied()
+        .collect();
+    let stale_validators = state
+        .validator_list
+        .iter()
+        .filter(|addr| {
+            !state
+                .user
+                .get(addr)
+                .map_or(false, |user_state| user_state.is_validator)
+        })
+        .copied()
+        .collect();
+    Ok(ViewStaleListEntriesResponse {
+        stale_curators,
+        stale_validators,
+    })
+}
+
+/// View the union of `curator_list` and `validator_list` without duplicates.
+/// The entries of `curator_list` come first, followed by the entries only in `validator_list`.
+///
+/// Caller: Any accounts / Any contracts
+#[receive(
+    contract = "overlay-users",
+    name = "view_all_role_holders",
+    return_value = "ViewAllRoleHoldersResponse"
+)]
+fn contract_view_all_role_holders<S: HasStateApi>(
+    _ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<ViewAllRoleHoldersResponse> {
+    let state = host.state();
+    let mut seen = BTreeSet::new();
+    let response = state
+        .curator_list
+        .iter()
+        .chain(state.validator_list.iter())
+        .filter(|addr| seen.insert(**addr))
+        .copied()
+        .collect();
+    Ok(response)
+}
+
+/// Check the invariants of the state and report whether each of them holds.
+/// Each invariant is checked by its own pass over the state.
+///
+/// Caller: Any accounts / Any contracts
+#[receive(
+    contract = "overlay-users",
+    name = "audit_state",
+    return_value = "AuditStateResponse"
+)]
+fn contract_audit_state<S: HasStateApi>(
+    _ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<AuditStateResponse> {
+    let state = host.state();
+    let flagged_users_listed = state.user.iter().all(|(addr, user_state)| {
+        (!user_state.is_curator || state.curator_list.contains(&addr))
+            && (!user_state.is_validator || state.validator_list.contains(&addr))
+    });
+    let listed_users_flagged = state.curator_list.iter().all(|addr| {
+        state
+            .user
+            .get(addr)
+            .map_or(false, |user_state| user_state.is_curator)
+    }) && state.validator_list.iter().all(|addr| {
+        state
+            .user
+            .get(addr)
+            .map_or(false, |user_state| user_state.is_validator)
+    });
+    let has_no_duplicate =
+        |list: &Vec<AccountAddress>| list.iter().collect::<BTreeSet<_>>().len() == list.len();
+    let no_empty_project_ids = state.user.iter().all(|(_, user_state)| {
+        user_state
+            .curated_projects
+            .iter()
+            .all(|project_id| !project_id.is_empty())
+            && user_state
+                .validated_projects
+                .iter()
+                .all(|(project_id, _)| !project_id.is_empty())
+    });
+    Ok(AuditStateResponse {
+        lists_match_map: flagged_users_listed && listed_users_flagged,
+        no_duplicate_list_entries: has_no_duplicate(&state.curator_list)
+            && has_no_duplicate(&state.validator_list),
+        no_empty_project_ids,
+        project_contract_configured: state.project_contract_addr != ContractAddress::new(0, 0),
+    })
+}
+
+/// Check whether the state has been updated since the given `state_seq`.
+/// Clients can poll this function and re-read the state only when it is stale.
+///
+/// Caller: Any accounts / Any contracts
+#[receive(
+    contract = "overlay-users",
+    name = "changed_since",
+    parameter = "ChangedSinceParams",
+    return_value = "ChangedSinceResponse"
+)]
+fn contract_changed_since<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<ChangedSinceResponse> {
+    let last_seen: ChangedSinceParams = ctx.parameter_cursor().get()?;
+    let state_seq = host.state().state_seq;
+    Ok(ChangedSinceResponse {
+        stale: last_seen < state_seq,
+        state_seq,
+    })
+}
+
+/// View the aggregated statistics of the OVERLAY users.
+/// All the statistics are computed in a single pass over the user state.
+///
+/// Caller: Any accounts / Any contracts
+#[receive(
+    contract = "overlay-users",
+    name = "view_stats",
+    return_value = "ViewStatsResponse"
+)]
+fn contract_view_stats<S: HasStateApi>(
+    _ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<ViewStatsResponse> {
+    let mut stats = ViewStatsResponse::default();
+    for (_, user_state) in host.state().user.iter() {
+        stats.user_count += 1;
+        if user_state.is_curator {
+            stats.curator_count += 1;
+        }
+        if user_state.is_validator {
+            stats.validator_count += 1;
+        }
+        if user_state.is_curator && user_state.is_validator {
+            stats.dual_role_count += 1;
+        }
+        stats.total_curations += user_state.curated_projects.len() as u64;
+        stats.total_validations += user_state.validated_projects.len() as u64;
+    }
+    Ok(stats)
+}
+
+/// View the number of users for each combination of the curator and validator roles.
+/// The users are classified in a single pass over the user state.
+///
+/// Caller: Any accounts / Any contracts
+#[receive(
+    contract = "overlay-users",
+    name = "view_role_histogram",
+    return_value = "ViewRoleHistogramResponse"
+)]
+fn contract_view_role_histogram<S: HasStateApi>(
+    _ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<ViewRoleHistogramResponse> {
+    let mut histogram = ViewRoleHistogramResponse::default();
+    for (_, user_state) in host.state().user.iter() {
+        match (user_state.is_curator, user_state.is_validator) {
+            (false, false) => histogram.none += 1,
+            (true, false) => histogram.curator_only += 1,
+            (false, true) => histogram.validator_only += 1,
+            (true, true) => histogram.both += 1,
+        }
+    }
+    Ok(histogram)
+}
+
+/// View the accounts that applied for a role with `apply_for_role` and are not granted yet.
+///
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
+#[receive(
+    contract = "overlay-users",
+    name = "view_pending_applications",
+    return_value = "ViewPendingApplicationsResponse",
+    error = "Error"
+)]
+fn contract_view_pending_applications<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<ViewPendingApplicationsResponse> {
+    let state = host.state();
+    ensure!(is_admin(ctx, &state.admin), Error::InvalidCaller);
+    Ok(ViewPendingApplicationsResponse {
+        curators: state.pending_curators.iter().map(|addr| *addr).collect(),
+        validators: state.pending_validators.iter().map(|addr| *addr).collect(),
+    })
+}
+
+/// View the number of the pending applications for each role.
+///
+/// Caller: Any accounts / Any contracts
+#[receive(
+    contract = "overlay-users",
+    name = "pending_count",
+    return_value = "PendingCountResponse"
+)]
+fn contract_pending_count<S: HasStateApi>(
+    _ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<PendingCountResponse> {
+    let state = host.state();
+    Ok(PendingCountResponse {
+        curators: state.pending_curator_count,
+        validators: state.pending_validator_count,
+    })
+}
+
+/// View the SHA-256 digest of the users, the role lists and the configuration.
+/// The users are serialized in ascending order of their addresses, and the sets are serialized
+/// in ascending order of their elements, each prefixed by its size as a `u32`, so an off-chain mirror
+/// can compute the same digest to detect divergence.
+/// The bookkeeping fields `state_seq`, `event_seq`, `cached_counts`, `last_admin_activity`,
+/// `admin_action_counts`, `last_snapshot` and `last_snapshot_time` are not included.
+///
+/// Caller: Any accounts / Any contracts
+#[receive(
+    contract = "overlay-users",
+    name = "view_state_digest",
+    return_value = "ViewStateDigestResponse",
+    crypto_primitives
+)]
+fn contract_view_state_digest<S: HasStateApi>(
+    _ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+    crypto_primitives: &impl HasCryptoPrimitives,
+) -> ContractResult<ViewStateDigestResponse> {
+    let bytes = serial_state_digest(host.state());
+    Ok(crypto_primitives.hash_sha2_256(&bytes).0)
+}
+
+/// Serialize the part of the state covered by `view_state_digest`.
+fn serial_state_digest<S: HasStateApi>(state: &State<S>) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    // `StateMap` iterates in the order of the serialized keys, which is the address order for the
+    // fixed-size addresses. The users are counted while iterating, so the count is written afterwards.
+    let mut user_count = 0u32;
+    let mut user_bytes = Vec::new();
+    for (addr, user_state) in state.user.iter() {
+        user_count += 1;
+        user_bytes.extend(to_bytes(&*addr));
+        user_bytes.extend(to_bytes(&*user_state));
+    }
+    bytes.extend(to_bytes(&user_count));
+    bytes.extend(user_bytes);
+    bytes.extend(to_bytes(&state.curator_list));
+    bytes.extend(to_bytes(&state.validator_list));
+    bytes.extend(to_bytes(&state.admin));
+    bytes.extend(to_bytes(&state.project_contract_addr));
+    bytes.extend(to_bytes(&state.disjoint_project_roles));
+    bytes.extend(to_bytes(&state.last_admin_change));
+    bytes.extend(to_bytes(&state.config_locked));
+    bytes.extend(to_bytes(&state.auto_prune_on_remove));
+    bytes.extend(to_bytes(&state.default_user_state));
+    bytes.extend(to_bytes(&state.reject_privileged_targets));
+    bytes.extend(to_bytes(&state.max_projects_per_user));
+    bytes.extend(to_bytes(&state.curators_frozen));
+    bytes.extend(to_bytes(&state.require_known_users));
+    bytes.extend(to_bytes(&state.users_view_public));
+    bytes.extend(to_bytes(&state.events_enabled));
+    // `StateSet` iterates in the order of the serialized keys, which puts shorter project ids first
+    // because of their length prefix. Collecting into a `BTreeSet` sorts them as strings, and
+    // serializes them prefixed by their count.
+    let allowed_projects: BTreeSet<ProjectId> = state
+        .allowed_projects
+        .iter()
+        .map(|project_id| project_id.clone())
+        .collect();
+    bytes.extend(to_bytes(&allowed_projects));
+    bytes.extend(to_bytes(&state.curate_auto_create));
+    bytes.extend(to_bytes(&state.role_cooldown));
+    // The counts of the sets come from their elements rather than the cached pending counts, so
+    // moving an address between the two pending sets changes the digest even if the counts drift.
+    let pending_curators: BTreeSet<AccountAddress> =
+        state.pending_curators.iter().map(|addr| *addr).collect();
+    bytes.extend(to_bytes(&pending_curators));
+    let pending_validators: BTreeSet<AccountAddress> =
+        state.pending_validators.iter().map(|addr| *addr).collect();
+    bytes.extend(to_bytes(&pending_validators));
+    bytes.extend(to_bytes(&state.recovery_account));
+    bytes.extend(to_bytes(&state.admin_inactivity_timeout));
+    let observers: BTreeSet<AccountAddress> = state.observers.iter().map(|addr| *addr).collect();
+    bytes.extend(to_bytes(&observers));
+    bytes.extend(to_bytes(&state.curator_weight));
+    bytes.extend(to_bytes(&state.validator_weight));
+    bytes.extend(to_bytes(&state.strict_removals));
+    let mut scored_user_count = 0u32;
+    let mut score_bytes = Vec::new();
+    for (addr, scores) in state.curation_scores.iter() {
+        scored_user_count += 1;
+        score_bytes.extend(to_bytes(&*addr));
+        score_bytes.extend(to_bytes(&*scores));
+    }
+    bytes.extend(to_bytes(&scored_user_count));
+    bytes.extend(score_bytes);
+    bytes
+}
+
+/// View the number of distinct project ids curated or validated by any user.
+/// Note that this collects all the project ids into a set, so the cost grows with
+/// the total number of user-project relationships both in time and in memory.
+///
+/// Caller: Any accounts / Any contracts
+#[receive(
+    contract = "overlay-users",
+    name = "distinct_project_count",
+    return_value = "u64"
+)]
+fn contract_distinct_project_count<S: HasStateApi>(
+    _ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<u64> {
+    let mut project_ids = BTreeSet::new();
+    for (_, user_state) in host.state().user.iter() {
+        project_ids.extend(user_state.curated_projects.iter().cloned());
+        project_ids.extend(
+            user_state
+                .validated_projects
+                .iter()
+                .map(|(project_id, _)| project_id.clone()),
+        );
+    }
+    Ok(project_ids.len() as u64)
+}
+
+/// View the number of curators of each project id, in ascending order of the project ids.
+/// Note that the response grows with the number of distinct curated project ids.
+///
+/// Caller: Any accounts / Any contracts
+#[receive(
+    contract = "overlay-users",
+    name = "project_curation_counts",
+    return_value = "ProjectCurationCountsResponse"
+)]
+fn contract_project_curation_counts<S: HasStateApi>(
+    _ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<ProjectCurationCountsResponse> {
+    let mut counts = BTreeMap::<ProjectId, u32>::new();
+    for (_, user_state) in host.state().user.iter() {
+        for project_id in user_state.curated_projects.iter() {
+            *counts.entry(project_id.clone()).or_insert(0) += 1;
+        }
+    }
+    Ok(counts.into_iter().collect())
+}
+
+/// View the number of validators of each project id, in ascending order of the project ids.
+/// Note that the response grows with the number of distinct validated project ids.
+///
+/// Caller: Any accounts / Any contracts
+#[receive(
+    contract = "overlay-users",
+    name = "project_validation_counts",
+    return_value = "ProjectValidationCountsResponse"
+)]
+fn contract_project_validation_counts<S: HasStateApi>(
+    _ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<ProjectValidationCountsResponse> {
+    let mut counts = BTreeMap::<ProjectId, u32>::new();
+    for (_, user_state) in host.state().user.iter() {
+        for (project_id, _) in user_state.validated_projects.iter() {
+            *counts.entry(project_id.clone()).or_insert(0) += 1;
+        }
+    }
+    Ok(counts.into_iter().collect())
+}
+
+/// Check whether any user curates or validates the project id.
+/// The users are scanned until the first reference is found.
+///
+/// Caller: Any accounts / Any contracts
+///
+/// This function is designed to be called by overlay-projects before a project is deleted.
+#[receive(
+    contract = "overlay-users",
+    name = "is_project_referenced",
+    parameter = "IsProjectReferencedParams",
+    return_value = "bool"
+)]
+fn contract_is_project_referenced<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<bool> {
+    let project_id: IsProjectReferencedParams = ctx.parameter_cursor().get()?;
+    let referenced = host.state().user.iter().any(|(_, user_state)| {
+        user_state.has_curated(&project_id) || user_state.has_validated(&project_id)
+    });
+    Ok(referenced)
+}
+
+/// Check whether any user curates or validates each of the project ids.
+/// The response follows the input order. The users are scanned once for all the project ids.
+///
+/// Caller: Any accounts / Any contracts
+///
+/// This function is designed to be called by overlay-projects before projects are deleted in bulk.
+#[receive(
+    contract = "overlay-users",
+    name = "which_projects_referenced",
+    parameter = "WhichProjectsReferencedParams",
+    return_value = "WhichProjectsReferencedResponse"
+)]
+fn contract_which_projects_referenced<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<WhichProjectsReferencedResponse> {
+    let project_ids: WhichProjectsReferencedParams = ctx.parameter_cursor().get()?;
+    let mut response: WhichProjectsReferencedResponse = project_ids
+        .into_iter()
+        .map(|project_id| (project_id, false))
+        .collect();
+    for (_, user_state) in host.state().user.iter() {
+        for (project_id, referenced) in response.iter_mut() {
+            if !*referenced {
+                *referenced =
+                    user_state.has_curated(project_id) || user_state.has_validated(project_id);
+            }
+        }
+        if response.iter().all(|(_, referenced)| *referenced) {
+            break;
+        }
+    }
+    Ok(response)
+}
+
+/// View every curated project id starting with the inputted prefix, paired with the user curating it.
+/// Note that the response grows with the number of the matching project ids.
+///
+/// Caller: Any accounts / Any contracts
+/// Reject if:
+/// * The prefix is longer than `MAX_PROJECT_PREFIX_LEN` bytes (`InvalidArgument`).
+#[receive(
+    contract = "overlay-users",
+    name = "curators_of_project_prefix",
+    parameter = "CuratorsOfProjectPrefixParams",
+    return_value = "CuratorsOfProjectPrefixResponse"
+)]
+fn contract_curators_of_project_prefix<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<CuratorsOfProjectPrefixResponse> {
+    let prefix: CuratorsOfProjectPrefixParams = ctx.parameter_cursor().get()?;
+    ensure!(
+        prefix.len() <= MAX_PROJECT_PREFIX_LEN,
+        Error::InvalidArgument
+    );
+    let mut response = Vec::new();
+    for (addr, user_state) in host.state().user.iter() {
+        for project_id in user_state.curated_projects.iter() {
+            if project_id.starts_with(prefix.as_str()) {
+                response.push((*addr, project_id.clone()));
+            }
+        }
+    }
+    Ok(response)
+}
+
+/// View each curator address with the number of its curated projects.
+/// The response follows the order of `curator_list`.
+/// A listed curator that does not exist in the user state is reported with 0 projects.
+///
+/// Caller: Any accounts / Any contracts
+#[receive(
+    contract = "overlay-users",
+    name = "view_curators_flat",
+    return_value = "ViewCuratorsFlatResponse"
+)]
+fn contract_view_curators_flat<S: HasStateApi>(
+    _ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<ViewCuratorsFlatResponse> {
+    let state = host.state();
+    let response = state
+        .curator_list
+        .iter()
+        .map(|addr| {
+            let curated_count = state
+                .user
+                .get(addr)
+                .map(|user_state| user_state.curated_projects.len() as u32)
+                .unwrap_or(0);
+            (*addr, curated_count)
+        })
+        .collect();
+    Ok(response)
+}
+
+/// View the 1-based rank of the user among the curators by the number of curated projects,
+/// in descending order. Curators with the same number of curated projects share the same rank.
+/// Returns None if the user is not a curator.
+///
+/// Caller: Any accounts / Any contracts
+#[receive(
+    contract = "overlay-users",
+    name = "curator_rank",
+    parameter = "CuratorRankParams",
+    return_value = "Option<u32>"
+)]
+fn contract_curator_rank<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<Option<u32>> {
+    let params: CuratorRankParams = ctx.parameter_cursor().get()?;
+    let state = host.state();
+    let curated_count = match state.user.get(&params.addr) {
+        Some(user_state) if user_state.is_curator => user_state.curated_projects.len(),
+        _ => return Ok(None),
+    };
+    let higher_count = state
+        .user
+        .iter()
+        .filter(|(_, user_state)| {
+            user_state.is_curator && user_state.curated_projects.len() > curated_count
+        })
+        .count();
+    Ok(Some(higher_count as u32 + 1))
+}
+
+/// View the activity score of the user, which is the number of the curated projects multiplied by
+/// `curator_weight` plus the number of the validated projects multiplied by `validator_weight`.
+/// The score of an unknown user is 0. The score saturates at `u64::MAX`.
+///
+/// Caller: Any accounts / Any contracts
+#[receive(
+    contract = "overlay-users",
+    name = "view_user_score",
+    parameter = "ViewUserScoreParams",
+    return_value = "u64"
+)]
+fn contract_view_user_score<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<u64> {
+    let params: ViewUserScoreParams = ctx.parameter_cursor().get()?;
+    let state = host.state();
+    let score = state
+        .user
+        .get(&params.addr)
+        .map(|user_state| {
+            (user_state.curated_projects.len() as u64)
+                .saturating_mul(state.curator_weight)
+                .saturating_add(
+                    (user_state.validated_projects.len() as u64)
+                        .saturating_mul(state.validator_weight),
+                )
+        })
+        .unwrap_or(0);
+    Ok(score)
+}
+
+/// View the curated project ids of the user with the quality scores attached by `curate_with_score`.
+/// The response follows the order of the curated projects of the user, and the curations recorded
+/// without a score have the score 0.
+/// If the requested user address dose not exist in the state, it returns an empty list.
+///
+/// Caller: Any accounts / Any contracts
+#[receive(
+    contract = "overlay-users",
+    name = "view_curation_scores",
+    parameter = "ViewCurationScoresParams",
+    return_value = "ViewCurationScoresResponse"
+)]
+fn contract_view_curation_scores<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<ViewCurationScoresResponse> {
+    let params: ViewCurationScoresParams = ctx.parameter_cursor().get()?;
+    let state = host.state();
+    let scores = state
+        .curation_scores
+        .get(&params.addr)
+        .map(|scores| scores.clone())
+        .unwrap_or_default();
+    let response = state
+        .user
+        .get(&params.addr)
+        .map(|user_state| {
+            user_state
+                .curated_projects
+                .iter()
+                .map(|project_id| {
+                    let score = scores
+                        .iter()
+                        .find(|(scored, _)| scored == project_id)
+                        .map_or(0, |(_, score)| *score);
+                    (project_id.clone(), score)
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    Ok(response)
+}
+
+/// View at most `limit` curators with the number of their curated projects,
+/// ordered by the number descending. Curators with the same number are ordered by address.
+/// All the users are scanned and sorted, so the cost is O(n log n) in the number of users.
+///
+/// Caller: Any accounts / Any contracts
+#[receive(
+    contract = "overlay-users",
+    name = "top_curators",
+    parameter = "TopCuratorsParams",
+    return_value = "TopCuratorsResponse"
+)]
+fn contract_top_curators<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<TopCuratorsResponse> {
+    let params: TopCuratorsParams = ctx.parameter_cursor().get()?;
+    let mut curators: TopCuratorsResponse = host
+        .state()
+        .user
+        .iter()
+        .filter(|(_, user_state)| user_state.is_curator)
+        .map(|(addr, user_state)| (*addr, user_state.curated_projects.len() as u32))
+        .collect();
+    // the sort is stable, so the address order of the user state is kept for ties.
+    curators.sort_by(|(_, a), (_, b)| b.cmp(a));
+    curators.truncate(params.limit.try_into().unwrap_or(usize::MAX));
+    Ok(curators)
+}
+
+/// View the most recently added curators with their user data, the most recent first.
+/// Since `add_curator` pushes to `curator_list`, the tail of the list holds the newest curators.
+/// If `limit` is larger than `curator_list`, all the curators are returned.
+/// A listed curator that does not exist in the user state is reported with the default user data.
+///
+/// Caller: Any accounts / Any contracts
+#[receive(
+    contract = "overlay-users",
+    name = "recent_curators",
+    parameter = "RecentCuratorsParams",
+    return_value = "RecentCuratorsResponse"
+)]
+fn contract_recent_curators<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<RecentCuratorsResponse> {
+    let params: RecentCuratorsParams = ctx.parameter_cursor().get()?;
+    let state = host.state();
+    let response = state
+        .curator_list
+        .iter()
+        .rev()
+        .map(|addr| {
+            let user_state = state
+                .user
+                .get(addr)
+                .map(|user_state_ref| user_state_ref.clone())
+                .unwrap_or_default();
+            (*addr, user_state)
+        })
+        .filter(|(_, user_state)| !user_state.deleted)
+        .take(params.limit.try_into().unwrap_or(usize::MAX))
+        .collect();
+    Ok(response)
+}
+
+/// View the number of users inserted into the state at or after `since`.
+/// Users inserted before the creation time was recorded are not counted.
+///
+/// Caller: Any accounts / Any contracts
+#[receive(
+    contract = "overlay-users",
+    name = "users_created_since",
+    parameter = "UsersCreatedSinceParams",
+    return_value = "u64"
+)]
+fn contract_users_created_since<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<u64> {
+    let since: UsersCreatedSinceParams = ctx.parameter_cursor().get()?;
+    let count = host
+        .state()
+        .user
+        .iter()
+        .filter(|(_, user_state)| {
+            user_state
+                .created_at
+                .map_or(false, |created_at| created_at >= since)
+        })
+        .count();
+    Ok(count as u64)
+}
+
+/// View the earliest and the latest grant times of the roles currently held by the users.
+/// Both are `None` if no user has a recorded grant time.
+///
+/// Caller: Any accounts / Any contracts
+#[receive(
+    contract = "overlay-users",
+    name = "view_activity_span",
+    return_value = "ViewActivitySpanResponse"
+)]
+fn contract_view_activity_span<S: HasStateApi>(
+    _ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<ViewActivitySpanResponse> {
+    let mut response = ViewActivitySpanResponse {
+        first_grant: None,
+        last_grant: None,
+    };
+    for (_, user_state) in host.state().user.iter() {
+        for since in [user_state.curator_since, user_state.validator_since]
+            .into_iter()
+            .flatten()
+        {
+            response.first_grant =
+                Some(response.first_grant.map_or(since, |first| first.min(since)));
+            response.last_grant = Some(response.last_grant.map_or(since, |last| last.max(since)));
+        }
+    }
+    Ok(response)
+}
+
+/// View at most `limit` users ordered by their latest role grant time, the most recent first.
+/// The latest of `curator_since` and `validator_since` is used as the grant time of a user.
+/// Users without any grant time are placed last.
+///
+/// Caller: Any accounts / Any contracts if `users_view_public` is set, otherwise current admin account.
+/// Reject if:
+/// * `users_view_public` is not set and caller is not the current admin account.
+#[receive(
+    contract = "overlay-users",
+    name = "view_users_by_grant_time",
+    parameter = "ViewUsersByGrantTimeParams",
+    return_value = "ViewUsersByGrantTimeResponse"
+)]
+fn contract_view_users_by_grant_time<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<ViewUsersByGrantTimeResponse> {
+    let params: ViewUsersByGrantTimeParams = ctx.parameter_cursor().get()?;
+    let state = host.state();
+    ensure!(
+        state.users_view_public || is_admin(ctx, &state.admin),
+        Error::InvalidCaller
+    );
+    let mut users: ViewUsersByGrantTimeResponse = state
+        .user
+        .iter()
+        .filter(|(_, user_state)| params.include_deleted || !user_state.deleted)
+        .map(|(addr, user_state)| (*addr, user_state.clone()))
+        .collect();
+    // `None` is less than any `Some`, so the users without the grant time come last.
+    users.sort_by(|(_, a), (_, b)| b.latest_grant_time().cmp(&a.latest_grant_time()));
+    users.truncate(params.limit.try_into().unwrap_or(usize::MAX));
+    Ok(users)
+}
+
+/// View all the user addresses holding the tag.
+///
+/// Caller: Any accounts / Any contracts
+#[receive(
+    contract = "overlay-users",
+    name = "users_by_tag",
+    parameter = "UsersByTagParams",
+    return_value = "UsersByTagResponse"
+)]
+fn contract_users_by_tag<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<UsersByTagResponse> {
+    let params: UsersByTagParams = ctx.parameter_cursor().get()?;
+    let response = host
+        .state()
+        .user
+        .iter()
+        .filter(|(_, user_state)| user_state.tags.contains(&params.tag))
+        .map(|(addr, _)| *addr)
+        .collect();
+    Ok(response)
+}
+
+/// Check whether the caller is the configured overlay-projects contract.
+/// The overlay-projects contract can invoke this to confirm the wiring before invoking `curate`.
+///
+/// Caller: Any accounts / Any contracts
+#[receive(
+    contract = "overlay-users",
+    name = "am_i_project_contract",
+    return_value = "bool"
+)]
+fn contract_am_i_project_contract<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<bool> {
+    Ok(ctx.sender() == Address::Contract(host.state().project_contract_addr))
+}
+
+/// Check whether the user has curated the project.
+/// If the requested user address dose not exist in the state, it returns false.
+///
+/// Caller: Any accounts / Any contracts
+#[receive(
+    contract = "overlay-users",
+    name = "has_curated",
+    parameter = "HasCuratedParams",
+    return_value = "bool"
+)]
+fn contract_has_curated<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<bool> {
+    let params: HasCuratedParams = ctx.parameter_cursor().get()?;
+    Ok(host
+        .state()
+        .user
+        .get(&params.addr)
+        .map(|user_state| user_state.has_curated(&params.project_id))
+        .unwrap_or(false))
+}
+
+/// Check whether the user has validated the project.
+/// If the requested user address dose not exist in the state, it returns false.
+///
+/// Caller: Any accounts / Any contracts
+#[receive(
+    contract = "overlay-users",
+    name = "has_validated",
+    parameter = "HasValidatedParams",
+    return_value = "bool"
+)]
+fn contract_has_validated<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<bool> {
+    let params: HasValidatedParams = ctx.parameter_cursor().get()?;
+    Ok(host
+        .state()
+        .user
+        .get(&params.addr)
+        .map(|user_state| user_state.has_validated(&params.project_id))
+        .unwrap_or(false))
+}
+
+/// Check whether the user has both curated and validated the project, e.g. for conflict-of-interest checks.
+/// If the requested user address dose not exist in the state, it returns false.
+///
+/// Caller: Any accounts / Any contracts
+#[receive(
+    contract = "overlay-users",
+    name = "self_curated_and_validated",
+    parameter = "SelfCuratedAndValidatedParams",
+    return_value = "bool"
+)]
+fn contract_self_curated_and_validated<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<bool> {
+    let params: SelfCuratedAndValidatedParams = ctx.parameter_cursor().get()?;
+    Ok(host
+        .state()
+        .user
+        .get(&params.addr)
+        .map(|user_state| {
+            user_state.has_curated(&params.project_id)
+                && user_state.has_validated(&params.project_id)
+        })
+        .unwrap_or(false))
+}
+
+/// Check whether each of the inputted accounts is a curator.
+/// The response preserves the input order. Unknown accounts are reported as non-curators.
+///
+/// Caller: Any accounts / Any contracts
+#[receive(
+    contract = "overlay-users",
+    name = "are_curators",
+    parameter = "AreCuratorsParams",
+    return_value = "AreCuratorsResponse"
+)]
+fn contract_are_curators<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<AreCuratorsResponse> {
+    let params: AreCuratorsParams = ctx.parameter_cursor().get()?;
+    let users = &host.state().user;
+    let response = params
+        .into_iter()
+        .map(|addr| {
+            let is_curator = users
+                .get(&addr)
+                .map(|user_state| user_state.is_curator)
+                .unwrap_or(false);
+            (addr, is_curator)
+        })
+        .collect();
+    Ok(response)
+}
+
+/// Filter the inputted accounts down to the ones that exist in the state.
+/// The response preserves the input order.
+///
+/// Caller: Any accounts / Any contracts
+#[receive(
+    contract = "overlay-users",
+    name = "filter_known_users",
+    parameter = "FilterKnownUsersParams",
+    return_value = "FilterKnownUsersResponse"
+)]
+fn contract_filter_known_users<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<FilterKnownUsersResponse> {
+    let params: FilterKnownUsersParams = ctx.parameter_cursor().get()?;
+    let users = &host.state().user;
+    let response = params
+        .into_iter()
+        .filter(|addr| users.get(addr).is_some())
+        .collect();
+    Ok(response)
+}
+
+/// Compare the inputted expected curator set with `curator_list`.
+/// `missing` follows the input order and `extra` follows the order of `curator_list`.
+///
+/// Caller: Any accounts / Any contracts
+#[receive(
+    contract = "overlay-users",
+    name = "diff_curators",
+    parameter = "DiffCuratorsParams",
+    return_value = "DiffCuratorsResponse"
+)]
+fn contract_diff_curators<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<DiffCuratorsResponse> {
+    let params: DiffCuratorsParams = ctx.parameter_cursor().get()?;
+    let curator_list = &host.state().curator_list;
+    let curators: BTreeSet<&AccountAddress> = curator_list.iter().collect();
+    let expected: BTreeSet<&AccountAddress> = params.iter().collect();
+    let mut missing = Vec::new();
+    for addr in params.iter() {
+        if !curators.contains(addr) && !missing.contains(addr) {
+            missing.push(*addr);
+        }
+    }
+    let extra = curator_list
+        .iter()
+        .filter(|addr| !expected.contains(addr))
+        .copied()
+        .collect();
+    Ok(DiffCuratorsResponse { missing, extra })
+}
+
+/// Check whether the caller is the admin of this module.
+/// An account admin is compared with the invoker and a contract admin with the sender.
+fn is_admin(ctx: &impl HasReceiveContext, admin: &Address) -> bool {
+    match admin {
+        Address::Account(admin_account) => ctx.invoker() == *admin_account,
+        Address::Contract(_) => ctx.sender() == *admin,
+    }
+}
+
+/// Collect the user state for `view_users` and `view_users_with_deleted`.
+fn collect_users<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    state: &State<S>,
+    include_deleted: bool,
+) -> ContractResult<ViewUsersResponse> {
+    ensure!(
+        state.users_view_public || is_admin(ctx, &state.admin),
+        Error::InvalidCaller
+    );
+    let users = &state.user;
+    let users_response = users
+        .iter()
+        .filter(|(_, user_state_ref)| include_deleted || !user_state_ref.deleted)
+        .map(|(account_address_ref, user_state_ref)| {
+            (account_address_ref.clone(), user_state_ref.clone())
+        })
+        .collect();
+    Ok(users_response)
+}
+
+/// Mark the user as a curator, registering it from `default_user_state` if it does not exist,
+/// and keep `curator_list` and `validator_list` in sync. A pending curator application is removed.
+/// Returns true if the state has been changed.
+fn grant_curator<S: HasStateApi>(
+    state: &mut State<S>,
+    addr: &AccountAddress,
+    now: Timestamp,
+) -> bool {
+    let default_user_state = state.default_user_state.clone();
+    let is_new_user = state.user.get(addr).is_none();
+    let mut changed = !state
+        .user
+        .get(addr)
+        .map_or(false, |user_state| user_state.is_curator);
+    let is_new_validator = default_user_state.is_validator && is_new_user;
+    state
+        .user
+        .entry(*addr)
+        .and_modify(|user_state| {
+            if !user_state.is_curator {
+                user_state.last_role_change = Some(now);
+            }
+            user_state.set_curator(true, now);
+        })
+        .or_insert_with(|| {
+            let mut user_state = UserState::from_template(&default_user_state, now);
+            user_state.set_curator(true, now);
+            user_state.last_role_change = Some(now);
+            user_state
+        });
+    if !state.curator_list.contains(addr) {
+        state.curator_list.push(*addr);
+        changed = true;
+    }
+    if is_new_validator && !state.validator_list.contains(addr) {
+        state.validator_list.push(*addr);
+    }
+    if is_new_user {
+        state.cached_counts.user_count += 1;
+    }
+    refresh_list_counts(state);
+    changed | clear_application(state, Role::Curator, addr)
+}
+
+/// Check the preconditions of `curate` for the user and the project id without updating the state.
+fn check_curate_project<S: HasStateApi>(
+    state: &State<S>,
+    addr: &AccountAddress,
+    project_id: &ProjectId,
+) -> ContractResult<()> {
+    ensure!(
+        !state.reject_privileged_targets || state.admin != Address::Account(*addr),
+        Error::InvalidArgument
+    );
+    ensure!(
+        is_project_allowed(state, project_id),
+        Error::ProjectNotAllowed
+    );
+    let target_user = state.user.get(addr);
+    ensure!(target_user.is_some(), Error::UserNotFound);
+    let target_user = target_user.unwrap();
+    ensure!(!target_user.deleted, Error::UserDeleted);
+    ensure!(target_user.is_curator, Error::NotACurator);
+    ensure!(
+        !state.disjoint_project_roles || !target_user.has_validated(project_id),
+        Error::ProjectRoleConflict
+    );
+    ensure!(
+        target_user.has_curated(project_id)
+            || target_user.can_add_project(state.max_projects_per_user),
+        Error::TooManyProjects
+    );
+    Ok(())
+}
+
+/// Add project id to the curated projects of the user, applying the preconditions of `curate`.
+/// Returns true if the project id has been added.
+fn curate_project<S: HasStateApi>(
+    state: &mut State<S>,
+    addr: &AccountAddress,
+    project_id: &ProjectId,
+) -> ContractResult<bool> {
+    check_curate_project(state, addr, project_id)?;
+    let mut target_user = state.user.get_mut(addr).ok_or(Error::UserNotFound)?;
+    if target_user.has_curated(project_id) {
+        return Ok(false);
+    }
+    target_user.curated_projects.push(project_id.clone());
+    Ok(true)
+}
+
+/// Register the unknown user as a curator for `curate` if `curate_auto_create` is set, unless the
+/// curator set is frozen or `require_known_users` is set. Returns true if the user has been registered.
+fn auto_create_curator<S: HasStateApi>(
+    state: &mut State<S>,
+    addr: &AccountAddress,
+    now: Timestamp,
+) -> bool {
+    if !state.curate_auto_create
+        || state.curators_frozen
+        || state.require_known_users
+        || state.user.get(addr).is_some()
+    {
+        return false;
+    }
+    grant_curator(state, addr, now)
+}
+
+/// Drop the curation scores of the user for the project ids it does not curate anymore,
+/// removing its entry when no score is left.
+fn sync_curation_scores<S: HasStateApi>(state: &mut State<S>, addr: &AccountAddress) {
+    let curated_projects = state
+        .user
+        .get(addr)
+        .map(|user_state| user_state.curated_projects.clone())
+        .unwrap_or_default();
+    let is_empty = match state.curation_scores.get_mut(addr) {
+        Some(mut scores) => {
+            scores.retain(|(project_id, _)| curated_projects.contains(project_id));
+            scores.is_empty()
+        },
+        None => return,
+    };
+    if is_empty {
+        state.curation_scores.remove(addr);
+    }
+}
+
+/// Check the preconditions of `validate` for the user and the project id without updating the state.
+fn check_validate_project<S: HasStateApi>(
+    state: &State<S>,
+    addr: &AccountAddress,
+    project_id: &ProjectId,
+) -> ContractResult<()> {
+    ensure!(
+        !state.reject_privileged_targets || state.admin != Address::Account(*addr),
+        Error::InvalidArgument
+    );
+    ensure!(
+        is_project_allowed(state, project_id),
+        Error::ProjectNotAllowed
+    );
+    let target_user = state.user.get(addr);
+    ensure!(target_user.is_some(), Error::UserNotFound);
+    let target_user = target_user.unwrap();
+    ensure!(!target_user.deleted, Error::UserDeleted);
+    ensure!(target_user.is_validator, Error::NotAValidator);
+    ensure!(
+        !state.disjoint_project_roles || !target_user.has_curated(project_id),
+        Error::ProjectRoleConflict
+    );
+    ensure!(
+        target_user.has_validated(project_id)
+            || target_user.can_add_project(state.max_projects_per_user),
+        Error::TooManyProjects
+    );
+    Ok(())
+}
+
+/// Add project id to the validated projects of the user, applying the preconditions of `validate`.
+/// Returns true if the project id has been added.
+fn validate_project<S: HasStateApi>(
+    state: &mut State<S>,
+    addr: &AccountAddress,
+    project_id: &ProjectId,
+) -> ContractResult<bool> {
+    check_validate_project(state, addr, project_id)?;
+    let project_contract_addr = state.project_contract_addr;
+    let mut target_user = state.user.get_mut(addr).ok_or(Error::UserNotFound)?;
+    if target_user.has_validated(project_id) {
+        return Ok(false);
+    }
+    target_user
+        .validated_projects
+        .push((project_id.clone(), project_contract_addr));
+    Ok(true)
+}
+
+/// Check whether the project id can be curated and validated.
+/// An empty `allowed_projects` allows all the project ids.
+fn is_project_allowed<S: HasStateApi>(state: &State<S>, project_id: &ProjectId) -> bool {
+    state.allowed_projects.is_empty() || state.allowed_projects.contains(project_id)
+}
+
+/// Log the event unless the events are disabled by `events_enabled` of the state.
+/// The event is built with the current `event_seq`, which is incremented only when the event is logged.
+fn log_event<S: HasStateApi>(
+    logger: &mut impl HasLogger,
+    state: &mut State<S>,
+    event: impl FnOnce(u64) -> Event,
+) -> ContractResult<()> {
+    if state.events_enabled {
+        logger.log(&event(state.event_seq))?;
+        state.event_seq += 1;
+    }
+    Ok(())
+}
+
+/// Clear the recovery configuration on an admin change, so that the account named as the recovery
+/// account by the previous admin can not take over from the new admin.
+fn clear_recovery_config<S: HasStateApi>(state: &mut State<S>) {
+    state.recovery_account = None;
+    state.admin_inactivity_timeout = Duration::from_millis(0);
+    state.last_admin_activity = None;
+}
+
+/// Record an admin-gated call of the inputted function.
+/// The call is counted in `admin_action_counts`, and its time is recorded for
+/// `inactive_admin_takeover` while a recovery account is set.
+fn record_admin_activity<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    state: &mut State<S>,
+    action: &str,
+) {
+    state
+        .admin_action_counts
+        .entry(action.into())
+        .and_modify(|count| *count += 1)
+        .or_insert(1);
+    if state.recovery_account.is_some() {
+        state.last_admin_activity = Some(ctx.metadata().slot_time());
+    }
+}
+
+/// Check the preconditions of `add_curator` or `add_validator` on the state without updating it.
+fn check_add_role<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    state: &State<S>,
+    role: Role,
+    addr: &AccountAddress,
+) -> ContractResult<()> {
+    ensure!(
+        role != Role::Curator || !state.curators_frozen,
+        Error::CuratorsFrozen
+    );
+    ensure!(
+        !state.require_known_users || state.user.get(addr).is_some(),
+        Error::UserNotFound
+    );
+    ensure_not_deleted(state, addr)?;
+    let holds_role = state.user.get(addr).map_or(false, |user_state| match role {
+        Role::Curator => user_state.is_curator,
+        Role::Validator => user_state.is_validator,
+    });
+    if !holds_role {
+        ensure_role_cooldown_elapsed(state, addr, ctx.metadata().slot_time())?;
+    }
+    Ok(())
+}
+
+/// Reject with `UserDeleted` if the user exists in the state and has been soft-deleted.
+fn ensure_not_deleted<S: HasStateApi>(
+    state: &State<S>,
+    addr: &AccountAddress,
+) -> ContractResult<()> {
+    ensure!(
+        !state
+            .user
+            .get(addr)
+            .map_or(false, |user_state| user_state.deleted),
+        Error::UserDeleted
+    );
+    Ok(())
+}
+
+/// Reject with `CooldownActive` if the roles of the user have been changed within `role_cooldown`.
+fn ensure_role_cooldown_elapsed<S: HasStateApi>(
+    state: &State<S>,
+    addr: &AccountAddress,
+    now: Timestamp,
+) -> ContractResult<()> {
+    let last_role_change = state
+        .user
+        .get(addr)
+        .and_then(|user_state| user_state.last_role_change);
+    if let Some(last_role_change) = last_role_change {
+        let cooldown_end = last_role_change
+            .timestamp_millis()
+            .saturating_add(state.role_cooldown.millis());
+        ensure!(
+            now.timestamp_millis() >= cooldown_end,
+            Error::CooldownActive
+        );
+    }
+    Ok(())
+}
+
+/// Remove the user from the state if it has neither roles nor projects.
+/// Returns true if the user has been removed.
+fn prune_user<S: HasStateApi>(
+    users: &mut StateMap<AccountAddress, UserState, S>,
+    addr: &AccountAddress,
+) -> bool {
+    let is_empty = users
+        .get(addr)
+        .map(|user_state| {
+            !user_state.is_curator
+                && !user_state.is_validator
+                && user_state.curated_projects.is_empty()
+                && user_state.validated_projects.is_empty()
+        })
+        .unwrap_or(false);
+    if is_empty {
+        users.remove(addr);
+    }
+    is_empty
+}
+
+/// Remove the pending application of the user for the role, keeping the pending count in sync.
+/// Returns true if an application has been removed.
+fn clear_application<S: HasStateApi>(
+    state: &mut State<S>,
+    role: Role,
+    addr: &AccountAddress,
+) -> bool {
+    let (pending, pending_count) = match role {
+        Role::Curator => (
+            &mut state.pending_curators,
+            &mut state.pending_curator_count,
+        ),
+        Role::Validator => (
+            &mut state.pending_validators,
+            &mut state.pending_validator_count,
+        ),
+    };
+    let removed = pending.remove(addr);
+    if removed {
+        *pending_count = pending_count.saturating_sub(1);
+    }
+    removed
+}
+
+/// Set the field to the value. Returns true if the value has changed.
+fn update_field<T: PartialEq>(field: &mut T, value: T) -> bool {
+    if *field == value {
+        return false;
+    }
+    *field = value;
+    true
+}
+
+/// Refresh the cached counts of `curator_list` and `validator_list` after the lists have been changed.
+fn refresh_list_counts<S: HasStateApi>(state: &mut State<S>) {
+    state.cached_counts.curator_count = state.curator_list.len() as u64;
+    state.cached_counts.validator_count = state.validator_list.len() as u64;
+}
+
+/// Replace `a` with `b` and `b` with `a` in the list keeping the positions of the entries.
+fn swap_in_list(list: &mut [AccountAddress], a: &AccountAddress, b: &AccountAddress) {
+    for addr in list.iter_mut() {
+        if addr == a {
+            *addr = *b;
+        } else if addr == b {
+            *addr = *a;
+        }
+    }
+}
+
+/// Convert the `StateV0` layout to the current state layout.
+/// Fields that did not exist in `StateV0` are set to the same values as `init` function.
+/// Validated projects are attributed to the overlay-projects contract of the old state.
+fn migrate_state_v0<S: HasStateApi>(
+    mut old_state: StateV0<S>,
+    state_builder: &mut StateBuilder<S>,
+) -> State<S> {
+    let mut user = state_builder.new_map();
+    let mut user_count = 0;
+    for (addr, old_user_state) in old_state.user.iter() {
+        user_count += 1;
+        user.insert(
+            *addr,
+            UserState {
+                is_curator: old_user_state.is_curator,
+                is_validator: old_user_state.is_validator,
+                curated_projects: old_user_state.curated_projects.clone(),
+                validated_projects: old_user_state
+                    .validated_projects
+                    .iter()
+                    .map(|project_id| (project_id.clone(), old_state.project_contract_addr))
+                    .collect(),
+                tags: Vec::new(),
+                max_projects: None,
+                curator_since: None,
+                validator_since: None,
+                deleted: false,
+                created_at: None,
+                was_ever_curator: old_user_state.is_curator,
+                was_ever_validator: old_user_state.is_validator,
+                last_role_change: None,
+            },
+        );
+    }
+    old_state.user.clear();
+    let cached_counts = CachedCounts {
+        user_count,
+        curator_count: old_state.curator_list.len() as u64,
+        validator_count: old_state.validator_list.len() as u64,
+    };
+    State {
+        admin: Address::Account(old_state.admin),
+        project_contract_addr: old_state.project_contract_addr,
+        user,
+        curator_list: old_state.curator_list,
+        validator_list: old_state.validator_list,
+        disjoint_project_roles: false,
+        last_admin_change: None,
+        config_locked: false,
+        auto_prune_on_remove: false,
+        default_user_state: UserState::default(),
+        reject_privileged_targets: false,
+        max_projects_per_user: None,
+        curators_frozen: false,
+        require_known_users: false,
+        cached_counts,
+        users_view_public: true,
+        events_enabled: true,
+        allowed_projects: state_builder.new_set(),
+        state_seq: 0,
+        curate_auto_create: false,
+        role_cooldown: Duration::from_millis(0),
+        pending_curators: state_builder.new_set(),
+        pending_validators: state_builder.new_set(),
+        pending_curator_count: 0,
+        pending_validator_count: 0,
+        recovery_account: None,
+        admin_inactivity_timeout: Duration::from_millis(0),
+        last_admin_activity: None,
+        observers: state_builder.new_set(),
+        curator_weight: 1,
+        validator_weight: 1,
+        last_snapshot: state_builder.new_map(),
+        last_snapshot_time: None,
+        admin_action_counts: state_builder.new_map(),
+        strict_removals: false,
+        event_seq: 0,
+        curation_scores: state_builder.new_map(),
+    }
+}
+
+/// Remove duplicated addresses from the list keeping the first occurrence of each.
+/// Returns the number of removed entries.
+fn dedup_list(list: &mut Vec<AccountAddress>) -> u32 {
+    let original_len = list.len();
+    let mut seen = BTreeSet::new();
+    list.retain(|addr| seen.insert(*addr));
+    (original_len - list.len()) as u32
+}
+
+/// Make the presence of the address in the list match `listed`.
+/// Returns true if the list has been changed.
+fn resync_list_entry(list: &mut Vec<AccountAddress>, addr: &AccountAddress, listed: bool) -> bool {
+    if listed == list.contains(addr) {
+        return false;
+    }
+    if listed {
+        list.push(*addr);
+    } else {
+        list.retain(|x| x != addr);
+    }
+    true
+}
+
+impl UserState {
+    /// Check whether the project id is in the curated projects.
+    fn has_curated(&self, project_id: &ProjectId) -> bool {
+        self.curated_projects.contains(project_id)
+    }
+
+    /// Check whether the project id is in the validated projects regardless of its source.
+    fn has_validated(&self, project_id: &ProjectId) -> bool {
+        self.validated_projects
+            .iter()
+            .any(|(validated, _)| validated == project_id)
+    }
+
+    /// Create the user data of a new user from the template.
+    /// The user and the roles of the template are recorded as created and granted at `now`.
+    fn from_template(template: &UserState, now: Timestamp) -> Self {
+        UserState {
+            curator_since: template.is_curator.then_some(now),
+            validator_since: template.is_validator.then_some(now),
+            created_at: Some(now),
+            was_ever_curator: template.is_curator,
+            was_ever_validator: template.is_validator,
+            last_role_change: None,
+            ..template.clone()
+        }
+    }
+
+    /// Set the curator role. The grant time is recorded and the user is marked as having been
+    /// a curator only when the role is newly granted.
+    fn set_curator(&mut self, is_curator: bool, now: Timestamp) {
+        if !is_curator {
+            self.curator_since = None;
+        } else if !self.is_curator {
+            self.curator_since = Some(now);
+            self.was_ever_curator = true;
+        }
+        self.is_curator = is_curator;
+    }
+
+    /// Set the validator role. The grant time is recorded and the user is marked as having been
+    /// a validator only when the role is newly granted.
+    fn set_validator(&mut self, is_validator: bool, now: Timestamp) {
+        if !is_validator {
+            self.validator_since = None;
+        } else if !self.is_validator {
+            self.validator_since = Some(now);
+            self.was_ever_validator = true;
+        }
+        self.is_validator = is_validator;
+    }
+
+    /// Check whether the user holds the role required by the filter.
+    fn matches_role(&self, filter: &RoleFilter) -> bool {
+        match filter {
+            RoleFilter::Curator => self.is_curator,
+            RoleFilter::Validator => self.is_validator,
+            RoleFilter::Any => self.is_curator || self.is_validator,
+        }
+    }
+
+    /// The latest time when one of the roles currently held was granted.
+    fn latest_grant_time(&self) -> Option<Timestamp> {
+        self.curator_since.max(self.validator_since)
+    }
+
+    /// Check whether one more project can be added without exceeding the project limit of this user.
+    /// The per-user limit takes precedence over the inputted global limit.
+    fn can_add_project(&self, max_projects_per_user: Option<u32>) -> bool {
+        match self.max_projects.or(max_projects_per_user) {
+            Some(max_projects) => {
+                self.curated_projects.len() + self.validated_projects.len() < max_projects as usize
+            },
+            None => true,
+        }
+    }
+}
+
+/// implements Debug for State inside test functions.
+/// this implementation will be build only when `concordium-std/wasm-test` feature is active.
+/// (e.g. when launched by `cargo concordium test`)
+#[concordium_cfg_test]
+impl<S: HasStateApi> Debug for State<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "admin: {:?}, project_contract_addr: {:?}, ",
+            self.admin, self.project_contract_addr
+        )?;
+        for (address, state) in self.user.iter() {
+            write!(f, "user_address: {:?}, user_state: {:?}, ", address, state)?;
+        }
+        write!(
+            f,
+            "curator_list: {:?}, validator_list: {:?}, ",
+            self.curator_list, self.validator_list
+        )?;
+        write!(
+            f,
+            "disjoint_project_roles: {:?}, last_admin_change: {:?}, ",
+            self.disjoint_project_roles, self.last_admin_change
+        )?;
+        write!(
+            f,
+            "config_locked: {:?}, auto_prune_on_remove: {:?}, ",
+            self.config_locked, self.auto_prune_on_remove
+        )?;
+        write!(f, "default_user_state: {:?}, ", self.default_user_state)?;
+        write!(
+            f,
+            "reject_privileged_targets: {:?}, ",
+            self.reject_privileged_targets
+        )?;
+        write!(
+            f,
+            "max_projects_per_user: {:?}, ",
+            self.max_projects_per_user
+        )?;
+        write!(f, "curators_frozen: {:?}, ", self.curators_frozen)?;
+        write!(f, "require_known_users: {:?}, ", self.require_known_users)?;
+        write!(f, "cached_counts: {:?}, ", self.cached_counts)?;
+        write!(f, "users_view_public: {:?}, ", self.users_view_public)?;
+        write!(f, "events_enabled: {:?}, ", self.events_enabled)?;
+        write!(f, "allowed_projects: [")?;
+        for project_id in self.allowed_projects.iter() {
+            write!(f, "{:?}, ", *project_id)?;
+        }
+        write!(f, "], ")?;
+        write!(f, "state_seq: {:?}, ", self.state_seq)?;
+        write!(f, "curate_auto_create: {:?}, ", self.curate_auto_create)?;
+        write!(f, "role_cooldown: {:?}, ", self.role_cooldown)?;
+        write!(f, "pending_curators: [")?;
+        for addr in self.pending_curators.iter() {
+            write!(f, "{:?}, ", *addr)?;
+        }
+        write!(f, "], ")?;
+        write!(f, "pending_validators: [")?;
+        for addr in self.pending_validators.iter() {
+            write!(f, "{:?}, ", *addr)?;
+        }
+        write!(f, "], ")?;
+        write!(f, "recovery_account: {:?}, ", self.recovery_account)?;
+        write!(
+            f,
+            "admin_inactivity_timeout: {:?}, ",
+            self.admin_inactivity_timeout
+        )?;
+        write!(f, "last_admin_activity: {:?}, ", self.last_admin_activity)?;
+        write!(f, "observers: [")?;
+        for addr in self.observers.iter() {
+            write!(f, "{:?}, ", *addr)?;
+        }
+        write!(f, "], ")?;
+        write!(f, "curator_weight: {:?}, ", self.curator_weight)?;
+        write!(f, "validator_weight: {:?}, ", self.validator_weight)?;
+        write!(f, "last_snapshot: [")?;
+        for (addr, user_state) in self.last_snapshot.iter() {
+            write!(f, "({:?}, {:?}), ", *addr, *user_state)?;
+        }
+        write!(f, "], ")?;
+        write!(f, "admin_action_counts: [")?;
+        for (action, count) in self.admin_action_counts.iter() {
+            write!(f, "({:?}, {:?}), ", *action, *count)?;
+        }
+        write!(f, "], ")?;
+        write!(f, "strict_removals: {:?}, ", self.strict_removals)?;
+        write!(f, "event_seq: {:?}, ", self.event_seq)?;
+        write!(
+            f,
+            "pending_curator_count: {:?}, ",
+            self.pending_curator_count
+        )?;
+        write!(
+            f,
+            "pending_validator_count: {:?}, ",
+            self.pending_validator_count
+        )?;
+        write!(f, "last_snapshot_time: {:?}, ", self.last_snapshot_time)?;
+        write!(f, "curation_scores: [")?;
+        for (addr, scores) in self.curation_scores.iter() {
+            write!(f, "({:?}, {:?}), ", *addr, *scores)?;
+        }
+        write!(f, "]")
+    }
+}
+
+/// implements PartialEq for `claim_eq` inside test functions.
+/// this implementation will be build only when `concordium-std/wasm-test` feature is active.
+/// (e.g. when launched by `cargo concordium test`)
+#[concordium_cfg_test]
+impl<S: HasStateApi> PartialEq for State<S> {
+    fn eq(&self, other: &Self) -> bool {
+        if self.admin != other.admin {
+            return false;
+        }
+        if self.project_contract_addr != other.project_contract_addr {
+            return false;
+        }
+        if self.user.iter().count() != other.user.iter().count() {
+            return false;
+        }
+        for (my_user_address, my_user_state) in self.user.iter() {
+            let other_user_state = other.user.get(&my_user_address);
+            if other_user_state.is_none() {
+                return false;
+            }
+            let other_user_state = other_user_state.unwrap();
+            if my_user_state.clone() != other_user_state.clone() {
+                return false;
+            }
+        }
+        if self.curator_list != other.curator_list {
+            return false;
+        }
+        if self.validator_list != other.validator_list {
+            return false;
+        }
+        if self.disjoint_project_roles != other.disjoint_project_roles {
+            return false;
+        }
+        if self.last_admin_change != other.last_admin_change {
+            return false;
+        }
+        if self.config_locked != other.config_locked {
+            return false;
+        }
+        if self.auto_prune_on_remove != other.auto_prune_on_remove {
+            return false;
+        }
+        if self.default_user_state != other.default_user_state {
+            return false;
+        }
+        if self.reject_privileged_targets != other.reject_privileged_targets {
+            return false;
+        }
+        if self.max_projects_per_user != other.max_projects_per_user {
+            return false;
+        }
+        if self.curators_frozen != other.curators_frozen {
+            return false;
+        }
+        if self.require_known_users != other.require_known_users {
+            return false;
+        }
+        if self.cached_counts != other.cached_counts {
+            return false;
+        }
+        if self.users_view_public != other.users_view_public {
+            return false;
+        }
+        if self.events_enabled != other.events_enabled {
+            return false;
+        }
+        if self.allowed_projects.iter().count() != other.allowed_projects.iter().count() {
+            return false;
+        }
+        for project_id in self.allowed_projects.iter() {
+            if !other.allowed_projects.contains(&project_id) {
+                return false;
+            }
+        }
+        if self.state_seq != other.state_seq {
+            return false;
+        }
+        if self.curate_auto_create != other.curate_auto_create {
+            return false;
+        }
+        if self.role_cooldown != other.role_cooldown {
+            return false;
+        }
+        if self.pending_curators.iter().count() != other.pending_curators.iter().count() {
+            return false;
+        }
+        for addr in self.pending_curators.iter() {
+            if !other.pending_curators.contains(&addr) {
+                return false;
+            }
+        }
+        if self.pending_validators.iter().count() != other.pending_validators.iter().count() {
+            return false;
+        }
+        for addr in self.pending_validators.iter() {
+            if !other.pending_validators.contains(&addr) {
+                return false;
+            }
+        }
+        if self.recovery_account != other.recovery_account {
+            return false;
+        }
+        if self.admin_inactivity_timeout != other.admin_inactivity_timeout {
+            return false;
+        }
+        if self.last_admin_activity != other.last_admin_activity {
+            return false;
+        }
+        if self.observers.iter().count() != other.observers.iter().count() {
+            return false;
+        }
+        for addr in self.observers.iter() {
+            if !other.observers.contains(&addr) {
+                return false;
+            }
+        }
+        if self.curator_weight != other.curator_weight {
+            return false;
+        }
+        if self.validator_weight != other.validator_weight {
+            return false;
+        }
+        if self.last_snapshot.iter().count() != other.last_snapshot.iter().count() {
+            return false;
+        }
+        for (addr, user_state) in self.last_snapshot.iter() {
+            if other
+                .last_snapshot
+                .get(&addr)
+                .map(|other_user_state| other_user_state.clone())
+                != Some(user_state.clone())
+            {
+                return false;
+            }
+        }
+        if self.admin_action_counts.iter().count() != other.admin_action_counts.iter().count() {
+            return false;
+        }
+        for (action, count) in self.admin_action_counts.iter() {
+            if other
+                .admin_action_counts
+                .get(&action)
+                .map(|other_count| *other_count)
+                != Some(*count)
+            {
+                return false;
+            }
+        }
+        if self.strict_removals != other.strict_removals {
+            return false;
+        }
+        if self.event_seq != other.event_seq {
+            return false;
+        }
+        if self.pending_curator_count != other.pending_curator_count {
+            return false;
+        }
+        if self.pending_validator_count != other.pending_validator_count {
+            return false;
+        }
+        if self.last_snapshot_time != other.last_snapshot_time {
+            return false;
+        }
+        if self.curation_scores.iter().count() != other.curation_scores.iter().count() {
+            return false;
+        }
+        for (addr, scores) in self.curation_scores.iter() {
+            if other
+                .curation_scores
+                .get(&addr)
+                .map(|other_scores| other_scores.clone())
+                != Some(scores.clone())
+            {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn ne(&self, other: &Self) -> bool {
+        !self.eq(other)
+    }
+}
+
+/// implements Debug for UserState inside test functions.
+/// this implementation will be build only when `concordium-std/wasm-test` feature is active.
+/// (e.g. when launched by `cargo concordium test`)
+#[concordium_cfg_test]
+impl Debug for UserState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "is_curator: {}, is_validator: {}, curated_projects: {:?}, validated_projects: {:?}, ",
+            self.is_curator, self.is_validator, self.curated_projects, self.validated_projects
+        )?;
+        write!(f, "tags: {:?}, ", self.tags)?;
+        write!(f, "max_projects: {:?}, ", self.max_projects)?;
+        write!(f, "curator_since: {:?}, ", self.curator_since)?;
+        write!(f, "validator_since: {:?}, ", self.validator_since)?;
+        write!(f, "deleted: {:?}, ", self.deleted)?;
+        write!(f, "created_at: {:?}, ", self.created_at)?;
+        write!(f, "was_ever_curator: {:?}, ", self.was_ever_curator)?;
+        write!(f, "was_ever_validator: {:?}, ", self.was_ever_validator)?;
+        write!(f, "last_role_change: {:?}", self.last_role_change)
+    }
+}
+
+/// implements PartialEq for `claim_eq` inside test functions.
+/// this implementation will be build only when `concordium-std/wasm-test` feature is active.
+/// (e.g. when launched by `cargo concordium test`)
+#[concordium_cfg_test]
+impl PartialEq for UserState {
+    fn eq(&self, other: &Self) -> bool {
+        if self.is_curator != other.is_curator {
+            return false;
+        }
+        if self.is_validator != other.is_validator {
+            return false;
+        }
+        if self.curated_projects != other.curated_projects {
+            return false;
+        }
+        if self.validated_projects != other.validated_projects {
+            return false;
+        }
+        if self.tags != other.tags {
+            return false;
+        }
+        if self.max_projects != other.max_projects {
+            return false;
+        }
+        if self.curator_since != other.curator_since {
+            return false;
+        }
+        if self.validator_since != other.validator_since {
+            return false;
+        }
+        if self.deleted != other.deleted {
+            return false;
+        }
+        if self.created_at != other.created_at {
+            return false;
+        }
+        if self.was_ever_curator != other.was_ever_curator {
+            return false;
+        }
+        if self.was_ever_validator != other.was_ever_validator {
+            return false;
+        }
+        if self.last_role_change != other.last_role_change {
+            return false;
+        }
+        true
+    }
+
+    fn ne(&self, other: &Self) -> bool {
+        !self.eq(other)
+    }
+}
+
+#[concordium_cfg_test]
+mod tests {
+    use super::*;
+    use concordium_std::hashes::HashBytes;
+    use test_infrastructure::*;
+
+    /// Build a state with the same values as `contract_init` for the tests to override with `..`.
+    fn default_test_state(state_builder: &mut TestStateBuilder) -> State<TestStateApi> {
+        State {
+            admin: Address::Account(AccountAddress([0; 32])),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            disjoint_project_roles: false,
+            last_admin_change: None,
+            config_locked: false,
+            auto_prune_on_remove: false,
+            default_user_state: UserState::default(),
+            reject_privileged_targets: false,
+            max_projects_per_user: None,
+            curators_frozen: false,
+            require_known_users: false,
+            cached_counts: CachedCounts::default(),
+            users_view_public: true,
+            events_enabled: true,
+            allowed_projects: state_builder.new_set(),
+            state_seq: 0,
+            curate_auto_create: false,
+            role_cooldown: Duration::from_millis(0),
+            pending_curators: state_builder.new_set(),
+            pending_validators: state_builder.new_set(),
+            pending_curator_count: 0,
+            pending_validator_count: 0,
+            recovery_account: None,
+            admin_inactivity_timeout: Duration::from_millis(0),
+            last_admin_activity: None,
+            observers: state_builder.new_set(),
+            curator_weight: 1,
+            validator_weight: 1,
+            last_snapshot: state_builder.new_map(),
+            last_snapshot_time: None,
+            admin_action_counts: state_builder.new_map(),
+            strict_removals: false,
+            event_seq: 0,
+            curation_scores: state_builder.new_map(),
+        }
+    }
+
+    #[concordium_test]
+    /// Test that init succeeds.
+    fn test_init() {
+        // invoker will be an admin
+        let invoker = AccountAddress([0; 32]);
+        let mut ctx = TestInitContext::empty();
+        ctx.set_init_origin(invoker);
+
+        let mut state_builder = TestStateBuilder::new();
+
+        let expected_state = State {
+            admin: Address::Account(invoker),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+
+        // execute init
+        let result = contract_init(&ctx, &mut state_builder);
+
+        // check init result
+        claim!(result.is_ok());
+        let actual_state = result.unwrap();
+        claim_eq!(
+            actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.transfer_admin was successfully invoked by admin account.
+    fn test_contract_transfer_admin_invoked_by_admin() {
+        let admin = AccountAddress([0; 32]);
+        let try_to_transfer_to = AccountAddress([2; 32]);
+        let slot_time = Timestamp::from_timestamp_millis(1_000);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        ctx.set_metadata_slot_time(slot_time);
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut admin_action_counts = state_builder.new_map();
+        admin_action_counts.insert(String::from("transfer_admin"), 1);
+        let expected_state = State {
+            admin: Address::Account(try_to_transfer_to),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            last_admin_change: Some((slot_time, Address::Account(admin))),
+            state_seq: 1,
+            admin_action_counts,
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = TransferAdminParams {
+            admin: Address::Account(try_to_transfer_to),
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_transfer_admin(&ctx, &mut host);
+        claim!(result.is_ok());
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.transfer_admin was invoked by non-admin account.
+    fn test_contract_transfer_admin_invoked_by_non_admin() {
+        let admin = AccountAddress([0; 32]);
+        let suspicious = AccountAddress([1; 32]);
+        let try_to_transfer_to = AccountAddress([2; 32]);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(suspicious);
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = TransferAdminParams {
+            admin: Address::Account(try_to_transfer_to),
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_transfer_admin(&ctx, &mut host);
+        claim!(result.is_err());
+        claim_eq!(result.err(), Some(Error::InvalidCaller));
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.add_project_contract was successfully invoked by admin account.
+    fn test_contract_add_project_contract_invoked_by_admin() {
+        let admin = AccountAddress([0; 32]);
+        let project_contract_addr_to_be_set = ContractAddress::new(1, 2);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut admin_action_counts = state_builder.new_map();
+        admin_action_counts.insert(String::from("add_project_contract"), 1);
+        let expected_state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: project_contract_addr_to_be_set,
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            state_seq: 1,
+            admin_action_counts,
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = AddProjectContractParams {
+            project_contract_addr: project_contract_addr_to_be_set,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_add_project_contract(&ctx, &mut host);
+        claim!(result.is_ok());
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.add_project_contract was invoked by non-admin account.
+    fn test_contract_add_project_contract_invoked_by_non_admin() {
+        let admin = AccountAddress([0; 32]);
+        let suspicious = AccountAddress([1; 32]);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(suspicious);
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let project_contract_addr = ContractAddress::new(1, 2);
+        let params = AddProjectContractParams {
+            project_contract_addr,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_add_project_contract(&ctx, &mut host);
+        claim!(result.is_err());
+        claim_eq!(result.err(), Some(Error::InvalidCaller));
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.set_disjoint_project_roles was successfully invoked by admin account.
+    fn test_contract_set_disjoint_project_roles_invoked_by_admin() {
+        let admin = AccountAddress([0; 32]);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut admin_action_counts = state_builder.new_map();
+        admin_action_counts.insert(String::from("set_disjoint_project_roles"), 1);
+        let expected_state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            disjoint_project_roles: true,
+            state_seq: 1,
+            admin_action_counts,
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = SetDisjointProjectRolesParams {
+            disjoint_project_roles: true,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_set_disjoint_project_roles(&ctx, &mut host);
+        claim!(result.is_ok());
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.set_disjoint_project_roles was invoked by non-admin account.
+    fn test_contract_set_disjoint_project_roles_invoked_by_non_admin() {
+        let admin = AccountAddress([0; 32]);
+        let suspicious = AccountAddress([1; 32]);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(suspicious);
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = SetDisjointProjectRolesParams {
+            disjoint_project_roles: true,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_set_disjoint_project_roles(&ctx, &mut host);
+        claim!(result.is_err());
+        claim_eq!(result.err(), Some(Error::InvalidCaller));
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.add_curator handle new user entry.
+    fn test_contract_add_new_curator_invoked_by_admin() {
+        let admin = AccountAddress([0; 32]);
+        let project_contract_addr = ContractAddress::new(0, 0);
+        let existing_user = AccountAddress([1; 32]);
+        let curator = AccountAddress([2; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(10));
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(
+            existing_user,
+            UserState {
+                is_curator: false,
+                is_validator: false,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+                ..UserState::default()
+            },
+        );
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr,
+            user,
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut expected_user = state_builder.new_map();
+        expected_user.insert(
+            existing_user,
+            UserState {
+                is_curator: false,
+                is_validator: false,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+                ..UserState::default()
+            },
+        );
+        expected_user.insert(
+            curator,
+            UserState {
+                is_curator: true,
+                is_validator: false,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+                curator_since: Some(Timestamp::from_timestamp_millis(10)),
+                created_at: Some(Timestamp::from_timestamp_millis(10)),
+                was_ever_curator: true,
+                last_role_change: Some(Timestamp::from_timestamp_millis(10)),
+                ..UserState::default()
+            },
+        );
+        let mut admin_action_counts = state_builder.new_map();
+        admin_action_counts.insert(String::from("add_curator"), 1);
+        let expected_state = State {
+            admin: Address::Account(admin),
+            project_contract_addr,
+            user: expected_user,
+            curator_list: vec![curator],
+            validator_list: Vec::new(),
+            cached_counts: CachedCounts {
+                user_count: 1,
+                curator_count: 1,
+                ..CachedCounts::default()
+            },
+            state_seq: 1,
+            admin_action_counts,
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = AddCuratorParams { addr: curator };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_add_curator(&ctx, &mut host);
+        claim!(result.is_ok());
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.add_curator handle existing user entry.
+    fn test_contract_modify_curator_invoked_by_admin() {
+        let admin = AccountAddress([0; 32]);
+        let project_contract_addr = ContractAddress::new(0, 0);
+        let existing_user = AccountAddress([1; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(10));
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(
+            existing_user,
+            UserState {
+                is_curator: false,
+                is_validator: false,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+                ..UserState::default()
+            },
+        );
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr,
+            user,
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut expected_user = state_builder.new_map();
+        expected_user.insert(
+            existing_user,
+            UserState {
+                is_curator: true,
+                is_validator: false,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+                curator_since: Some(Timestamp::from_timestamp_millis(10)),
+                was_ever_curator: true,
+                last_role_change: Some(Timestamp::from_timestamp_millis(10)),
+                ..UserState::default()
+            },
+        );
+        let mut admin_action_counts = state_builder.new_map();
+        admin_action_counts.insert(String::from("add_curator"), 1);
+        let expected_state = State {
+            admin: Address::Account(admin),
+            project_contract_addr,
+            user: expected_user,
+            curator_list: vec![existing_user],
+            validator_list: Vec::new(),
+            cached_counts: CachedCounts {
+                curator_count: 1,
+                ..CachedCounts::default()
+            },
+            state_seq: 1,
+            admin_action_counts,
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = AddCuratorParams {
+            addr: existing_user,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_add_curator(&ctx, &mut host);
+        claim!(result.is_ok());
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.contract_add_curator was invoked by non-admin account.
+    fn test_contract_add_curator_invoked_by_non_admin() {
+        let admin = AccountAddress([0; 32]);
+        let suspicious = AccountAddress([1; 32]);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(suspicious);
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = AddCuratorParams {
+            addr: AccountAddress([2; 32]),
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_add_curator(&ctx, &mut host);
+        claim!(result.is_err());
+        claim_eq!(result.err(), Some(Error::InvalidCaller));
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.contract_remove_curator successfully remove the input
+    fn test_contract_remove_curator_invoked_by_admin() {
+        let admin = AccountAddress([0; 32]);
+        let project_contract_addr = ContractAddress::new(0, 0);
+        let existing_user = AccountAddress([1; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(10));
+        ctx.set_invoker(admin);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(
+            existing_user,
+            UserState {
+                is_curator: true,
+                is_validator: false,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+                ..UserState::default()
+            },
+        );
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr,
+            user,
+            curator_list: vec![existing_user],
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut expected_user = state_builder.new_map();
+        expected_user.insert(
+            existing_user,
+            UserState {
+                is_curator: false,
+                is_validator: false,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+                last_role_change: Some(Timestamp::from_timestamp_millis(10)),
+                ..UserState::default()
+            },
+        );
+        let mut admin_action_counts = state_builder.new_map();
+        admin_action_counts.insert(String::from("remove_curator"), 1);
+        let expected_state = State {
+            admin: Address::Account(admin),
+            project_contract_addr,
+            user: expected_user,
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            state_seq: 1,
+            admin_action_counts,
+            event_seq: 1,
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = RemoveCuratorParams {
+            addr: existing_user,
+            reason: None,
+            force: false,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let mut logger = TestLogger::init();
+        let result = contract_remove_curator(&ctx, &mut host, &mut logger);
+        claim!(result.is_ok());
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.contract_remove_curator succeeds even if the parameter user is not
+    /// curator
+    fn test_contract_remove_curator_with_no_effect_invoked_by_admin() {
+        let admin = AccountAddress([0; 32]);
+        let project_contract_addr = ContractAddress::new(0, 0);
+        let existing_user = AccountAddress([1; 32]);
+        let not_curator = AccountAddress([2; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(10));
+        ctx.set_invoker(admin);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(
+            existing_user,
+            UserState {
+                is_curator: true,
+                is_validator: false,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+                ..UserState::default()
+            },
+        );
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr,
+            user,
+            curator_list: vec![existing_user],
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut expected_user = state_builder.new_map();
+        expected_user.insert(
+            existing_user,
+            UserState {
+                is_curator: true,
+                is_validator: false,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+                ..UserState::default()
+            },
+        );
+        let mut admin_action_counts = state_builder.new_map();
+        admin_action_counts.insert(String::from("remove_curator"), 1);
+        let expected_state = State {
+            admin: Address::Account(admin),
+            project_contract_addr,
+            user: expected_user,
+            curator_list: vec![existing_user],
+            validator_list: Vec::new(),
+            cached_counts: CachedCounts {
+                curator_count: 1,
+                ..CachedCounts::default()
+            },
+            admin_action_counts,
+            event_seq: 1,
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = RemoveCuratorParams {
+            addr: not_curator,
+            reason: None,
+            force: false,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let mut logger = TestLogger::init();
+        let result = contract_remove_curator(&ctx, &mut host, &mut logger);
+        claim!(result.is_ok());
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.contract_remove_curator was invoked by non-admin account.
+    fn test_contract_remove_curator_invoked_by_non_admin() {
+        let admin = AccountAddress([0; 32]);
+        let suspicious = AccountAddress([1; 32]);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(suspicious);
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = RemoveCuratorParams {
+            addr: AccountAddress([2; 32]),
+            reason: None,
+            force: false,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let mut logger = TestLogger::init();
+        let result = contract_remove_curator(&ctx, &mut host, &mut logger);
+        claim!(result.is_err());
+        claim_eq!(result.err(), Some(Error::InvalidCaller));
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.add_validator handle new user entry.
+    fn test_contract_add_new_validator_invoked_by_admin() {
+        let admin = AccountAddress([0; 32]);
+        let project_contract_addr = ContractAddress::new(0, 0);
+        let existing_user = AccountAddress([1; 32]);
+        let validator = AccountAddress([2; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(10));
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(
+            existing_user,
+            UserState {
+                is_curator: false,
+                is_validator: false,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+                ..UserState::default()
+            },
+        );
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr,
+            user,
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut expected_user = state_builder.new_map();
+        expected_user.insert(
+            existing_user,
+            UserState {
+                is_curator: false,
+                is_validator: false,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+                ..UserState::default()
+            },
+        );
+        expected_user.insert(
+            validator,
+            UserState {
+                is_curator: false,
+                is_validator: true,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+                validator_since: Some(Timestamp::from_timestamp_millis(10)),
+                created_at: Some(Timestamp::from_timestamp_millis(10)),
+                was_ever_validator: true,
+                last_role_change: Some(Timestamp::from_timestamp_millis(10)),
+                ..UserState::default()
+            },
+        );
+        let mut admin_action_counts = state_builder.new_map();
+        admin_action_counts.insert(String::from("add_validator"), 1);
+        let expected_state = State {
+            admin: Address::Account(admin),
+            project_contract_addr,
+            user: expected_user,
+            curator_list: Vec::new(),
+            validator_list: vec![validator],
+            cached_counts: CachedCounts {
+                user_count: 1,
+                validator_count: 1,
+                ..CachedCounts::default()
+            },
+            state_seq: 1,
+            admin_action_counts,
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = AddValidatorParams { addr: validator };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_add_validator(&ctx, &mut host);
+        claim!(result.is_ok());
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.add_validator handle existing user entry.
+    fn test_contract_modify_validator_invoked_by_admin() {
+        let admin = AccountAddress([0; 32]);
+        let project_contract_addr = ContractAddress::new(0, 0);
+        let existing_user = AccountAddress([1; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(10));
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(
+            existing_user,
+            UserState {
+                is_curator: false,
+                is_validator: false,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+                ..UserState::default()
+            },
+        );
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr,
+            user,
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut expected_user = state_builder.new_map();
+        expected_user.insert(
+            existing_user,
+            UserState {
+                is_curator: false,
+                is_validator: true,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+                validator_since: Some(Timestamp::from_timestamp_millis(10)),
+                was_ever_validator: true,
+                last_role_change: Some(Timestamp::from_timestamp_millis(10)),
+                ..UserState::default()
+            },
+        );
+        let mut admin_action_counts = state_builder.new_map();
+        admin_action_counts.insert(String::from("add_validator"), 1);
+        let expected_state = State {
+            admin: Address::Account(admin),
+            project_contract_addr,
+            user: expected_user,
+            curator_list: Vec::new(),
+            validator_list: vec![existing_user],
+            cached_counts: CachedCounts {
+                validator_count: 1,
+                ..CachedCounts::default()
+            },
+            state_seq: 1,
+            admin_action_counts,
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = AddValidatorParams {
+            addr: existing_user,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_add_validator(&ctx, &mut host);
+        claim!(result.is_ok());
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.contract_add_validator was invoked by non-admin account.
+    fn test_contract_add_validator_invoked_by_non_admin() {
+        let admin = AccountAddress([0; 32]);
+        let suspicious = AccountAddress([1; 32]);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(suspicious);
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = AddValidatorParams {
+            addr: AccountAddress([2; 32]),
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_add_validator(&ctx, &mut host);
+        claim!(result.is_err());
+        claim_eq!(result.err(), Some(Error::InvalidCaller));
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.contract_remove_validator successfully remove the input
+    fn test_contract_remove_validator_invoked_by_admin() {
+        let admin = AccountAddress([0; 32]);
+        let project_contract_addr = ContractAddress::new(0, 0);
+        let existing_user = AccountAddress([1; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(10));
+        ctx.set_invoker(admin);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(
+            existing_user,
+            UserState {
+                is_curator: false,
+                is_validator: true,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+                ..UserState::default()
+            },
+        );
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr,
+            user,
+            curator_list: Vec::new(),
+            validator_list: vec![existing_user],
+            ..default_test_state(&mut state_builder)
+        };
+        let mut expected_user = state_builder.new_map();
+        expected_user.insert(
+            existing_user,
+            UserState {
+                is_curator: false,
+                is_validator: false,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+                last_role_change: Some(Timestamp::from_timestamp_millis(10)),
+                ..UserState::default()
+            },
+        );
+        let mut admin_action_counts = state_builder.new_map();
+        admin_action_counts.insert(String::from("remove_validator"), 1);
+        let expected_state = State {
+            admin: Address::Account(admin),
+            project_contract_addr,
+            user: expected_user,
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            state_seq: 1,
+            admin_action_counts,
+            event_seq: 1,
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = RemoveValidatorParams {
+            addr: existing_user,
+            reason: None,
+            force: false,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let mut logger = TestLogger::init();
+        let result = contract_remove_validator(&ctx, &mut host, &mut logger);
+        claim!(result.is_ok());
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.contract_remove_validator succeeds even if the parameter user is not
+    /// validator
+    fn test_contract_remove_validator_with_no_effect_invoked_by_admin() {
+        let admin = AccountAddress([0; 32]);
+        let project_contract_addr = ContractAddress::new(0, 0);
+        let existing_user = AccountAddress([1; 32]);
+        let not_validator = AccountAddress([2; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(10));
+        ctx.set_invoker(admin);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(
+            existing_user,
+            UserState {
+                is_curator: false,
+                is_validator: true,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+                ..UserState::default()
+            },
+        );
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr,
+            user,
+            curator_list: Vec::new(),
+            validator_list: vec![existing_user],
+            ..default_test_state(&mut state_builder)
+        };
+        let mut expected_user = state_builder.new_map();
+        expected_user.insert(
+            existing_user,
+            UserState {
+                is_curator: false,
+                is_validator: true,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+                ..UserState::default()
+            },
+        );
+        let mut admin_action_counts = state_builder.new_map();
+        admin_action_counts.insert(String::from("remove_validator"), 1);
+        let expected_state = State {
+            admin: Address::Account(admin),
+            project_contract_addr,
+            user: expected_user,
+            curator_list: Vec::new(),
+            validator_list: vec![existing_user],
+            cached_counts: CachedCounts {
+                validator_count: 1,
+                ..CachedCounts::default()
+            },
+            admin_action_counts,
+            event_seq: 1,
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = RemoveValidatorParams {
+            addr: not_validator,
+            reason: None,
+            force: false,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let mut logger = TestLogger::init();
+        let result = contract_remove_validator(&ctx, &mut host, &mut logger);
+        claim!(result.is_ok());
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.contract_remove_validator was invoked by non-admin account.
+    fn test_contract_remove_validator_invoked_by_non_admin() {
+        let admin = AccountAddress([0; 32]);
+        let suspicious = AccountAddress([1; 32]);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(suspicious);
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = RemoveValidatorParams {
+            addr: AccountAddress([2; 32]),
+            reason: None,
+            force: false,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let mut logger = TestLogger::init();
+        let result = contract_remove_validator(&ctx, &mut host, &mut logger);
+        claim!(result.is_err());
+        claim_eq!(result.err(), Some(Error::InvalidCaller));
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.curate successfully add project id to user entry.
+    fn test_contract_curate() {
+        let admin = AccountAddress([0; 32]);
+        let project_contract_addr = ContractAddress::new(0, 0);
+        let existing_user = AccountAddress([1; 32]);
+        let project_id: ProjectId = "TEST-PRJ".into();
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_sender(Address::Contract(project_contract_addr));
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(
+            existing_user,
+            UserState {
+                is_curator: true,
+                is_validator: false,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+                ..UserState::default()
+            },
+        );
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr,
+            user,
+            curator_list: vec![existing_user],
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut expected_user = state_builder.new_map();
+        expected_user.insert(
+            existing_user,
+            UserState {
+                is_curator: true,
+                is_validator: false,
+                curated_projects: vec![project_id.clone()],
+                validated_projects: Vec::new(),
+                ..UserState::default()
+            },
+        );
+        let expected_state = State {
+            admin: Address::Account(admin),
+            project_contract_addr,
+            user: expected_user,
+            curator_list: vec![existing_user],
+            validator_list: Vec::new(),
+            state_seq: 1,
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = CurateParams {
+            addr: existing_user,
+            project_id: project_id.clone(),
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_curate(&ctx, &mut host);
+        claim!(result.is_ok());
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.curate fails if the input user has not been added as a curator.
+    fn test_contract_curate_fails_with_no_user() {
+        let admin = AccountAddress([0; 32]);
+        let project_contract_addr = ContractAddress::new(0, 0);
+        let existing_user = AccountAddress([1; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_sender(Address::Contract(project_contract_addr));
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr,
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = CurateParams {
+            addr: existing_user,
+            project_id: "TEST-PRJ".into(),
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_curate(&ctx, &mut host);
+        claim!(result.is_err());
+        claim_eq!(result.err(), Some(Error::UserNotFound));
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.curate was invoked by non-project contract account.
+    fn test_contract_curate_invoked_by_non_project_contract_addr() {
+        let project_contract_addr = ContractAddress::new(0, 0);
+        let suspicious = ContractAddress::new(0, 1);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_sender(Address::Contract(suspicious));
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Account(AccountAddress([0; 32])),
+            project_contract_addr,
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = CurateParams {
+            addr: AccountAddress([2; 32]),
+            project_id: "TEST-PRJ".into(),
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_curate(&ctx, &mut host);
+        claim!(result.is_err());
+        claim_eq!(result.err(), Some(Error::InvalidCaller));
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.validate successfully add project id to user entry.
+    fn test_contract_validate() {
+        let admin = AccountAddress([0; 32]);
+        let project_contract_addr = ContractAddress::new(0, 0);
+        let existing_user = AccountAddress([1; 32]);
+        let project_id: ProjectId = "TEST-PRJ".into();
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_sender(Address::Contract(project_contract_addr));
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(
+            existing_user,
+            UserState {
+                is_curator: false,
+                is_validator: true,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+                ..UserState::default()
+            },
+        );
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr,
+            user,
+            curator_list: Vec::new(),
+            validator_list: vec![existing_user],
+            ..default_test_state(&mut state_builder)
+        };
+        let mut expected_user = state_builder.new_map();
+        expected_user.insert(
+            existing_user,
+            UserState {
+                is_curator: false,
+                is_validator: true,
+                curated_projects: Vec::new(),
+                validated_projects: vec![(project_id.clone(), project_contract_addr)],
+                ..UserState::default()
+            },
+        );
+        let expected_state = State {
+            admin: Address::Account(admin),
+            project_contract_addr,
+            user: expected_user,
+            curator_list: Vec::new(),
+            validator_list: vec![existing_user],
+            state_seq: 1,
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = ValidateParams {
+            addr: existing_user,
+            project_id: project_id.clone(),
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_validate(&ctx, &mut host);
+        claim!(result.is_ok());
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.validate fails if the input user has not been added as a validator.
+    fn test_contract_validate_fails_with_no_user() {
+        let admin = AccountAddress([0; 32]);
+        let project_contract_addr = ContractAddress::new(0, 0);
+        let existing_user = AccountAddress([1; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_sender(Address::Contract(project_contract_addr));
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr,
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = ValidateParams {
+            addr: existing_user,
+            project_id: "TEST-PRJ".into(),
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_validate(&ctx, &mut host);
+        claim!(result.is_err());
+        claim_eq!(result.err(), Some(Error::UserNotFound));
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.validate was invoked by non-project contract account.
+    fn test_contract_validate_invoked_by_non_project_contract_addr() {
+        let project_contract_addr = ContractAddress::new(0, 0);
+        let suspicious = ContractAddress::new(0, 1);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_sender(Address::Contract(suspicious));
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Account(AccountAddress([0; 32])),
+            project_contract_addr,
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = ValidateParams {
+            addr: AccountAddress([2; 32]),
+            project_id: "TEST-PRJ".into(),
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_validate(&ctx, &mut host);
+        claim!(result.is_err());
+        claim_eq!(result.err(), Some(Error::InvalidCaller));
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.curate accepts a project the user has validated when
+    /// `disjoint_project_roles` is not set.
+    fn test_contract_curate_validated_project_without_disjoint_project_roles() {
+        let admin = AccountAddress([0; 32]);
+        let project_contract_addr = ContractAddress::new(0, 0);
+        let existing_user = AccountAddress([1; 32]);
+        let project_id: ProjectId = "TEST-PRJ".into();
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_sender(Address::Contract(project_contract_addr));
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(
+            existing_user,
+            UserState {
+                is_curator: true,
+                is_validator: true,
+                curated_projects: Vec::new(),
+                validated_projects: vec![(project_id.clone(), project_contract_addr)],
+                ..UserState::default()
+            },
+        );
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr,
+            user,
+            curator_list: vec![existing_user],
+            validator_list: vec![existing_user],
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = CurateParams {
+            addr: existing_user,
+            project_id: project_id.clone(),
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_curate(&ctx, &mut host);
+        claim!(result.is_ok());
+        let user_state = host.state().user.get(&existing_user).unwrap();
+        claim_eq!(user_state.curated_projects, vec![project_id.clone()]);
+        claim_eq!(
+            user_state.validated_projects,
+            vec![(project_id, project_contract_addr)]
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.curate rejects a project the user has validated when
+    /// `disjoint_project_roles` is set.
+    fn test_contract_curate_validated_project_with_disjoint_project_roles() {
+        let admin = AccountAddress([0; 32]);
+        let project_contract_addr = ContractAddress::new(0, 0);
+        let existing_user = AccountAddress([1; 32]);
+        let project_id: ProjectId = "TEST-PRJ".into();
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_sender(Address::Contract(project_contract_addr));
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(
+            existing_user,
+            UserState {
+                is_curator: true,
+                is_validator: true,
+                curated_projects: Vec::new(),
+                validated_projects: vec![(project_id.clone(), project_contract_addr)],
+                ..UserState::default()
+            },
+        );
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr,
+            user,
+            curator_list: vec![existing_user],
+            validator_list: vec![existing_user],
+            disjoint_project_roles: true,
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = CurateParams {
+            addr: existing_user,
+            project_id,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_curate(&ctx, &mut host);
+        claim!(result.is_err());
+        claim_eq!(result.err(), Some(Error::ProjectRoleConflict));
+        let user_state = host.state().user.get(&existing_user).unwrap();
+        claim!(user_state.curated_projects.is_empty());
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.validate accepts a project the user has curated when
+    /// `disjoint_project_roles` is not set.
+    fn test_contract_validate_curated_project_without_disjoint_project_roles() {
+        let admin = AccountAddress([0; 32]);
+        let project_contract_addr = ContractAddress::new(0, 0);
+        let existing_user = AccountAddress([1; 32]);
+        let project_id: ProjectId = "TEST-PRJ".into();
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_sender(Address::Contract(project_contract_addr));
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(
+            existing_user,
+            UserState {
+                is_curator: true,
+                is_validator: true,
+                curated_projects: vec![project_id.clone()],
+                validated_projects: Vec::new(),
+                ..UserState::default()
+            },
+        );
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr,
+            user,
+            curator_list: vec![existing_user],
+            validator_list: vec![existing_user],
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = ValidateParams {
+            addr: existing_user,
+            project_id: project_id.clone(),
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_validate(&ctx, &mut host);
+        claim!(result.is_ok());
+        let user_state = host.state().user.get(&existing_user).unwrap();
+        claim_eq!(user_state.curated_projects, vec![project_id.clone()]);
+        claim_eq!(
+            user_state.validated_projects,
+            vec![(project_id, project_contract_addr)]
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.validate rejects a project the user has curated when
+    /// `disjoint_project_roles` is set.
+    fn test_contract_validate_curated_project_with_disjoint_project_roles() {
+        let admin = AccountAddress([0; 32]);
+        let project_contract_addr = ContractAddress::new(0, 0);
+        let existing_user = AccountAddress([1; 32]);
+        let project_id: ProjectId = "TEST-PRJ".into();
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_sender(Address::Contract(project_contract_addr));
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(
+            existing_user,
+            UserState {
+                is_curator: true,
+                is_validator: true,
+                curated_projects: vec![project_id.clone()],
+                validated_projects: Vec::new(),
+                ..UserState::default()
+            },
+        );
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr,
+            user,
+            curator_list: vec![existing_user],
+            validator_list: vec![existing_user],
+            disjoint_project_roles: true,
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = ValidateParams {
+            addr: existing_user,
+            project_id,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_validate(&ctx, &mut host);
+        claim!(result.is_err());
+        claim_eq!(result.err(), Some(Error::ProjectRoleConflict));
+        let user_state = host.state().user.get(&existing_user).unwrap();
+        claim!(user_state.validated_projects.is_empty());
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.upgrade can not be invoked by non-admin.
+    fn test_contract_upgrade_invoked_by_non_admin() {
+        let owner = AccountAddress([0; 32]);
+        let suspicious = AccountAddress([1; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_owner(owner);
+        ctx.set_sender(Address::Account(suspicious));
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Account(owner),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = UpgradeParams {
+            module: HashBytes::new([0; 32]),
+            migrate: None,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_upgrade(&ctx, &mut host);
+        claim!(result.is_err());
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.contract_view_admin returns administrative data.
+    fn test_contract_view_admin_invoked_by_admin() {
+        let admin = AccountAddress([0; 32]);
+        let project_contract_addr = ContractAddress::new(1, 2);
+        let curator = AccountAddress([1; 32]);
+        let validator = AccountAddress([2; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr,
+            user: state_builder.new_map(),
+            curator_list: vec![curator],
+            validator_list: vec![validator],
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // invoke method
+        let result = contract_view_admin(&ctx, &mut host);
+        claim!(result.is_ok());
+        let view = result.unwrap();
+        claim_eq!(view.admin, Address::Account(admin));
+        claim_eq!(view.project_contract_addr, project_contract_addr);
+        claim_eq!(view.curator_list, vec![curator]);
+        claim_eq!(view.validator_list, vec![validator]);
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.contract_view_admin should fail when invoked by non-admin
+    fn test_contract_view_admin_invoked_by_not_admin() {
+        let admin = AccountAddress([0; 32]);
+        let suspicious = AccountAddress([1; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(suspicious);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(1, 2),
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // invoke method
+        let result = contract_view_admin(&ctx, &mut host);
+        claim!(result.is_err());
+        claim_eq!(result.err(), Some(Error::InvalidCaller));
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.view_last_admin_change returns the recorded admin transfer.
+    fn test_contract_view_last_admin_change() {
+        let admin = AccountAddress([0; 32]);
+        let new_admin = AccountAddress([2; 32]);
+        let slot_time = Timestamp::from_timestamp_millis(1_000);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        ctx.set_metadata_slot_time(slot_time);
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // nothing has been recorded before the first transfer
+        let result = contract_view_last_admin_change(&ctx, &host);
+        claim_eq!(result, Ok(None));
+
+        // transfer admin
+        let params = TransferAdminParams {
+            admin: Address::Account(new_admin),
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = contract_transfer_admin(&ctx, &mut host);
+        claim!(result.is_ok());
+
+        // invoke method
+        let result = contract_view_last_admin_change(&ctx, &host);
+        claim_eq!(result, Ok(Some((slot_time, Address::Account(admin)))));
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.contract_view_user returns single user data.
+    fn test_contract_view_user_for_existing_user() {
+        let admin = AccountAddress([0; 32]);
+        let existing_user = AccountAddress([1; 32]);
+        let validated_project_id: ProjectId = "TEST-PRJ".into();
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(
+            existing_user,
+            UserState {
+                is_curator: false,
+                is_validator: true,
+                curated_projects: Vec::new(),
+                validated_projects: vec![(
+                    validated_project_id.clone(),
+                    ContractAddress::new(1, 2),
+                )],
+                ..UserState::default()
+            },
+        );
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(1, 2),
+            user,
+            curator_list: vec![],
+            validator_list: vec![existing_user],
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = AddrParams {
+            addr: existing_user,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_view_user(&ctx, &mut host);
+        claim!(result.is_ok());
+        let view = result.unwrap();
+        claim!(!view.is_curator);
+        claim!(view.is_validator);
+        claim!(view.curated_projects.is_empty());
+        claim_eq!(
+            view.validated_projects,
+            vec![(validated_project_id, ContractAddress::new(1, 2))]
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.contract_view_user returns default user data.
+    fn test_contract_view_user_for_non_existing_user() {
+        let admin = AccountAddress([0; 32]);
+        let anyone = AccountAddress([100; 32]);
+        let existing_user = AccountAddress([1; 32]);
+        let non_existing_user = AccountAddress([2; 32]);
+        let validated_project_id: ProjectId = "TEST-PRJ".into();
+        let mut ctx = TestReceiveContext::empty();
+        // anyone can call this contract function.
+        ctx.set_invoker(anyone);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(
+            existing_user,
+            UserState {
+                is_curator: false,
+                is_validator: true,
+                curated_projects: Vec::new(),
+                validated_projects: vec![(validated_project_id, ContractAddress::new(1, 2))],
+                ..UserState::default()
+            },
+        );
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(1, 2),
+            user,
+            curator_list: vec![],
+            validator_list: vec![existing_user],
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = AddrParams {
+            addr: non_existing_user,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_view_user(&ctx, &mut host);
+        claim!(result.is_ok());
+        let view = result.unwrap();
+        claim!(!view.is_curator);
+        claim!(!view.is_validator);
+        claim!(view.curated_projects.is_empty());
+        claim!(view.validated_projects.is_empty());
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.contract_view_users returns all user data.
+    fn test_contract_view_users() {
+        let admin = AccountAddress([0; 32]);
+        let anyone = AccountAddress([100; 32]);
+        let existing_user1 = (
+            AccountAddress([1; 32]),
+            UserState {
+                is_curator: false,
+                is_validator: true,
+                curated_projects: Vec::new(),
+                validated_projects: vec![("TEST-PRJ1".into(), ContractAddress::new(1, 2))],
+                ..UserState::default()
+            },
+        );
+        let existing_user2 = (
+            AccountAddress([2; 32]),
+            UserState {
+                is_curator: true,
+                is_validator: false,
+                curated_projects: vec!["TEST-PRJ2".into()],
+                validated_projects: Vec::new(),
+                ..UserState::default()
+            },
+        );
+        let mut ctx = TestReceiveContext::empty();
+        // anyone can call this contract function.
+        ctx.set_invoker(anyone);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(existing_user1.0, existing_user1.1.clone());
+        user.insert(existing_user2.0, existing_user2.1.clone());
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(1, 2),
+            user,
+            curator_list: vec![existing_user2.0],
+            validator_list: vec![existing_user1.0],
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // invoke method
+        let result = contract_view_users(&ctx, &mut host);
+        claim!(result.is_ok());
+        let view = result.unwrap();
+        claim_eq!(view.len(), 2);
+        for (addr, state) in view {
+            if addr == existing_user1.0 {
+                claim_eq!(state, existing_user1.1.clone());
+            } else if addr == existing_user2.0 {
+                claim_eq!(state, existing_user2.1.clone());
+            } else {
+                fail!("unexpected user address returned...");
+            }
+        }
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.contract_are_curators returns curator status in input order.
+    fn test_contract_are_curators() {
+        let admin = AccountAddress([0; 32]);
+        let anyone = AccountAddress([100; 32]);
+        let curator = AccountAddress([1; 32]);
+        let validator = AccountAddress([2; 32]);
+        let absent = AccountAddress([3; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        // anyone can call this contract function.
+        ctx.set_invoker(anyone);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(
+            curator,
+            UserState {
+                is_curator: true,
+                is_validator: false,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+                ..UserState::default()
+            },
+        );
+        user.insert(
+            validator,
+            UserState {
+                is_curator: false,
+                is_validator: true,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+                ..UserState::default()
+            },
+        );
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(1, 2),
+            user,
+            curator_list: vec![curator],
+            validator_list: vec![validator],
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params: AreCuratorsParams = vec![absent, curator, validator, curator];
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_are_curators(&ctx, &mut host);
+        claim!(result.is_ok());
+        claim_eq!(
+            result.unwrap(),
+            vec![
+                (absent, false),
+                (curator, true),
+                (validator, false),
+                (curator, true)
+            ]
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.dedup_lists removes duplicated list entries keeping the order.
+    fn test_contract_dedup_lists_invoked_by_admin() {
+        let admin = AccountAddress([0; 32]);
+        let user1 = AccountAddress([1; 32]);
+        let user2 = AccountAddress([2; 32]);
+        let user3 = AccountAddress([3; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: state_builder.new_map(),
+            curator_list: vec![user1, user2, user1, user3, user2, user1],
+            validator_list: vec![user3, user3],
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // invoke method
+        let result = contract_dedup_lists(&ctx, &mut host);
+        claim!(result.is_ok());
+        let response = result.unwrap();
+        claim_eq!(response.curator_duplicates, 3);
+        claim_eq!(response.validator_duplicates, 1);
+        let actual_state = host.state();
+        claim_eq!(actual_state.curator_list, vec![user1, user2, user3]);
+        claim_eq!(actual_state.validator_list, vec![user3]);
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.dedup_lists was invoked by non-admin account.
+    fn test_contract_dedup_lists_invoked_by_non_admin() {
+        let admin = AccountAddress([0; 32]);
+        let suspicious = AccountAddress([1; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(suspicious);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: state_builder.new_map(),
+            curator_list: vec![suspicious, suspicious],
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // invoke method
+        let result = contract_dedup_lists(&ctx, &mut host);
+        claim!(result.is_err());
+        claim_eq!(result.err(), Some(Error::InvalidCaller));
+        claim_eq!(host.state().curator_list, vec![suspicious, suspicious]);
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.contract_view_stats returns the aggregated statistics.
+    fn test_contract_view_stats() {
+        let admin = AccountAddress([0; 32]);
+        let anyone = AccountAddress([100; 32]);
+        let curator = AccountAddress([1; 32]);
+        let validator = AccountAddress([2; 32]);
+        let dual_role = AccountAddress([3; 32]);
+        let no_role = AccountAddress([4; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        // anyone can call this contract function.
+        ctx.set_invoker(anyone);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(
+            curator,
+            UserState {
+                is_curator: true,
+                is_validator: false,
+                curated_projects: vec!["TEST-PRJ1".into(), "TEST-PRJ2".into()],
+                validated_projects: Vec::new(),
+                ..UserState::default()
+            },
+        );
+        user.insert(
+            validator,
+            UserState {
+                is_curator: false,
+                is_validator: true,
+                curated_projects: Vec::new(),
+                validated_projects: vec![("TEST-PRJ1".into(), ContractAddress::new(1, 2))],
+                ..UserState::default()
+            },
+        );
+        user.insert(
+            dual_role,
+            UserState {
+                is_curator: true,
+                is_validator: true,
+                curated_projects: vec!["TEST-PRJ3".into()],
+                validated_projects: vec![
+                    ("TEST-PRJ2".into(), ContractAddress::new(1, 2)),
+                    ("TEST-PRJ3".into(), ContractAddress::new(1, 2)),
+                ],
+                ..UserState::default()
+            },
+        );
+        user.insert(
+            no_role,
+            UserState {
+                is_curator: false,
+                is_validator: false,
+                curated_projects: vec!["TEST-PRJ4".into()],
+                validated_projects: Vec::new(),
+                ..UserState::default()
+            },
+        );
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(1, 2),
+            user,
+            curator_list: vec![curator, dual_role],
+            validator_list: vec![validator, dual_role],
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // invoke method
+        let result = contract_view_stats(&ctx, &mut host);
+        claim!(result.is_ok());
+        let stats = result.unwrap();
+        claim_eq!(stats.user_count, 4);
+        claim_eq!(stats.curator_count, 2);
+        claim_eq!(stats.validator_count, 2);
+        claim_eq!(stats.dual_role_count, 1);
+        claim_eq!(stats.total_curations, 4);
+        claim_eq!(stats.total_validations, 3);
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.transfer_admin can transfer admin to a contract.
+    fn test_contract_transfer_admin_to_contract() {
+        let admin = AccountAddress([0; 32]);
+        let governance_contract = ContractAddress::new(10, 0);
+        let slot_time = Timestamp::from_timestamp_millis(1_000);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        ctx.set_metadata_slot_time(slot_time);
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut admin_action_counts = state_builder.new_map();
+        admin_action_counts.insert(String::from("transfer_admin"), 1);
+        let expected_state = State {
+            admin: Address::Contract(governance_contract),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            last_admin_change: Some((slot_time, Address::Account(admin))),
+            state_seq: 1,
+            admin_action_counts,
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = TransferAdminParams {
+            admin: Address::Contract(governance_contract),
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_transfer_admin(&ctx, &mut host);
+        claim!(result.is_ok());
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.transfer_admin was successfully invoked by contract admin.
+    fn test_contract_transfer_admin_invoked_by_contract_admin() {
+        let governance_contract = ContractAddress::new(10, 0);
+        let governance_member = AccountAddress([1; 32]);
+        let try_to_transfer_to = AccountAddress([2; 32]);
+        let slot_time = Timestamp::from_timestamp_millis(1_000);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(governance_member);
+        ctx.set_sender(Address::Contract(governance_contract));
+        ctx.set_metadata_slot_time(slot_time);
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Contract(governance_contract),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut admin_action_counts = state_builder.new_map();
+        admin_action_counts.insert(String::from("transfer_admin"), 1);
+        let expected_state = State {
+            admin: Address::Account(try_to_transfer_to),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            last_admin_change: Some((slot_time, Address::Contract(governance_contract))),
+            state_seq: 1,
+            admin_action_counts,
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = TransferAdminParams {
+            admin: Address::Account(try_to_transfer_to),
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_transfer_admin(&ctx, &mut host);
+        claim!(result.is_ok());
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.add_curator was successfully invoked by contract admin.
+    fn test_contract_add_curator_invoked_by_contract_admin() {
+        let governance_contract = ContractAddress::new(10, 0);
+        let governance_member = AccountAddress([1; 32]);
+        let curator = AccountAddress([2; 32]);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(governance_member);
+        ctx.set_sender(Address::Contract(governance_contract));
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(10));
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Contract(governance_contract),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut expected_user = state_builder.new_map();
+        expected_user.insert(
+            curator,
+            UserState {
+                is_curator: true,
+                is_validator: false,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+                curator_since: Some(Timestamp::from_timestamp_millis(10)),
+                created_at: Some(Timestamp::from_timestamp_millis(10)),
+                was_ever_curator: true,
+                last_role_change: Some(Timestamp::from_timestamp_millis(10)),
+                ..UserState::default()
+            },
+        );
+        let mut admin_action_counts = state_builder.new_map();
+        admin_action_counts.insert(String::from("add_curator"), 1);
+        let expected_state = State {
+            admin: Address::Contract(governance_contract),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: expected_user,
+            curator_list: vec![curator],
+            validator_list: Vec::new(),
+            cached_counts: CachedCounts {
+                user_count: 1,
+                curator_count: 1,
+                ..CachedCounts::default()
+            },
+            state_seq: 1,
+            admin_action_counts,
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = AddCuratorParams { addr: curator };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_add_curator(&ctx, &mut host);
+        claim!(result.is_ok());
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that an account can not act as the contract admin even if it invoked the transaction
+    /// through which the contract admin is called.
+    fn test_contract_add_curator_invoked_directly_by_contract_admin_member() {
+        let governance_contract = ContractAddress::new(10, 0);
+        let governance_member = AccountAddress([1; 32]);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(governance_member);
+        ctx.set_sender(Address::Account(governance_member));
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Contract(governance_contract),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = AddCuratorParams {
+            addr: AccountAddress([2; 32]),
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_add_curator(&ctx, &mut host);
+        claim!(result.is_err());
+        claim_eq!(result.err(), Some(Error::InvalidCaller));
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.view_admin was invoked by a contract other than the contract admin.
+    fn test_contract_view_admin_invoked_by_non_admin_contract() {
+        let governance_contract = ContractAddress::new(10, 0);
+        let suspicious = ContractAddress::new(11, 0);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(AccountAddress([1; 32]));
+        ctx.set_sender(Address::Contract(suspicious));
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Contract(governance_contract),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // invoke method
+        let result = contract_view_admin(&ctx, &mut host);
+        claim!(result.is_err());
+        claim_eq!(result.err(), Some(Error::InvalidCaller));
+    }
+
+    #[concordium_test]
+    /// Test that the `StateV0` layout is migrated keeping the account admin and the user data.
+    fn test_migrate_state_v0() {
+        let admin = AccountAddress([0; 32]);
+        let project_contract_addr = ContractAddress::new(1, 2);
+        let curator = AccountAddress([1; 32]);
+        let validator = AccountAddress([2; 32]);
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(
+            curator,
+            UserStateV0 {
+                is_curator: true,
+                is_validator: false,
+                curated_projects: vec!["TEST-PRJ1".into()],
+                validated_projects: Vec::new(),
+            },
+        );
+        user.insert(
+            validator,
+            UserStateV0 {
+                is_curator: false,
+                is_validator: true,
+                curated_projects: Vec::new(),
+                validated_projects: vec!["TEST-PRJ1".into(), "TEST-PRJ2".into()],
+            },
+        );
+        let old_state = StateV0 {
+            admin,
+            project_contract_addr,
+            user,
+            curator_list: vec![curator],
+            validator_list: vec![validator],
+        };
+        let mut expected_user = state_builder.new_map();
+        expected_user.insert(
+            curator,
+            UserState {
+                is_curator: true,
+                is_validator: false,
+                curated_projects: vec!["TEST-PRJ1".into()],
+                validated_projects: Vec::new(),
+                was_ever_curator: true,
+                ..UserState::default()
+            },
+        );
+        expected_user.insert(
+            validator,
+            UserState {
+                is_curator: false,
+                is_validator: true,
+                curated_projects: Vec::new(),
+                validated_projects: vec![
+                    ("TEST-PRJ1".into(), project_contract_addr),
+                    ("TEST-PRJ2".into(), project_contract_addr),
+                ],
+                was_ever_validator: true,
+                ..UserState::default()
+            },
+        );
+        let expected_state = State {
+            admin: Address::Account(admin),
+            project_contract_addr,
+            user: expected_user,
+            curator_list: vec![curator],
+            validator_list: vec![validator],
+            cached_counts: CachedCounts {
+                user_count: 2,
+                curator_count: 1,
+                validator_count: 1,
+            },
+            ..default_test_state(&mut state_builder)
+        };
+
+        // migrate
+        let actual_state = migrate_state_v0(old_state, &mut state_builder);
+        claim_eq!(
+            actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.contract_view_curators_flat returns curated project counts in the
+    /// order of curator list.
+    fn test_contract_view_curators_flat() {
+        let admin = AccountAddress([0; 32]);
+        let anyone = AccountAddress([100; 32]);
+        let curator1 = AccountAddress([1; 32]);
+        let curator2 = AccountAddress([2; 32]);
+        let missing_curator = AccountAddress([3; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        // anyone can call this contract function.
+        ctx.set_invoker(anyone);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(
+            curator1,
+            UserState {
+                is_curator: true,
+                is_validator: false,
+                curated_projects: vec!["TEST-PRJ1".into()],
+                validated_projects: Vec::new(),
+                ..UserState::default()
+            },
+        );
+        user.insert(
+            curator2,
+            UserState {
+                is_curator: true,
+                is_validator: true,
+                curated_projects: vec!["TEST-PRJ1".into(), "TEST-PRJ2".into()],
+                validated_projects: vec![("TEST-PRJ3".into(), ContractAddress::new(1, 2))],
+                ..UserState::default()
+            },
+        );
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(1, 2),
+            user,
+            curator_list: vec![curator2, missing_curator, curator1],
+            validator_list: vec![curator2],
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // invoke method
+        let result = contract_view_curators_flat(&ctx, &mut host);
+        claim!(result.is_ok());
+        claim_eq!(
+            result.unwrap(),
+            vec![(curator2, 2), (missing_curator, 0), (curator1, 1)]
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.curate distinguishes a validator-only user from an unknown user.
+    fn test_contract_curate_fails_with_validator_only_user() {
+        let admin = AccountAddress([0; 32]);
+        let project_contract_addr = ContractAddress::new(0, 0);
+        let validator = AccountAddress([1; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_sender(Address::Contract(project_contract_addr));
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(
+            validator,
+            UserState {
+                is_curator: false,
+                is_validator: true,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+                ..UserState::default()
+            },
+        );
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr,
+            user,
+            curator_list: Vec::new(),
+            validator_list: vec![validator],
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = CurateParams {
+            addr: validator,
+            project_id: "TEST-PRJ".into(),
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_curate(&ctx, &mut host);
+        claim!(result.is_err());
+        claim_eq!(result.err(), Some(Error::NotACurator));
+        let user_state = host.state().user.get(&validator).unwrap();
+        claim!(user_state.curated_projects.is_empty());
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.validate distinguishes a curator-only user from an unknown user.
+    fn test_contract_validate_fails_with_curator_only_user() {
+        let admin = AccountAddress([0; 32]);
+        let project_contract_addr = ContractAddress::new(0, 0);
+        let curator = AccountAddress([1; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_sender(Address::Contract(project_contract_addr));
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(
+            curator,
+            UserState {
+                is_curator: true,
+                is_validator: false,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+                ..UserState::default()
+            },
+        );
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr,
+            user,
+            curator_list: vec![curator],
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = ValidateParams {
+            addr: curator,
+            project_id: "TEST-PRJ".into(),
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_validate(&ctx, &mut host);
+        claim!(result.is_err());
+        claim_eq!(result.err(), Some(Error::NotAValidator));
+        let user_state = host.state().user.get(&curator).unwrap();
+        claim!(user_state.validated_projects.is_empty());
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.lock_config was successfully invoked by admin account.
+    fn test_contract_lock_config_invoked_by_admin() {
+        let admin = AccountAddress([0; 32]);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(1, 2),
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut admin_action_counts = state_builder.new_map();
+        admin_action_counts.insert(String::from("lock_config"), 1);
+        let expected_state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(1, 2),
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            config_locked: true,
+            state_seq: 1,
+            admin_action_counts,
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // invoke method
+        let result = contract_lock_config(&ctx, &mut host);
+        claim!(result.is_ok());
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.lock_config was invoked by non-admin account.
+    fn test_contract_lock_config_invoked_by_non_admin() {
+        let admin = AccountAddress([0; 32]);
+        let suspicious = AccountAddress([1; 32]);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(suspicious);
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(1, 2),
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // invoke method
+        let result = contract_lock_config(&ctx, &mut host);
+        claim!(result.is_err());
+        claim_eq!(result.err(), Some(Error::InvalidCaller));
+        claim!(!host.state().config_locked);
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.add_project_contract is rejected after the configuration is locked.
+    fn test_contract_add_project_contract_after_lock_config() {
+        let admin = AccountAddress([0; 32]);
+        let first_project_contract_addr = ContractAddress::new(1, 2);
+        let second_project_contract_addr = ContractAddress::new(3, 4);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // set the project contract before locking
+        let params = AddProjectContractParams {
+            project_contract_addr: first_project_contract_addr,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = contract_add_project_contract(&ctx, &mut host);
+        claim!(result.is_ok());
+
+        // lock the configuration
+        let result = contract_lock_config(&ctx, &mut host);
+        claim!(result.is_ok());
+
+        // try to change the project contract after locking
+        let params = AddProjectContractParams {
+            project_contract_addr: second_project_contract_addr,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = contract_add_project_contract(&ctx, &mut host);
+        claim!(result.is_err());
+        claim_eq!(result.err(), Some(Error::ConfigLocked));
+        claim_eq!(
+            host.state().project_contract_addr,
+            first_project_contract_addr
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.contract_view_user_projects returns deduplicated project ids.
+    fn test_contract_view_user_projects() {
+        let admin = AccountAddress([0; 32]);
+        let anyone = AccountAddress([100; 32]);
+        let dual_role = AccountAddress([1; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        // anyone can call this contract function.
+        ctx.set_invoker(anyone);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(
+            dual_role,
+            UserState {
+                is_curator: true,
+                is_validator: true,
+                curated_projects: vec!["TEST-PRJ1".into(), "TEST-PRJ2".into()],
+                validated_projects: vec![
+                    ("TEST-PRJ2".into(), ContractAddress::new(1, 2)),
+                    ("TEST-PRJ3".into(), ContractAddress::new(1, 2)),
+                ],
+                ..UserState::default()
+            },
+        );
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(1, 2),
+            user,
+            curator_list: vec![dual_role],
+            validator_list: vec![dual_role],
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = AddrParams { addr: dual_role };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_view_user_projects(&ctx, &mut host);
+        claim!(result.is_ok());
+        let expected: Vec<ProjectId> =
+            vec!["TEST-PRJ1".into(), "TEST-PRJ2".into(), "TEST-PRJ3".into()];
+        claim_eq!(result.unwrap(), expected);
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.contract_view_user_projects returns nothing for unknown users.
+    fn test_contract_view_user_projects_for_non_existing_user() {
+        let admin = AccountAddress([0; 32]);
+        let anyone = AccountAddress([100; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        // anyone can call this contract function.
+        ctx.set_invoker(anyone);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(1, 2),
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = AddrParams {
+            addr: AccountAddress([1; 32]),
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_view_user_projects(&ctx, &mut host);
+        claim!(result.is_ok());
+        claim!(result.unwrap().is_empty());
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.set_auto_prune_on_remove was successfully invoked by admin account.
+    fn test_contract_set_auto_prune_on_remove_invoked_by_admin() {
+        let admin = AccountAddress([0; 32]);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = SetAutoPruneOnRemoveParams {
+            auto_prune_on_remove: true,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_set_auto_prune_on_remove(&ctx, &mut host);
+        claim!(result.is_ok());
+        claim!(host.state().auto_prune_on_remove);
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.set_auto_prune_on_remove was invoked by non-admin account.
+    fn test_contract_set_auto_prune_on_remove_invoked_by_non_admin() {
+        let admin = AccountAddress([0; 32]);
+        let suspicious = AccountAddress([1; 32]);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(suspicious);
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = SetAutoPruneOnRemoveParams {
+            auto_prune_on_remove: true,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_set_auto_prune_on_remove(&ctx, &mut host);
+        claim!(result.is_err());
+        claim_eq!(result.err(), Some(Error::InvalidCaller));
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.remove_curator removes a user left without roles and projects
+    /// when `auto_prune_on_remove` is set.
+    fn test_contract_remove_curator_with_auto_prune_on_remove() {
+        let admin = AccountAddress([0; 32]);
+        let project_contract_addr = ContractAddress::new(0, 0);
+        let existing_user = AccountAddress([1; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(10));
+        ctx.set_invoker(admin);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(
+            existing_user,
+            UserState {
+                is_curator: true,
+                is_validator: false,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+                ..UserState::default()
+            },
+        );
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr,
+            user,
+            curator_list: vec![existing_user],
+            validator_list: Vec::new(),
+            auto_prune_on_remove: true,
+            ..default_test_state(&mut state_builder)
+        };
+        let mut admin_action_counts = state_builder.new_map();
+        admin_action_counts.insert(String::from("remove_curator"), 1);
+        let expected_state = State {
+            admin: Address::Account(admin),
+            project_contract_addr,
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            auto_prune_on_remove: true,
+            state_seq: 1,
+            admin_action_counts,
+            event_seq: 1,
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = RemoveCuratorParams {
+            addr: existing_user,
+            reason: None,
+            force: false,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let mut logger = TestLogger::init();
+        let result = contract_remove_curator(&ctx, &mut host, &mut logger);
+        claim!(result.is_ok());
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.remove_validator keeps a user that still has projects
+    /// even if `auto_prune_on_remove` is set.
+    fn test_contract_remove_validator_with_auto_prune_on_remove_keeps_projects() {
+        let admin = AccountAddress([0; 32]);
+        let project_contract_addr = ContractAddress::new(0, 0);
+        let existing_user = AccountAddress([1; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(10));
+        ctx.set_invoker(admin);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(
+            existing_user,
+            UserState {
+                is_curator: false,
+                is_validator: true,
+                curated_projects: Vec::new(),
+                validated_projects: vec![("TEST-PRJ".into(), project_contract_addr)],
+                ..UserState::default()
+            },
+        );
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr,
+            user,
+            curator_list: Vec::new(),
+            validator_list: vec![existing_user],
+            auto_prune_on_remove: true,
+            ..default_test_state(&mut state_builder)
+        };
+        let mut expected_user = state_builder.new_map();
+        expected_user.insert(
+            existing_user,
+            UserState {
+                is_curator: false,
+                is_validator: false,
+                curated_projects: Vec::new(),
+                validated_projects: vec![("TEST-PRJ".into(), project_contract_addr)],
+                last_role_change: Some(Timestamp::from_timestamp_millis(10)),
+                ..UserState::default()
+            },
+        );
+        let mut admin_action_counts = state_builder.new_map();
+        admin_action_counts.insert(String::from("remove_validator"), 1);
+        let expected_state = State {
+            admin: Address::Account(admin),
+            project_contract_addr,
+            user: expected_user,
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            auto_prune_on_remove: true,
+            state_seq: 1,
+            admin_action_counts,
+            event_seq: 1,
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = RemoveValidatorParams {
+            addr: existing_user,
+            reason: None,
+            force: true,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let mut logger = TestLogger::init();
+        let result = contract_remove_validator(&ctx, &mut host, &mut logger);
+        claim!(result.is_ok());
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.remove_validator keeps a user left without roles and projects
+    /// when `auto_prune_on_remove` is not set.
+    fn test_contract_remove_validator_without_auto_prune_on_remove() {
+        let admin = AccountAddress([0; 32]);
+        let project_contract_addr = ContractAddress::new(0, 0);
+        let existing_user = AccountAddress([1; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(10));
+        ctx.set_invoker(admin);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(
+            existing_user,
+            UserState {
+                is_curator: false,
+                is_validator: true,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+                ..UserState::default()
+            },
+        );
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr,
+            user,
+            curator_list: Vec::new(),
+            validator_list: vec![existing_user],
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = RemoveValidatorParams {
+            addr: existing_user,
+            reason: None,
+            force: false,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let mut logger = TestLogger::init();
+        let result = contract_remove_validator(&ctx, &mut host, &mut logger);
+        claim!(result.is_ok());
+        let user_state = host.state().user.get(&existing_user);
+        claim!(user_state.is_some());
+        claim!(!user_state.unwrap().is_validator);
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.backfill_lists rebuilds both lists from the user state.
+    fn test_contract_backfill_lists_invoked_by_admin() {
+        let admin = AccountAddress([0; 32]);
+        let curator = AccountAddress([1; 32]);
+        let validator = AccountAddress([2; 32]);
+        let dual_role = AccountAddress([3; 32]);
+        let no_role = AccountAddress([4; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(
+            curator,
+            UserState {
+                is_curator: true,
+                is_validator: false,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+                ..UserState::default()
+            },
+        );
+        user.insert(
+            validator,
+            UserState {
+                is_curator: false,
+                is_validator: true,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+                ..UserState::default()
+            },
+        );
+        user.insert(
+            dual_role,
+            UserState {
+                is_curator: true,
+                is_validator: true,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+                ..UserState::default()
+            },
+        );
+        user.insert(
+            no_role,
+            UserState {
+                is_curator: false,
+                is_validator: false,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+                ..UserState::default()
+            },
+        );
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user,
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // invoke method
+        let result = contract_backfill_lists(&ctx, &mut host);
+        claim!(result.is_ok());
+        let response = result.unwrap();
+        claim_eq!(response.curator_count, 2);
+        claim_eq!(response.validator_count, 2);
+        let actual_state = host.state();
+        claim_eq!(actual_state.curator_list, vec![curator, dual_role]);
+        claim_eq!(actual_state.validator_list, vec![validator, dual_role]);
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.backfill_lists was invoked by non-admin account.
+    fn test_contract_backfill_lists_invoked_by_non_admin() {
+        let admin = AccountAddress([0; 32]);
+        let suspicious = AccountAddress([1; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(suspicious);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: state_builder.new_map(),
+            curator_list: vec![suspicious],
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // invoke method
+        let result = contract_backfill_lists(&ctx, &mut host);
+        claim!(result.is_err());
+        claim_eq!(result.err(), Some(Error::InvalidCaller));
+        claim_eq!(host.state().curator_list, vec![suspicious]);
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.has_curated and overlay-users.has_validated check the project ids of
+    /// the user.
+    fn test_contract_has_curated_and_has_validated() {
+        let admin = AccountAddress([0; 32]);
+        let anyone = AccountAddress([100; 32]);
+        let existing_user = AccountAddress([1; 32]);
+        let non_existing_user = AccountAddress([2; 32]);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(
+            existing_user,
+            UserState {
+                is_curator: true,
+                is_validator: true,
+                curated_projects: vec!["TEST-PRJ1".into()],
+                validated_projects: vec![("TEST-PRJ2".into(), ContractAddress::new(1, 2))],
+                ..UserState::default()
+            },
+        );
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(1, 2),
+            user,
+            curator_list: vec![existing_user],
+            validator_list: vec![existing_user],
+            ..default_test_state(&mut state_builder)
+        };
+        let host = TestHost::new(state, state_builder);
+
+        let cases = [
+            (existing_user, "TEST-PRJ1", true, false),
+            (existing_user, "TEST-PRJ2", false, true),
+            (existing_user, "TEST-PRJ3", false, false),
+            (non_existing_user, "TEST-PRJ1", false, false),
+        ];
+        for (addr, project_id, curated, validated) in cases {
+            let mut ctx = TestReceiveContext::empty();
+            // anyone can call this contract function.
+            ctx.set_invoker(anyone);
+
+            // create parameters
+            let params = UserProjectParams {
+                addr,
+                project_id: project_id.into(),
+            };
+            let params_byte = to_bytes(&params);
+            ctx.set_parameter(&params_byte);
+
+            // invoke methods
+            claim_eq!(contract_has_curated(&ctx, &host), Ok(curated));
+            claim_eq!(contract_has_validated(&ctx, &host), Ok(validated));
+        }
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.set_user_roles_batch applies grants, revokes and no-ops.
+    fn test_contract_set_user_roles_batch_invoked_by_admin() {
+        let admin = AccountAddress([0; 32]);
+        let project_contract_addr = ContractAddress::new(0, 0);
+        let curator = AccountAddress([1; 32]);
+        let validator = AccountAddress([2; 32]);
+        let unchanged = AccountAddress([3; 32]);
+        let new_user = AccountAddress([4; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(10));
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(
+            curator,
+            UserState {
+                is_curator: true,
+                is_validator: false,
+                curated_projects: vec!["TEST-PRJ".into()],
+                validated_projects: Vec::new(),
+                ..UserState::default()
+            },
+        );
+        user.insert(
+            validator,
+            UserState {
+                is_curator: false,
+                is_validator: true,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+                ..UserState::default()
+            },
+        );
+        user.insert(
+            unchanged,
+            UserState {
+                is_curator: true,
+                is_validator: true,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+                ..UserState::default()
+            },
+        );
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr,
+            user,
+            curator_list: vec![curator, unchanged],
+            validator_list: vec![validator, unchanged],
+            ..default_test_state(&mut state_builder)
+        };
+        let mut expected_user = state_builder.new_map();
+        expected_user.insert(
+            curator,
+            UserState {
+                is_curator: false,
+                is_validator: true,
+                curated_projects: vec!["TEST-PRJ".into()],
+                validated_projects: Vec::new(),
+                validator_since: Some(Timestamp::from_timestamp_millis(10)),
+                was_ever_validator: true,
+                last_role_change: Some(Timestamp::from_timestamp_millis(10)),
+                ..UserState::default()
+            },
+        );
+        expected_user.insert(
+            validator,
+            UserState {
+                is_curator: false,
+                is_validator: false,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+                last_role_change: Some(Timestamp::from_timestamp_millis(10)),
+                ..UserState::default()
+            },
+        );
+        expected_user.insert(
+            unchanged,
+            UserState {
+                is_curator: true,
+                is_validator: true,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+                ..UserState::default()
+            },
+        );
+        expected_user.insert(
+            new_user,
+            UserState {
+                is_curator: true,
+                is_validator: false,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+                curator_since: Some(Timestamp::from_timestamp_millis(10)),
+                created_at: Some(Timestamp::from_timestamp_millis(10)),
+                was_ever_curator: true,
+                last_role_change: Some(Timestamp::from_timestamp_millis(10)),
+                ..UserState::default()
+            },
+        );
+        let mut admin_action_counts = state_builder.new_map();
+        admin_action_counts.insert(String::from("set_user_roles_batch"), 1);
+        let expected_state = State {
+            admin: Address::Account(admin),
+            project_contract_addr,
+            user: expected_user,
+            curator_list: vec![unchanged, new_user],
+            validator_list: vec![unchanged, curator],
+            cached_counts: CachedCounts {
+                user_count: 1,
+                curator_count: 2,
+                validator_count: 2,
+            },
+            state_seq: 1,
+            admin_action_counts,
+            event_seq: 4,
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let users = vec![
+            UserRolesParams {
+                addr: curator,
+                is_curator: false,
+                is_validator: true,
+            },
+            UserRolesParams {
+                addr: validator,
+                is_curator: false,
+                is_validator: false,
+            },
+            UserRolesParams {
+                addr: unchanged,
+                is_curator: true,
+                is_validator: true,
+            },
+            UserRolesParams {
+                addr: new_user,
+                is_curator: true,
+                is_validator: false,
+            },
+        ];
+        let params = SetUserRolesBatchParams {
+            users,
+            skip_admin: None,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let mut logger = TestLogger::init();
+        let result = contract_set_user_roles_batch(&ctx, &mut host, &mut logger);
+        claim_eq!(result.map(|response| response.changed_count), Ok(3));
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.set_user_roles_batch was invoked by non-admin account.
+    fn test_contract_set_user_roles_batch_invoked_by_non_admin() {
+        let admin = AccountAddress([0; 32]);
+        let suspicious = AccountAddress([1; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(suspicious);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = SetUserRolesBatchParams {
+            users: vec![UserRolesParams {
+                addr: suspicious,
+                is_curator: true,
+                is_validator: true,
+            }],
+            skip_admin: None,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let mut logger = TestLogger::init();
+        let result = contract_set_user_roles_batch(&ctx, &mut host, &mut logger);
+        claim!(result.is_err());
+        claim_eq!(result.err(), Some(Error::InvalidCaller));
+        claim!(host.state().user.get(&suspicious).is_none());
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.validated_projects_by_source filters validated projects by the
+    /// overlay-projects contract that recorded them.
+    fn test_contract_validated_projects_by_source() {
+        let admin = AccountAddress([0; 32]);
+        let anyone = AccountAddress([100; 32]);
+        let validator = AccountAddress([1; 32]);
+        let old_project_contract_addr = ContractAddress::new(1, 2);
+        let project_contract_addr = ContractAddress::new(3, 4);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(
+            validator,
+            UserState {
+                is_curator: false,
+                is_validator: true,
+                curated_projects: Vec::new(),
+                validated_projects: vec![
+                    ("TEST-PRJ1".into(), old_project_contract_addr),
+                    ("TEST-PRJ2".into(), project_contract_addr),
+                    ("TEST-PRJ3".into(), old_project_contract_addr),
+                ],
+                ..UserState::default()
+            },
+        );
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr,
+            user,
+            curator_list: Vec::new(),
+            validator_list: vec![validator],
+            ..default_test_state(&mut state_builder)
+        };
+        let host = TestHost::new(state, state_builder);
+
+        let cases: [(AccountAddress, ContractAddress, Vec<ProjectId>); 4] = [
+            (
+                validator,
+                old_project_contract_addr,
+                vec!["TEST-PRJ1".into(), "TEST-PRJ3".into()],
+            ),
+            (validator, project_contract_addr, vec!["TEST-PRJ2".into()]),
+            (validator, ContractAddress::new(5, 6), Vec::new()),
+            (AccountAddress([2; 32]), project_contract_addr, Vec::new()),
+        ];
+        for (addr, source, expected) in cases {
+            let mut ctx = TestReceiveContext::empty();
+            // anyone can call this contract function.
+            ctx.set_invoker(anyone);
+
+            // create parameters
+            let params = ValidatedProjectsBySourceParams { addr, source };
+            let params_byte = to_bytes(&params);
+            ctx.set_parameter(&params_byte);
+
+            // invoke method
+            let result = contract_validated_projects_by_source(&ctx, &host);
+            claim_eq!(result, Ok(expected));
+        }
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.validate records the overlay-projects contract as the source.
+    fn test_contract_validate_records_source() {
+        let admin = AccountAddress([0; 32]);
+        let old_project_contract_addr = ContractAddress::new(1, 2);
+        let project_contract_addr = ContractAddress::new(3, 4);
+        let validator = AccountAddress([1; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_sender(Address::Contract(project_contract_addr));
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(
+            validator,
+            UserState {
+                is_curator: false,
+                is_validator: true,
+                curated_projects: Vec::new(),
+                validated_projects: vec![("TEST-PRJ1".into(), old_project_contract_addr)],
+                ..UserState::default()
+            },
+        );
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr,
+            user,
+            curator_list: Vec::new(),
+            validator_list: vec![validator],
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = ValidateParams {
+            addr: validator,
+            project_id: "TEST-PRJ2".into(),
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_validate(&ctx, &mut host);
+        claim!(result.is_ok());
+        let user_state = host.state().user.get(&validator).unwrap();
+        let expected: Vec<(ProjectId, ContractAddress)> = vec![
+            ("TEST-PRJ1".into(), old_project_contract_addr),
+            ("TEST-PRJ2".into(), project_contract_addr),
+        ];
+        claim_eq!(user_state.validated_projects, expected);
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.view_user_extended reports the admin account as the admin.
+    fn test_contract_view_user_extended_for_admin() {
+        let admin = AccountAddress([0; 32]);
+        let anyone = AccountAddress([100; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        // anyone can call this contract function.
+        ctx.set_invoker(anyone);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(1, 2),
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = AddrParams { addr: admin };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_view_user_extended(&ctx, &mut host);
+        claim!(result.is_ok());
+        let view = result.unwrap();
+        claim!(view.is_admin);
+        claim!(!view.user_state.is_curator);
+        claim!(!view.user_state.is_validator);
+        claim!(view.user_state.curated_projects.is_empty());
+        claim!(view.user_state.validated_projects.is_empty());
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.view_user_extended returns the user data of non-admin users.
+    fn test_contract_view_user_extended_for_non_admin() {
+        let admin = AccountAddress([0; 32]);
+        let curator = AccountAddress([1; 32]);
+        let curator_state = UserState {
+            is_curator: true,
+            is_validator: false,
+            curated_projects: vec!["TEST-PRJ".into()],
+            validated_projects: Vec::new(),
+            ..UserState::default()
+        };
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(curator);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(curator, curator_state.clone());
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(1, 2),
+            user,
+            curator_list: vec![curator],
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = AddrParams { addr: curator };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_view_user_extended(&ctx, &mut host);
+        claim!(result.is_ok());
+        let view = result.unwrap();
+        claim!(!view.is_admin);
+        claim_eq!(view.user_state, curator_state);
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.swap_users swaps the user data and the list memberships.
+    fn test_contract_swap_users_invoked_by_admin() {
+        let admin = AccountAddress([0; 32]);
+        let project_contract_addr = ContractAddress::new(0, 0);
+        let user_a = AccountAddress([1; 32]);
+        let user_b = AccountAddress([2; 32]);
+        let other = AccountAddress([3; 32]);
+        let user_a_state = UserState {
+            is_curator: true,
+            is_validator: false,
+            curated_projects: vec!["TEST-PRJ1".into()],
+            validated_projects: Vec::new(),
+            ..UserState::default()
+        };
+        let user_b_state = UserState {
+            is_curator: false,
+            is_validator: true,
+            curated_projects: Vec::new(),
+            validated_projects: vec![("TEST-PRJ2".into(), project_contract_addr)],
+            ..UserState::default()
+        };
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(user_a, user_a_state.clone());
+        user.insert(user_b, user_b_state.clone());
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr,
+            user,
+            curator_list: vec![other, user_a],
+            validator_list: vec![user_b, other],
+            ..default_test_state(&mut state_builder)
+        };
+        let mut expected_user = state_builder.new_map();
+        expected_user.insert(user_a, user_b_state);
+        expected_user.insert(user_b, user_a_state);
+        let mut admin_action_counts = state_builder.new_map();
+        admin_action_counts.insert(String::from("swap_users"), 1);
+        let expected_state = State {
+            admin: Address::Account(admin),
+            project_contract_addr,
+            user: expected_user,
+            curator_list: vec![other, user_b],
+            validator_list: vec![user_a, other],
+            state_seq: 1,
+            admin_action_counts,
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = SwapUsersParams {
+            a: user_a,
+            b: user_b,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_swap_users(&ctx, &mut host);
+        claim!(result.is_ok());
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.swap_users moves the user data to an account that does not exist.
+    fn test_contract_swap_users_with_non_existing_user() {
+        let admin = AccountAddress([0; 32]);
+        let project_contract_addr = ContractAddress::new(0, 0);
+        let old_key = AccountAddress([1; 32]);
+        let new_key = AccountAddress([2; 32]);
+        let user_state = UserState {
+            is_curator: true,
+            is_validator: true,
+            curated_projects: vec!["TEST-PRJ1".into()],
+            validated_projects: Vec::new(),
+            ..UserState::default()
+        };
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(old_key, user_state.clone());
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr,
+            user,
+            curator_list: vec![old_key],
+            validator_list: vec![old_key],
+            ..default_test_state(&mut state_builder)
+        };
+        let mut expected_user = state_builder.new_map();
+        expected_user.insert(new_key, user_state);
+        let mut admin_action_counts = state_builder.new_map();
+        admin_action_counts.insert(String::from("swap_users"), 1);
+        let expected_state = State {
+            admin: Address::Account(admin),
+            project_contract_addr,
+            user: expected_user,
+            curator_list: vec![new_key],
+            validator_list: vec![new_key],
+            state_seq: 1,
+            admin_action_counts,
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = SwapUsersParams {
+            a: old_key,
+            b: new_key,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_swap_users(&ctx, &mut host);
+        claim!(result.is_ok());
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.swap_users rejects swapping an account with itself.
+    fn test_contract_swap_users_with_same_user() {
+        let admin = AccountAddress([0; 32]);
+        let user_a = AccountAddress([1; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = SwapUsersParams {
+            a: user_a,
+            b: user_a,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_swap_users(&ctx, &mut host);
+        claim!(result.is_err());
+        claim_eq!(result.err(), Some(Error::InvalidArgument));
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.swap_users was invoked by non-admin account.
+    fn test_contract_swap_users_invoked_by_non_admin() {
+        let admin = AccountAddress([0; 32]);
+        let suspicious = AccountAddress([1; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(suspicious);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = SwapUsersParams {
+            a: suspicious,
+            b: AccountAddress([2; 32]),
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_swap_users(&ctx, &mut host);
+        claim!(result.is_err());
+        claim_eq!(result.err(), Some(Error::InvalidCaller));
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.set_default_user_state was invoked by admin account.
+    fn test_contract_set_default_user_state_invoked_by_admin() {
+        let admin = AccountAddress([0; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut admin_action_counts = state_builder.new_map();
+        admin_action_counts.insert(String::from("set_default_user_state"), 1);
+        let expected_state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            default_user_state: UserState {
+                is_curator: false,
+                is_validator: true,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+                ..UserState::default()
+            },
+            state_seq: 1,
+            admin_action_counts,
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = SetDefaultUserStateParams {
+            is_curator: false,
+            is_validator: true,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_set_default_user_state(&ctx, &mut host);
+        claim!(result.is_ok());
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.set_default_user_state was invoked by non-admin account.
+    fn test_contract_set_default_user_state_invoked_by_non_admin() {
+        let admin = AccountAddress([0; 32]);
+        let suspicious = AccountAddress([1; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(suspicious);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = SetDefaultUserStateParams {
+            is_curator: true,
+            is_validator: true,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_set_default_user_state(&ctx, &mut host);
+        claim!(result.is_err());
+        claim_eq!(result.err(), Some(Error::InvalidCaller));
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.add_curator adopts the configured default user state for a new user.
+    fn test_contract_add_curator_with_default_user_state() {
+        let admin = AccountAddress([0; 32]);
+        let new_user = AccountAddress([1; 32]);
+        let default_user_state = UserState {
+            is_curator: false,
+            is_validator: true,
+            curated_projects: Vec::new(),
+            validated_projects: Vec::new(),
+            ..UserState::default()
+        };
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(10));
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            default_user_state: default_user_state.clone(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut expected_user = state_builder.new_map();
+        expected_user.insert(
+            new_user,
+            UserState {
+                is_curator: true,
+                is_validator: true,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+                curator_since: Some(Timestamp::from_timestamp_millis(10)),
+                validator_since: Some(Timestamp::from_timestamp_millis(10)),
+                created_at: Some(Timestamp::from_timestamp_millis(10)),
+                was_ever_curator: true,
+                was_ever_validator: true,
+                last_role_change: Some(Timestamp::from_timestamp_millis(10)),
+                ..UserState::default()
+            },
+        );
+        let mut admin_action_counts = state_builder.new_map();
+        admin_action_counts.insert(String::from("add_curator"), 1);
+        let expected_state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: expected_user,
+            curator_list: vec![new_user],
+            validator_list: vec![new_user],
+            default_user_state,
+            cached_counts: CachedCounts {
+                user_count: 1,
+                curator_count: 1,
+                validator_count: 1,
+            },
+            state_seq: 1,
+            admin_action_counts,
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = AddCuratorParams { addr: new_user };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_add_curator(&ctx, &mut host);
+        claim!(result.is_ok());
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.recent_curators returns the newest curators first.
+    fn test_contract_recent_curators() {
+        let admin = AccountAddress([0; 32]);
+        let anyone = AccountAddress([100; 32]);
+        let curator1 = AccountAddress([1; 32]);
+        let curator2 = AccountAddress([2; 32]);
+        let curator3 = AccountAddress([3; 32]);
+        let curator_state = |project_id: &str| UserState {
+            is_curator: true,
+            is_validator: false,
+            curated_projects: vec![project_id.into()],
+            validated_projects: Vec::new(),
+            ..UserState::default()
+        };
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(curator1, curator_state("TEST-PRJ1"));
+        user.insert(curator2, curator_state("TEST-PRJ2"));
+        user.insert(curator3, curator_state("TEST-PRJ3"));
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user,
+            curator_list: vec![curator1, curator2, curator3],
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let host = TestHost::new(state, state_builder);
+
+        for (limit, expected) in [
+            (0, Vec::new()),
+            (
+                2,
+                vec![
+                    (curator3, curator_state("TEST-PRJ3")),
+                    (curator2, curator_state("TEST-PRJ2")),
+                ],
+            ),
+            (
+                10,
+                vec![
+                    (curator3, curator_state("TEST-PRJ3")),
+                    (curator2, curator_state("TEST-PRJ2")),
+                    (curator1, curator_state("TEST-PRJ1")),
+                ],
+            ),
+        ] {
+            let mut ctx = TestReceiveContext::empty();
+            // anyone can call this contract function.
+            ctx.set_invoker(anyone);
+
+            // create parameters
+            let params = RecentCuratorsParams { limit };
+            let params_byte = to_bytes(&params);
+            ctx.set_parameter(&params_byte);
+
+            // invoke method
+            let result = contract_recent_curators(&ctx, &host);
+            claim!(result.is_ok());
+            claim_eq!(result.unwrap(), expected);
+        }
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.add_user_tag adds a tag only once.
+    fn test_contract_add_user_tag() {
+        let admin = AccountAddress([0; 32]);
+        let curator = AccountAddress([1; 32]);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(
+            curator,
+            UserState {
+                is_curator: true,
+                is_validator: false,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+                ..UserState::default()
+            },
+        );
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user,
+            curator_list: vec![curator],
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut expected_user = state_builder.new_map();
+        expected_user.insert(
+            curator,
+            UserState {
+                is_curator: true,
+                is_validator: false,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+                tags: vec!["DeFi".into(), "gaming".into()],
+                ..UserState::default()
+            },
+        );
+        let mut admin_action_counts = state_builder.new_map();
+        admin_action_counts.insert(String::from("add_user_tag"), 3);
+        let expected_state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: expected_user,
+            curator_list: vec![curator],
+            validator_list: Vec::new(),
+            state_seq: 2,
+            admin_action_counts,
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        for tag in ["DeFi", "gaming", "DeFi"] {
+            let mut ctx = TestReceiveContext::empty();
+            ctx.set_invoker(admin);
+
+            // create parameters
+            let params = AddUserTagParams {
+                addr: curator,
+                tag: tag.into(),
+            };
+            let params_byte = to_bytes(&params);
+            ctx.set_parameter(&params_byte);
+
+            // invoke method
+            let result = contract_add_user_tag(&ctx, &mut host);
+            claim!(result.is_ok());
+        }
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.add_user_tag rejects a tag exceeding the per-user cap.
+    fn test_contract_add_user_tag_exceeds_cap() {
+        let admin = AccountAddress([0; 32]);
+        let curator = AccountAddress([1; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(
+            curator,
+            UserState {
+                is_curator: true,
+                is_validator: false,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+                tags: (0..MAX_TAGS_PER_USER)
+                    .map(|i| format!("TAG-{}", i))
+                    .collect(),
+                ..UserState::default()
+            },
+        );
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user,
+            curator_list: vec![curator],
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = AddUserTagParams {
+            addr: curator,
+            tag: "DeFi".into(),
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_add_user_tag(&ctx, &mut host);
+        claim!(result.is_err());
+        claim_eq!(result.err(), Some(Error::TooManyTags));
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.add_user_tag rejects a tag longer than MAX_TAG_LEN.
+    fn test_contract_add_user_tag_too_long() {
+        let admin = AccountAddress([0; 32]);
+        let curator = AccountAddress([1; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(curator, UserState::default());
+        let state = State {
+            admin: Address::Account(admin),
+            user,
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        let params_byte = to_bytes(&AddUserTagParams {
+            addr: curator,
+            tag: "x".repeat(MAX_TAG_LEN + 1),
+        });
+        ctx.set_parameter(&params_byte);
+        let result = contract_add_user_tag(&ctx, &mut host);
+        claim_eq!(result, Err(Error::InvalidArgument));
+        claim!(host.state().user.get(&curator).unwrap().tags.is_empty());
+
+        // a tag of exactly MAX_TAG_LEN bytes is accepted
+        let params_byte = to_bytes(&AddUserTagParams {
+            addr: curator,
+            tag: "x".repeat(MAX_TAG_LEN),
+        });
+        ctx.set_parameter(&params_byte);
+        let result = contract_add_user_tag(&ctx, &mut host);
+        claim_eq!(result, Ok(()));
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.remove_user_tag removes the tag from the user.
+    fn test_contract_remove_user_tag() {
+        let admin = AccountAddress([0; 32]);
+        let curator = AccountAddress([1; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(
+            curator,
+            UserState {
+                is_curator: true,
+                is_validator: false,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+                tags: vec!["DeFi".into(), "gaming".into()],
+                ..UserState::default()
+            },
+        );
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user,
+            curator_list: vec![curator],
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut expected_user = state_builder.new_map();
+        expected_user.insert(
+            curator,
+            UserState {
+                is_curator: true,
+                is_validator: false,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+                tags: vec!["gaming".into()],
+                ..UserState::default()
+            },
+        );
+        let mut admin_action_counts = state_builder.new_map();
+        admin_action_counts.insert(String::from("remove_user_tag"), 1);
+        let expected_state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: expected_user,
+            curator_list: vec![curator],
+            validator_list: Vec::new(),
+            state_seq: 1,
+            admin_action_counts,
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = RemoveUserTagParams {
+            addr: curator,
+            tag: "DeFi".into(),
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_remove_user_tag(&ctx, &mut host);
+        claim!(result.is_ok());
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.remove_user_tag rejects a user that does not exist.
+    fn test_contract_remove_user_tag_with_non_existing_user() {
+        let admin = AccountAddress([0; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = RemoveUserTagParams {
+            addr: AccountAddress([1; 32]),
+            tag: "DeFi".into(),
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_remove_user_tag(&ctx, &mut host);
+        claim!(result.is_err());
+        claim_eq!(result.err(), Some(Error::UserNotFound));
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.users_by_tag returns all the users holding the tag.
+    fn test_contract_users_by_tag() {
+        let admin = AccountAddress([0; 32]);
+        let anyone = AccountAddress([100; 32]);
+        let user1 = AccountAddress([1; 32]);
+        let user2 = AccountAddress([2; 32]);
+        let user3 = AccountAddress([3; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        // anyone can call this contract function.
+        ctx.set_invoker(anyone);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        for (addr, tags) in [
+            (user1, vec!["DeFi".into()]),
+            (user2, vec!["gaming".into()]),
+            (user3, vec!["gaming".into(), "DeFi".into()]),
+        ] {
+            user.insert(
+                addr,
+                UserState {
+                    is_curator: true,
+                    is_validator: false,
+                    curated_projects: Vec::new(),
+                    validated_projects: Vec::new(),
+                    tags,
+                    ..UserState::default()
+                },
+            );
+        }
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user,
+            curator_list: vec![user1, user2, user3],
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = UsersByTagParams { tag: "DeFi".into() };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_users_by_tag(&ctx, &host);
+        claim!(result.is_ok());
+        claim_eq!(result.unwrap(), vec![user1, user3]);
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.am_i_project_contract recognizes only the configured overlay-projects contract.
+    fn test_contract_am_i_project_contract() {
+        let admin = AccountAddress([0; 32]);
+        let project_contract_addr = ContractAddress::new(1, 2);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr,
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let host = TestHost::new(state, state_builder);
+
+        for (sender, expected) in [
+            (Address::Contract(project_contract_addr), true),
+            (Address::Contract(ContractAddress::new(3, 4)), false),
+        ] {
+            let mut ctx = TestReceiveContext::empty();
+            ctx.set_sender(sender);
+
+            // invoke method
+            let result = contract_am_i_project_contract(&ctx, &host);
+            claim!(result.is_ok());
+            claim_eq!(result.unwrap(), expected);
+        }
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.remove_curator logs the event with the reason.
+    fn test_contract_remove_curator_logs_reason() {
+        let admin = AccountAddress([0; 32]);
+        let curator = AccountAddress([1; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(10));
+        ctx.set_invoker(admin);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(
+            curator,
+            UserState {
+                is_curator: true,
+                is_validator: false,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+                ..UserState::default()
+            },
+        );
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user,
+            curator_list: vec![curator],
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = RemoveCuratorParams {
+            addr: curator,
+            reason: Some("inactive for a year".into()),
+            force: false,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let mut logger = TestLogger::init();
+        let result = contract_remove_curator(&ctx, &mut host, &mut logger);
+        claim!(result.is_ok());
+        claim_eq!(
+            logger.logs,
+            vec![to_bytes(&Event::RemoveCurator(RemoveRoleEvent {
+                event_seq: 0,
+                addr: curator,
+                reason: Some("inactive for a year".into()),
+            }))]
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.remove_validator logs the event with the reason.
+    fn test_contract_remove_validator_logs_reason() {
+        let admin = AccountAddress([0; 32]);
+        let validator = AccountAddress([1; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(10));
+        ctx.set_invoker(admin);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(
+            validator,
+            UserState {
+                is_curator: false,
+                is_validator: true,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+                ..UserState::default()
+            },
+        );
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user,
+            curator_list: Vec::new(),
+            validator_list: vec![validator],
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = RemoveValidatorParams {
+            addr: validator,
+            reason: Some("conflict of interest".into()),
+            force: false,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let mut logger = TestLogger::init();
+        let result = contract_remove_validator(&ctx, &mut host, &mut logger);
+        claim!(result.is_ok());
+        claim_eq!(
+            logger.logs,
+            vec![to_bytes(&Event::RemoveValidator(RemoveRoleEvent {
+                event_seq: 0,
+                addr: validator,
+                reason: Some("conflict of interest".into()),
+            }))]
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.remove_curator rejects a too long reason.
+    fn test_contract_remove_curator_with_too_long_reason() {
+        let admin = AccountAddress([0; 32]);
+        let curator = AccountAddress([1; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: state_builder.new_map(),
+            curator_list: vec![curator],
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = RemoveCuratorParams {
+            addr: curator,
+            reason: Some("x".repeat(MAX_REASON_LEN + 1)),
+            force: false,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let mut logger = TestLogger::init();
+        let result = contract_remove_curator(&ctx, &mut host, &mut logger);
+        claim!(result.is_err());
+        claim_eq!(result.err(), Some(Error::InvalidArgument));
+        claim!(logger.logs.is_empty());
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.curate rejects the admin account only when reject_privileged_targets is set.
+    fn test_contract_curate_with_reject_privileged_targets() {
+        let admin = AccountAddress([0; 32]);
+        let project_contract_addr = ContractAddress::new(1, 2);
+        for reject_privileged_targets in [false, true] {
+            let mut ctx = TestReceiveContext::empty();
+            ctx.set_sender(Address::Contract(project_contract_addr));
+            // setup state
+            let mut state_builder = TestStateBuilder::new();
+            let mut user = state_builder.new_map();
+            user.insert(
+                admin,
+                UserState {
+                    is_curator: true,
+                    is_validator: false,
+                    curated_projects: Vec::new(),
+                    validated_projects: Vec::new(),
+                    ..UserState::default()
+                },
+            );
+            let state = State {
+                admin: Address::Account(admin),
+                project_contract_addr,
+                user,
+                curator_list: vec![admin],
+                validator_list: Vec::new(),
+                reject_privileged_targets,
+                ..default_test_state(&mut state_builder)
+            };
+            let mut host = TestHost::new(state, state_builder);
+
+            // create parameters
+            let params = CurateParams {
+                addr: admin,
+                project_id: "TEST-PRJ1".into(),
+            };
+            let params_byte = to_bytes(&params);
+            ctx.set_parameter(&params_byte);
+
+            // invoke method
+            let result = contract_curate(&ctx, &mut host);
+            if reject_privileged_targets {
+                claim_eq!(result.err(), Some(Error::InvalidArgument));
+            } else {
+                claim!(result.is_ok());
+            }
+        }
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.validate rejects the admin account only when reject_privileged_targets is set.
+    fn test_contract_validate_with_reject_privileged_targets() {
+        let admin = AccountAddress([0; 32]);
+        let project_contract_addr = ContractAddress::new(1, 2);
+        for reject_privileged_targets in [false, true] {
+            let mut ctx = TestReceiveContext::empty();
+            ctx.set_sender(Address::Contract(project_contract_addr));
+            // setup state
+            let mut state_builder = TestStateBuilder::new();
+            let mut user = state_builder.new_map();
+            user.insert(
+                admin,
+                UserState {
+                    is_curator: false,
+                    is_validator: true,
+                    curated_projects: Vec::new(),
+                    validated_projects: Vec::new(),
+                    ..UserState::default()
+                },
+            );
+            let state = State {
+                admin: Address::Account(admin),
+                project_contract_addr,
+                user,
+                curator_list: Vec::new(),
+                validator_list: vec![admin],
+                reject_privileged_targets,
+                ..default_test_state(&mut state_builder)
+            };
+            let mut host = TestHost::new(state, state_builder);
+
+            // create parameters
+            let params = ValidateParams {
+                addr: admin,
+                project_id: "TEST-PRJ1".into(),
+            };
+            let params_byte = to_bytes(&params);
+            ctx.set_parameter(&params_byte);
+
+            // invoke method
+            let result = contract_validate(&ctx, &mut host);
+            if reject_privileged_targets {
+                claim_eq!(result.err(), Some(Error::InvalidArgument));
+            } else {
+                claim!(result.is_ok());
+            }
+        }
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.view_user_flags packs the roles of each user.
+    fn test_contract_view_user_flags() {
+        let admin = AccountAddress([0; 32]);
+        let anyone = AccountAddress([100; 32]);
+        let no_role = AccountAddress([1; 32]);
+        let curator = AccountAddress([2; 32]);
+        let validator = AccountAddress([3; 32]);
+        let both = AccountAddress([4; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        // anyone can call this contract function.
+        ctx.set_invoker(anyone);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        for (addr, is_curator, is_validator) in [
+            (no_role, false, false),
+            (curator, true, false),
+            (validator, false, true),
+            (both, true, true),
+        ] {
+            user.insert(
+                addr,
+                UserState {
+                    is_curator,
+                    is_validator,
+                    curated_projects: Vec::new(),
+                    validated_projects: Vec::new(),
+                    ..UserState::default()
+                },
+            );
+        }
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user,
+            curator_list: vec![curator, both],
+            validator_list: vec![validator, both],
+            ..default_test_state(&mut state_builder)
+        };
+        let host = TestHost::new(state, state_builder);
+
+        // invoke method
+        let result = contract_view_user_flags(&ctx, &host);
+        claim!(result.is_ok());
+        let flags = result.unwrap();
+        claim_eq!(
+            flags,
+            vec![
+                (no_role, 0b00),
+                (curator, 0b01),
+                (validator, 0b10),
+                (both, 0b11)
+            ]
+        );
+        // decode the flags
+        let decoded: Vec<(bool, bool)> = flags
+            .iter()
+            .map(|(_, flags)| (flags & 1 != 0, flags & 2 != 0))
+            .collect();
+        claim_eq!(
+            decoded,
+            vec![(false, false), (true, false), (false, true), (true, true)]
+        );
+    }
+
+    #[concordium_test]
+    /// Test that paging through overlay-users.view_users_after returns every user exactly once.
+    fn test_contract_view_users_after() {
+        let admin = AccountAddress([0; 32]);
+        let anyone = AccountAddress([100; 32]);
+        let addrs: Vec<AccountAddress> = (1..=5).map(|i| AccountAddress([i; 32])).collect();
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        for addr in addrs.iter() {
+            user.insert(
+                *addr,
+                UserState {
+                    is_curator: true,
+                    is_validator: false,
+                    curated_projects: Vec::new(),
+                    validated_projects: Vec::new(),
+                    ..UserState::default()
+                },
+            );
+        }
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user,
+            curator_list: addrs.clone(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let host = TestHost::new(state, state_builder);
+
+        let mut after = None;
+        let mut seen = Vec::new();
+        let mut page_sizes = Vec::new();
+        loop {
+            let mut ctx = TestReceiveContext::empty();
+            // anyone can call this contract function.
+            ctx.set_invoker(anyone);
+
+            // create parameters
+            let params = ViewUsersAfterParams {
+                after,
+                limit: 2,
+                include_deleted: false,
+            };
+            let params_byte = to_bytes(&params);
+            ctx.set_parameter(&params_byte);
+
+            // invoke method
+            let result = contract_view_users_after(&ctx, &host);
+            claim!(result.is_ok());
+            let response = result.unwrap();
+            page_sizes.push(response.users.len());
+            seen.extend(response.users.iter().map(|(addr, _)| *addr));
+            if response.next.is_none() {
+                break;
+            }
+            after = response.next;
+        }
+        claim_eq!(page_sizes, vec![2, 2, 1]);
+        claim_eq!(seen, addrs);
+
+        // a zero limit is rejected instead of returning an empty page without a cursor
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(anyone);
+        let params_byte = to_bytes(&ViewUsersAfterParams {
+            after: None,
+            limit: 0,
+            include_deleted: false,
+        });
+        ctx.set_parameter(&params_byte);
+        let result = contract_view_users_after(&ctx, &host);
+        claim_eq!(result.err(), Some(Error::InvalidArgument));
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.distinct_project_count counts shared project ids once.
+    fn test_contract_distinct_project_count() {
+        let admin = AccountAddress([0; 32]);
+        let anyone = AccountAddress([100; 32]);
+        let project_contract_addr = ContractAddress::new(1, 2);
+        let user1 = AccountAddress([1; 32]);
+        let user2 = AccountAddress([2; 32]);
+        let user3 = AccountAddress([3; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        // anyone can call this contract function.
+        ctx.set_invoker(anyone);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(
+            user1,
+            UserState {
+                is_curator: true,
+                is_validator: false,
+                curated_projects: vec!["TEST-PRJ1".into(), "TEST-PRJ2".into()],
+                validated_projects: Vec::new(),
+                ..UserState::default()
+            },
+        );
+        user.insert(
+            user2,
+            UserState {
+                is_curator: true,
+                is_validator: true,
+                curated_projects: vec!["TEST-PRJ2".into()],
+                validated_projects: vec![
+                    ("TEST-PRJ1".into(), project_contract_addr),
+                    ("TEST-PRJ3".into(), project_contract_addr),
+                ],
+                ..UserState::default()
+            },
+        );
+        user.insert(
+            user3,
+            UserState {
+                is_curator: false,
+                is_validator: true,
+                curated_projects: Vec::new(),
+                validated_projects: vec![("TEST-PRJ3".into(), project_contract_addr)],
+                ..UserState::default()
+            },
+        );
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr,
+            user,
+            curator_list: vec![user1, user2],
+            validator_list: vec![user2, user3],
+            ..default_test_state(&mut state_builder)
+        };
+        let host = TestHost::new(state, state_builder);
+
+        // invoke method
+        let result = contract_distinct_project_count(&ctx, &host);
+        claim!(result.is_ok());
+        claim_eq!(result.unwrap(), 3);
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.curate applies the per-user project limit over the global one.
+    fn test_contract_curate_with_max_projects() {
+        let admin = AccountAddress([0; 32]);
+        let project_contract_addr = ContractAddress::new(1, 2);
+        let trusted = AccountAddress([1; 32]);
+        let normal = AccountAddress([2; 32]);
+        let restricted = AccountAddress([3; 32]);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        for (addr, max_projects) in [(trusted, Some(3)), (normal, None), (restricted, Some(1))] {
+            user.insert(
+                addr,
+                UserState {
+                    is_curator: true,
+                    is_validator: false,
+                    curated_projects: vec!["TEST-PRJ1".into()],
+                    validated_projects: vec![("TEST-PRJ2".into(), project_contract_addr)],
+                    max_projects,
+                    ..UserState::default()
+                },
+            );
+        }
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr,
+            user,
+            curator_list: vec![trusted, normal, restricted],
+            validator_list: Vec::new(),
+            max_projects_per_user: Some(2),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        for (addr, expected) in [
+            (trusted, Ok(())),
+            (normal, Err(Error::TooManyProjects)),
+            (restricted, Err(Error::TooManyProjects)),
+        ] {
+            let mut ctx = TestReceiveContext::empty();
+            ctx.set_sender(Address::Contract(project_contract_addr));
+
+            // create parameters
+            let params = CurateParams {
+                addr,
+                project_id: "TEST-PRJ3".into(),
+            };
+            let params_byte = to_bytes(&params);
+            ctx.set_parameter(&params_byte);
+
+            // invoke method
+            let result = contract_curate(&ctx, &mut host);
+            claim_eq!(result, expected);
+        }
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.validate rejects a new project over the global project limit.
+    fn test_contract_validate_with_max_projects_per_user() {
+        let admin = AccountAddress([0; 32]);
+        let project_contract_addr = ContractAddress::new(1, 2);
+        let validator = AccountAddress([1; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_sender(Address::Contract(project_contract_addr));
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(
+            validator,
+            UserState {
+                is_curator: false,
+                is_validator: true,
+                curated_projects: Vec::new(),
+                validated_projects: vec![("TEST-PRJ1".into(), project_contract_addr)],
+                ..UserState::default()
+            },
+        );
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr,
+            user,
+            curator_list: Vec::new(),
+            validator_list: vec![validator],
+            max_projects_per_user: Some(1),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = ValidateParams {
+            addr: validator,
+            project_id: "TEST-PRJ2".into(),
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_validate(&ctx, &mut host);
+        claim!(result.is_err());
+        claim_eq!(result.err(), Some(Error::TooManyProjects));
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.set_user_max_projects sets the limit of the user.
+    fn test_contract_set_user_max_projects() {
+        let admin = AccountAddress([0; 32]);
+        let curator = AccountAddress([1; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(
+            curator,
+            UserState {
+                is_curator: true,
+                is_validator: false,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+                ..UserState::default()
+            },
+        );
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user,
+            curator_list: vec![curator],
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut expected_user = state_builder.new_map();
+        expected_user.insert(
+            curator,
+            UserState {
+                is_curator: true,
+                is_validator: false,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+                max_projects: Some(10),
+                ..UserState::default()
+            },
+        );
+        let mut admin_action_counts = state_builder.new_map();
+        admin_action_counts.insert(String::from("set_user_max_projects"), 1);
+        let expected_state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: expected_user,
+            curator_list: vec![curator],
+            validator_list: Vec::new(),
+            state_seq: 1,
+            admin_action_counts,
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = SetUserMaxProjectsParams {
+            addr: curator,
+            max_projects: Some(10),
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_set_user_max_projects(&ctx, &mut host);
+        claim!(result.is_ok());
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.diff_curators reports missing and extra curators.
+    fn test_contract_diff_curators() {
+        let admin = AccountAddress([0; 32]);
+        let anyone = AccountAddress([100; 32]);
+        let curator1 = AccountAddress([1; 32]);
+        let curator2 = AccountAddress([2; 32]);
+        let curator3 = AccountAddress([3; 32]);
+        let not_curator = AccountAddress([4; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        // anyone can call this contract function.
+        ctx.set_invoker(anyone);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: state_builder.new_map(),
+            curator_list: vec![curator1, curator2, curator3],
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params: DiffCuratorsParams = vec![not_curator, curator2, curator1];
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_diff_curators(&ctx, &host);
+        claim!(result.is_ok());
+        let response = result.unwrap();
+        claim_eq!(response.missing, vec![not_curator]);
+        claim_eq!(response.extra, vec![curator3]);
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.add_curator and remove_curator are rejected after freeze_curators.
+    fn test_contract_freeze_curators() {
+        let admin = AccountAddress([0; 32]);
+        let curator = AccountAddress([1; 32]);
+        let new_curator = AccountAddress([2; 32]);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(
+            curator,
+            UserState {
+                is_curator: true,
+                is_validator: false,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+                ..UserState::default()
+            },
+        );
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user,
+            curator_list: vec![curator],
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // freeze the curator set
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        let result = contract_freeze_curators(&ctx, &mut host);
+        claim!(result.is_ok());
+        claim!(host.state().curators_frozen);
+
+        // add a curator
+        let params = AddCuratorParams { addr: new_curator };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = contract_add_curator(&ctx, &mut host);
+        claim_eq!(result.err(), Some(Error::CuratorsFrozen));
+
+        // remove a curator
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        let params = RemoveCuratorParams {
+            addr: curator,
+            reason: None,
+            force: false,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let mut logger = TestLogger::init();
+        let result = contract_remove_curator(&ctx, &mut host, &mut logger);
+        claim_eq!(result.err(), Some(Error::CuratorsFrozen));
+        claim_eq!(host.state().curator_list, vec![curator]);
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.curate still works for existing curators when the curator set is frozen.
+    fn test_contract_curate_with_frozen_curators() {
+        let admin = AccountAddress([0; 32]);
+        let project_contract_addr = ContractAddress::new(1, 2);
+        let curator = AccountAddress([1; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_sender(Address::Contract(project_contract_addr));
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(
+            curator,
+            UserState {
+                is_curator: true,
+                is_validator: false,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+                ..UserState::default()
+            },
+        );
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr,
+            user,
+            curator_list: vec![curator],
+            validator_list: Vec::new(),
+            curators_frozen: true,
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = CurateParams {
+            addr: curator,
+            project_id: "TEST-PRJ1".into(),
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_curate(&ctx, &mut host);
+        claim!(result.is_ok());
+        let curated_projects = host
+            .state()
+            .user
+            .get(&curator)
+            .map(|user_state| user_state.curated_projects.clone());
+        claim_eq!(curated_projects, Some(vec![ProjectId::from("TEST-PRJ1")]));
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.validate returns the number of the validated projects of the user.
+    fn test_contract_validate_returns_validated_count() {
+        let admin = AccountAddress([0; 32]);
+        let project_contract_addr = ContractAddress::new(1, 2);
+        let validator = AccountAddress([1; 32]);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(
+            validator,
+            UserState {
+                is_curator: false,
+                is_validator: true,
+                curated_projects: Vec::new(),
+                validated_projects: vec![("TEST-PRJ1".into(), project_contract_addr)],
+                ..UserState::default()
+            },
+        );
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr,
+            user,
+            curator_list: Vec::new(),
+            validator_list: vec![validator],
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // a new validation increments the count and a duplicate keeps it.
+        for (project_id, expected) in [("TEST-PRJ2", 2), ("TEST-PRJ2", 2), ("TEST-PRJ3", 3)] {
+            let mut ctx = TestReceiveContext::empty();
+            ctx.set_sender(Address::Contract(project_contract_addr));
+
+            // create parameters
+            let params = ValidateParams {
+                addr: validator,
+                project_id: project_id.into(),
+            };
+            let params_byte = to_bytes(&params);
+            ctx.set_parameter(&params_byte);
+
+            // invoke method
+            let result = contract_validate(&ctx, &mut host);
+            claim_eq!(result, Ok(expected));
+        }
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.view_user_project_breakdown splits the projects of a dual-role user.
+    fn test_contract_view_user_project_breakdown() {
+        let admin = AccountAddress([0; 32]);
+        let anyone = AccountAddress([100; 32]);
+        let project_contract_addr = ContractAddress::new(1, 2);
+        let dual_role_user = AccountAddress([1; 32]);
+        let not_existing_user = AccountAddress([2; 32]);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(
+            dual_role_user,
+            UserState {
+                is_curator: true,
+                is_validator: true,
+                curated_projects: vec!["TEST-PRJ1".into(), "TEST-PRJ2".into()],
+                validated_projects: vec![("TEST-PRJ3".into(), project_contract_addr)],
+                ..UserState::default()
+            },
+        );
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr,
+            user,
+            curator_list: vec![dual_role_user],
+            validator_list: vec![dual_role_user],
+            ..default_test_state(&mut state_builder)
+        };
+        let host = TestHost::new(state, state_builder);
+
+        for (addr, expected_curated, expected_validated) in [
+            (
+                dual_role_user,
+                vec![ProjectId::from("TEST-PRJ1"), ProjectId::from("TEST-PRJ2")],
+                vec![ProjectId::from("TEST-PRJ3")],
+            ),
+            (not_existing_user, Vec::new(), Vec::new()),
+        ] {
+            let mut ctx = TestReceiveContext::empty();
+            // anyone can call this contract function.
+            ctx.set_invoker(anyone);
+
+            // create parameters
+            let params = ViewUserProjectBreakdownParams { addr };
+            let params_byte = to_bytes(&params);
+            ctx.set_parameter(&params_byte);
+
+            // invoke method
+            let result = contract_view_user_project_breakdown(&ctx, &host);
+            claim!(result.is_ok());
+            let response = result.unwrap();
+            claim_eq!(response.curated, expected_curated);
+            claim_eq!(response.validated, expected_validated);
+        }
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.add_curator creates unknown users only when require_known_users is not set.
+    fn test_contract_add_curator_with_require_known_users() {
+        let admin = AccountAddress([0; 32]);
+        let registered = AccountAddress([1; 32]);
+        let unknown = AccountAddress([2; 32]);
+        for require_known_users in [false, true] {
+            // setup state
+            let mut state_builder = TestStateBuilder::new();
+            let mut user = state_builder.new_map();
+            user.insert(
+                registered,
+                UserState {
+                    is_curator: false,
+                    is_validator: false,
+                    curated_projects: Vec::new(),
+                    validated_projects: Vec::new(),
+                    ..UserState::default()
+                },
+            );
+            let state = State {
+                admin: Address::Account(admin),
+                project_contract_addr: ContractAddress::new(0, 0),
+                user,
+                curator_list: Vec::new(),
+                validator_list: Vec::new(),
+                require_known_users,
+                ..default_test_state(&mut state_builder)
+            };
+            let mut host = TestHost::new(state, state_builder);
+
+            for (addr, expected) in [
+                (registered, Ok(())),
+                (
+                    unknown,
+                    if require_known_users {
+                        Err(Error::UserNotFound)
+                    } else {
+                        Ok(())
+                    },
+                ),
+            ] {
+                let mut ctx = TestReceiveContext::empty();
+                ctx.set_invoker(admin);
+                ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(10));
+
+                // create parameters
+                let params = AddCuratorParams { addr };
+                let params_byte = to_bytes(&params);
+                ctx.set_parameter(&params_byte);
+
+                // invoke method
+                let result = contract_add_curator(&ctx, &mut host);
+                claim_eq!(result, expected);
+            }
+            let expected_curator_list = if require_known_users {
+                vec![registered]
+            } else {
+                vec![registered, unknown]
+            };
+            claim_eq!(host.state().curator_list, expected_curator_list);
+        }
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.add_validator rejects an unknown user when require_known_users is set.
+    fn test_contract_add_validator_with_require_known_users() {
+        let admin = AccountAddress([0; 32]);
+        let unknown = AccountAddress([1; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            require_known_users: true,
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = AddValidatorParams { addr: unknown };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_add_validator(&ctx, &mut host);
+        claim!(result.is_err());
+        claim_eq!(result.err(), Some(Error::UserNotFound));
+        claim!(host.state().validator_list.is_empty());
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.register_user inserts a user without any roles.
+    fn test_contract_register_user_invoked_by_admin() {
+        let admin = AccountAddress([0; 32]);
+        let new_user = AccountAddress([1; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(10));
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut expected_user = state_builder.new_map();
+        expected_user.insert(
+            new_user,
+            UserState {
+                is_curator: false,
+                is_validator: false,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+                created_at: Some(Timestamp::from_timestamp_millis(10)),
+                ..UserState::default()
+            },
+        );
+        let expected_state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: expected_user,
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            cached_counts: CachedCounts {
+                user_count: 1,
+                ..CachedCounts::default()
+            },
+            state_seq: 1,
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = RegisterUserParams { addr: new_user };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_register_user(&ctx, &mut host);
+        claim!(result.is_ok());
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.register_user can be invoked by the user itself and a role can be granted afterwards.
+    fn test_contract_register_user_self_service() {
+        let admin = AccountAddress([0; 32]);
+        let new_user = AccountAddress([1; 32]);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            require_known_users: true,
+            ..default_test_state(&mut state_builder)
+        };
+        let mut expected_user = state_builder.new_map();
+        expected_user.insert(
+            new_user,
+            UserState {
+                is_curator: true,
+                is_validator: false,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+                curator_since: Some(Timestamp::from_timestamp_millis(10)),
+                created_at: Some(Timestamp::from_timestamp_millis(10)),
+                was_ever_curator: true,
+                last_role_change: Some(Timestamp::from_timestamp_millis(10)),
+                ..UserState::default()
+            },
+        );
+        let mut admin_action_counts = state_builder.new_map();
+        admin_action_counts.insert(String::from("add_curator"), 1);
+        let expected_state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: expected_user,
+            curator_list: vec![new_user],
+            validator_list: Vec::new(),
+            require_known_users: true,
+            cached_counts: CachedCounts {
+                user_count: 1,
+                curator_count: 1,
+                ..CachedCounts::default()
+            },
+            state_seq: 2,
+            admin_action_counts,
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+        let params = RegisterUserParams { addr: new_user };
+        let params_byte = to_bytes(&params);
+
+        // the user registers itself.
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(new_user);
+        ctx.set_sender(Address::Account(new_user));
+        ctx.set_parameter(&params_byte);
+        let result = contract_register_user(&ctx, &mut host);
+        claim!(result.is_ok());
+
+        // the admin grants the curator role.
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(10));
+        ctx.set_parameter(&params_byte);
+        let result = contract_add_curator(&ctx, &mut host);
+        claim!(result.is_ok());
+
+        // registering again keeps the role.
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(new_user);
+        ctx.set_sender(Address::Account(new_user));
+        ctx.set_parameter(&params_byte);
+        let result = contract_register_user(&ctx, &mut host);
+        claim!(result.is_ok());
+
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.register_user rejects registering another account by non-admin account.
+    fn test_contract_register_user_for_other_account() {
+        let admin = AccountAddress([0; 32]);
+        let suspicious = AccountAddress([1; 32]);
+        let other = AccountAddress([2; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(suspicious);
+        ctx.set_sender(Address::Account(suspicious));
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = RegisterUserParams { addr: other };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_register_user(&ctx, &mut host);
+        claim!(result.is_err());
+        claim_eq!(result.err(), Some(Error::InvalidCaller));
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.view_admin_status reports whether the caller is the admin.
+    fn test_contract_view_admin_status() {
+        let admin = AccountAddress([0; 32]);
+        let anyone = AccountAddress([100; 32]);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let host = TestHost::new(state, state_builder);
+
+        for (invoker, expected) in [(admin, true), (anyone, false)] {
+            let mut ctx = TestReceiveContext::empty();
+            ctx.set_invoker(invoker);
+
+            // invoke method
+            let result = contract_view_admin_status(&ctx, &host);
+            claim!(result.is_ok());
+            let response = result.unwrap();
+            claim_eq!(response.admin, Address::Account(admin));
+            claim_eq!(response.caller_is_admin, expected);
+        }
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.curate_batch_users curates for curators and returns the skipped users.
+    fn test_contract_curate_batch_users() {
+        let admin = AccountAddress([0; 32]);
+        let project_contract_addr = ContractAddress::new(1, 2);
+        let curator1 = AccountAddress([1; 32]);
+        let curator2 = AccountAddress([2; 32]);
+        let validator = AccountAddress([3; 32]);
+        let unknown = AccountAddress([4; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_sender(Address::Contract(project_contract_addr));
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(
+            curator1,
+            UserState {
+                is_curator: true,
+                is_validator: false,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+                ..UserState::default()
+            },
+        );
+        user.insert(
+            curator2,
+            UserState {
+                is_curator: true,
+                is_validator: false,
+                curated_projects: vec!["TEST-PRJ1".into()],
+                validated_projects: Vec::new(),
+                ..UserState::default()
+            },
+        );
+        user.insert(
+            validator,
+            UserState {
+                is_curator: false,
+                is_validator: true,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+                ..UserState::default()
+            },
+        );
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr,
+            user,
+            curator_list: vec![curator1, curator2],
+            validator_list: vec![validator],
+            ..default_test_state(&mut state_builder)
+        };
+        let mut expected_user = state_builder.new_map();
+        expected_user.insert(
+            curator1,
+            UserState {
+                is_curator: true,
+                is_validator: false,
+                curated_projects: vec!["TEST-PRJ1".into()],
+                validated_projects: Vec::new(),
+                ..UserState::default()
+            },
+        );
+        expected_user.insert(
+            curator2,
+            UserState {
+                is_curator: true,
+                is_validator: false,
+                curated_projects: vec!["TEST-PRJ1".into()],
+                validated_projects: Vec::new(),
+                ..UserState::default()
+            },
+        );
+        expected_user.insert(
+            validator,
+            UserState {
+                is_curator: false,
+                is_validator: true,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+                ..UserState::default()
+            },
+        );
+        let expected_state = State {
+            admin: Address::Account(admin),
+            project_contract_addr,
+            user: expected_user,
+            curator_list: vec![curator1, curator2],
+            validator_list: vec![validator],
+            state_seq: 1,
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = CurateBatchUsersParams {
+            project_id: "TEST-PRJ1".into(),
+            addrs: vec![unknown, curator1, validator, curator2],
+            strict: false,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_curate_batch_users(&ctx, &mut host);
+        claim!(result.is_ok());
+        claim_eq!(result.unwrap(), vec![unknown, validator]);
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.curate_batch_users was invoked by non-project contract.
+    fn test_contract_curate_batch_users_invoked_by_non_project_contract() {
+        let admin = AccountAddress([0; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_sender(Address::Contract(ContractAddress::new(3, 4)));
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(1, 2),
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = CurateBatchUsersParams {
+            project_id: "TEST-PRJ1".into(),
+            addrs: vec![AccountAddress([1; 32])],
+            strict: false,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_curate_batch_users(&ctx, &mut host);
+        claim!(result.is_err());
+        claim_eq!(result.err(), Some(Error::InvalidCaller));
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.recompute_counts corrects wrong cached counts.
+    fn test_contract_recompute_counts() {
+        let admin = AccountAddress([0; 32]);
+        let curator = AccountAddress([1; 32]);
+        let validator = AccountAddress([2; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        for (addr, is_curator, is_validator) in [(curator, true, false), (validator, false, true)] {
+            user.insert(
+                addr,
+                UserState {
+                    is_curator,
+                    is_validator,
+                    curated_projects: Vec::new(),
+                    validated_projects: Vec::new(),
+                    ..UserState::default()
+                },
+            );
+        }
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user,
+            curator_list: vec![curator],
+            validator_list: vec![validator],
+            cached_counts: CachedCounts {
+                user_count: 10,
+                curator_count: 0,
+                validator_count: 5,
+            },
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+        let expected_counts = CachedCounts {
+            user_count: 2,
+            curator_count: 1,
+            validator_count: 1,
+        };
+
+        // invoke method
+        let result = contract_recompute_counts(&ctx, &mut host);
+        claim!(result.is_ok());
+        claim_eq!(result.unwrap(), expected_counts);
+        claim_eq!(host.state().cached_counts, expected_counts);
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.get_user_roles returns the roles and the project counts of users.
+    fn test_contract_get_user_roles() {
+        let admin = AccountAddress([0; 32]);
+        let anyone = AccountAddress([100; 32]);
+        let project_contract_addr = ContractAddress::new(1, 2);
+        let curator = AccountAddress([1; 32]);
+        let dual_role_user = AccountAddress([2; 32]);
+        let unknown = AccountAddress([3; 32]);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(
+            curator,
+            UserState {
+                is_curator: true,
+                is_validator: false,
+                curated_projects: vec!["TEST-PRJ1".into()],
+                validated_projects: Vec::new(),
+                ..UserState::default()
+            },
+        );
+        user.insert(
+            dual_role_user,
+            UserState {
+                is_curator: true,
+                is_validator: true,
+                curated_projects: vec!["TEST-PRJ1".into(), "TEST-PRJ2".into()],
+                validated_projects: vec![
+                    ("TEST-PRJ3".into(), project_contract_addr),
+                    ("TEST-PRJ4".into(), project_contract_addr),
+                    ("TEST-PRJ5".into(), project_contract_addr),
+                ],
+                ..UserState::default()
+            },
+        );
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr,
+            user,
+            curator_list: vec![curator, dual_role_user],
+            validator_list: vec![dual_role_user],
+            ..default_test_state(&mut state_builder)
+        };
+        let host = TestHost::new(state, state_builder);
+
+        for (addr, expected) in [
+            (curator, (true, false, 1, 0)),
+            (dual_role_user, (true, true, 2, 3)),
+            (unknown, (false, false, 0, 0)),
+        ] {
+            let mut ctx = TestReceiveContext::empty();
+            // anyone can call this contract function.
+            ctx.set_invoker(anyone);
+
+            // create parameters
+            let params = GetUserRolesParams { addr };
+            let params_byte = to_bytes(&params);
+            ctx.set_parameter(&params_byte);
+
+            // invoke method
+            let result = contract_get_user_roles(&ctx, &host);
+            claim!(result.is_ok());
+            let response = result.unwrap();
+            claim_eq!(
+                (
+                    response.is_curator,
+                    response.is_validator,
+                    response.curated_count,
+                    response.validated_count
+                ),
+                expected
+            );
+            claim_eq!(to_bytes(&response).len(), 10);
+        }
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.force_curate appends the project id to a non-curator.
+    fn test_contract_force_curate_with_non_curator() {
+        let admin = AccountAddress([0; 32]);
+        let former_curator = AccountAddress([1; 32]);
+        let project_contract_addr = ContractAddress::new(1, 2);
+        for set_curator in [false, true] {
+            let mut ctx = TestReceiveContext::empty();
+            ctx.set_invoker(admin);
+            ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(10));
+            // setup state
+            let mut state_builder = TestStateBuilder::new();
+            let mut user = state_builder.new_map();
+            user.insert(
+                former_curator,
+                UserState {
+                    is_curator: false,
+                    is_validator: false,
+                    curated_projects: vec!["TEST-PRJ1".into()],
+                    validated_projects: Vec::new(),
+                    ..UserState::default()
+                },
+            );
+            let state = State {
+                admin: Address::Account(admin),
+                project_contract_addr,
+                user,
+                curator_list: Vec::new(),
+                validator_list: Vec::new(),
+                ..default_test_state(&mut state_builder)
+            };
+            let mut expected_user = state_builder.new_map();
+            expected_user.insert(
+                former_curator,
+                UserState {
+                    is_curator: set_curator,
+                    is_validator: false,
+                    curated_projects: vec!["TEST-PRJ1".into(), "TEST-PRJ2".into()],
+                    validated_projects: Vec::new(),
+                    curator_since: set_curator.then_some(Timestamp::from_timestamp_millis(10)),
+                    last_role_change: set_curator.then_some(Timestamp::from_timestamp_millis(10)),
+                    ..UserState::default()
+                },
+            );
+            let mut admin_action_counts = state_builder.new_map();
+            admin_action_counts.insert(String::from("force_curate"), 1);
+            let expected_state = State {
+                admin: Address::Account(admin),
+                project_contract_addr,
+                user: expected_user,
+                curator_list: if set_curator {
+                    vec![former_curator]
+                } else {
+                    Vec::new()
+                },
+                validator_list: Vec::new(),
+                cached_counts: CachedCounts {
+                    curator_count: if set_curator { 1 } else { 0 },
+                    ..CachedCounts::default()
+                },
+                state_seq: 1,
+                admin_action_counts,
+                ..default_test_state(&mut state_builder)
+            };
+            let mut host = TestHost::new(state, state_builder);
+
+            // create parameters
+            let params = ForceCurateParams {
+                addr: former_curator,
+                project_id: "TEST-PRJ2".into(),
+                set_curator,
+            };
+            let params_byte = to_bytes(&params);
+            ctx.set_parameter(&params_byte);
+
+            // invoke method
+            let result = contract_force_curate(&ctx, &mut host);
+            claim!(result.is_ok());
+            let actual_state = host.state();
+            claim_eq!(
+                *actual_state,
+                expected_state,
+                "state has been changed unexpectedly..."
+            );
+        }
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.view_drifted_users reports the users missing from the lists.
+    fn test_contract_view_drifted_users() {
+        let admin = AccountAddress([0; 32]);
+        let anyone = AccountAddress([100; 32]);
+        let listed = AccountAddress([1; 32]);
+        let unlisted_curator = AccountAddress([2; 32]);
+        let unlisted_validator = AccountAddress([3; 32]);
+        let unlisted_both = AccountAddress([4; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        // anyone can call this contract function.
+        ctx.set_invoker(anyone);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        for (addr, is_curator, is_validator) in [
+            (listed, true, true),
+            (unlisted_curator, true, false),
+            (unlisted_validator, false, true),
+            (unlisted_both, true, true),
+        ] {
+            user.insert(
+                addr,
+                UserState {
+                    is_curator,
+                    is_validator,
+                    curated_projects: Vec::new(),
+                    validated_projects: Vec::new(),
+                    ..UserState::default()
+                },
+            );
+        }
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user,
+            curator_list: vec![listed],
+            validator_list: vec![listed],
+            ..default_test_state(&mut state_builder)
+        };
+        let host = TestHost::new(state, state_builder);
+
+        // invoke method
+        let result = contract_view_drifted_users(&ctx, &host);
+        claim!(result.is_ok());
+        let response = result.unwrap();
+        claim_eq!(response.curators, vec![unlisted_curator, unlisted_both]);
+        claim_eq!(response.validators, vec![unlisted_validator, unlisted_both]);
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.view_users is restricted to the admin only when users_view_public is not set.
+    fn test_contract_view_users_with_users_view_public() {
+        let admin = AccountAddress([0; 32]);
+        let anyone = AccountAddress([100; 32]);
+        for (users_view_public, invoker, expected_ok) in [
+            (true, anyone, true),
+            (false, admin, true),
+            (false, anyone, false),
+        ] {
+            let mut ctx = TestReceiveContext::empty();
+            ctx.set_invoker(invoker);
+            // setup state
+            let mut state_builder = TestStateBuilder::new();
+            let state = State {
+                admin: Address::Account(admin),
+                project_contract_addr: ContractAddress::new(0, 0),
+                user: state_builder.new_map(),
+                curator_list: Vec::new(),
+                validator_list: Vec::new(),
+                users_view_public,
+                ..default_test_state(&mut state_builder)
+            };
+            let host = TestHost::new(state, state_builder);
+
+            // invoke method
+            let result = contract_view_users(&ctx, &host);
+            if expected_ok {
+                claim!(result.is_ok());
+            } else {
+                claim_eq!(result.err(), Some(Error::InvalidCaller));
+            }
+        }
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.view_users_after rejects non-admin account when users_view_public is not set.
+    fn test_contract_view_users_after_invoked_by_non_admin_when_gated() {
+        let admin = AccountAddress([0; 32]);
+        let anyone = AccountAddress([100; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(anyone);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            users_view_public: false,
+            ..default_test_state(&mut state_builder)
+        };
+        let host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = ViewUsersAfterParams {
+            after: None,
+            limit: 10,
+            include_deleted: false,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_view_users_after(&ctx, &host);
+        claim!(result.is_err());
+        claim_eq!(result.err(), Some(Error::InvalidCaller));
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.set_users_view_public was invoked by non-admin account.
+    fn test_contract_set_users_view_public_invoked_by_non_admin() {
+        let admin = AccountAddress([0; 32]);
+        let suspicious = AccountAddress([1; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(suspicious);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = SetUsersViewPublicParams {
+            users_view_public: false,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_set_users_view_public(&ctx, &mut host);
+        claim!(result.is_err());
+        claim_eq!(result.err(), Some(Error::InvalidCaller));
+        claim!(host.state().users_view_public);
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.curators_granted_between returns only the curators granted in the range.
+    fn test_contract_curators_granted_between() {
+        let admin = AccountAddress([0; 32]);
+        let anyone = AccountAddress([100; 32]);
+        let before = AccountAddress([1; 32]);
+        let at_from = AccountAddress([2; 32]);
+        let inside = AccountAddress([3; 32]);
+        let at_to = AccountAddress([4; 32]);
+        let after = AccountAddress([5; 32]);
+        let unknown_time = AccountAddress([6; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        // anyone can call this contract function.
+        ctx.set_invoker(anyone);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        for (addr, curator_since) in [
+            (before, Some(99)),
+            (at_from, Some(100)),
+            (inside, Some(150)),
+            (at_to, Some(200)),
+            (after, Some(201)),
+            (unknown_time, None),
+        ] {
+            user.insert(
+                addr,
+                UserState {
+                    is_curator: true,
+                    is_validator: false,
+                    curated_projects: Vec::new(),
+                    validated_projects: Vec::new(),
+                    curator_since: curator_since.map(Timestamp::from_timestamp_millis),
+                    ..UserState::default()
+                },
+            );
+        }
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user,
+            curator_list: vec![before, at_from, inside, at_to, after, unknown_time],
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = CuratorsGrantedBetweenParams {
+            from: Timestamp::from_timestamp_millis(100),
+            to: Timestamp::from_timestamp_millis(200),
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_curators_granted_between(&ctx, &host);
+        claim!(result.is_ok());
+        claim_eq!(result.unwrap(), vec![at_from, inside, at_to]);
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.cas_project_contract applies only when the expected address matches.
+    fn test_contract_cas_project_contract() {
+        let admin = AccountAddress([0; 32]);
+        let current = ContractAddress::new(1, 0);
+        let new = ContractAddress::new(2, 0);
+        for (expected, expected_result, expected_addr) in [
+            (current, Ok(()), new),
+            (
+                ContractAddress::new(3, 0),
+                Err(Error::PreconditionFailed),
+                current,
+            ),
+        ] {
+            let mut ctx = TestReceiveContext::empty();
+            ctx.set_invoker(admin);
+            // setup state
+            let mut state_builder = TestStateBuilder::new();
+            let state = State {
+                admin: Address::Account(admin),
+                project_contract_addr: current,
+                user: state_builder.new_map(),
+                curator_list: Vec::new(),
+                validator_list: Vec::new(),
+                ..default_test_state(&mut state_builder)
+            };
+            let mut host = TestHost::new(state, state_builder);
+
+            // create parameters
+            let params = CasProjectContractParams { expected, new };
+            let params_byte = to_bytes(&params);
+            ctx.set_parameter(&params_byte);
+
+            // invoke method
+            let result = contract_cas_project_contract(&ctx, &mut host);
+            claim_eq!(result, expected_result);
+            claim_eq!(host.state().project_contract_addr, expected_addr);
+        }
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.set_user_roles_batch logs a single aggregated event for the batch.
+    fn test_contract_set_user_roles_batch_logs_aggregated_event() {
+        let admin = AccountAddress([0; 32]);
+        let curator = AccountAddress([1; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(10));
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(
+            curator,
+            UserState {
+                is_curator: true,
+                is_validator: false,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+                ..UserState::default()
+            },
+        );
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user,
+            curator_list: vec![curator],
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // switch the curator to a validator and grant the curator role to the others
+        let params = SetUserRolesBatchParams {
+            users: (1..=4)
+                .map(|i| UserRolesParams {
+                    addr: AccountAddress([i; 32]),
+                    is_curator: i != 1,
+                    is_validator: i == 1,
+                })
+                .collect(),
+            skip_admin: None,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let mut logger = TestLogger::init();
+        let result = contract_set_user_roles_batch(&ctx, &mut host, &mut logger);
+        claim_eq!(result.map(|response| response.changed_count), Ok(4));
+        claim_eq!(
+            logger.logs,
+            vec![to_bytes(&Event::BatchRoleChange(BatchRoleChangeEvent {
+                event_seq: 0,
+                curators_added: 3,
+                curators_removed: 1,
+                validators_added: 1,
+                validators_removed: 0,
+            }))]
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.view_identity returns the contract address and owner from the context.
+    fn test_contract_view_identity() {
+        let owner = AccountAddress([1; 32]);
+        let self_address = ContractAddress::new(3, 0);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(AccountAddress([100; 32]));
+        ctx.set_owner(owner);
+        ctx.set_self_address(self_address);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Account(AccountAddress([0; 32])),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let host = TestHost::new(state, state_builder);
+
+        // invoke method
+        let result = contract_view_identity(&ctx, &host);
+        claim!(result.is_ok());
+        let response = result.unwrap();
+        claim_eq!(response.self_address, self_address);
+        claim_eq!(response.owner, owner);
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.remove_validator rejects a user with validated projects
+    /// unless `force` is set.
+    fn test_contract_remove_validator_with_active_projects() {
+        let admin = AccountAddress([0; 32]);
+        let project_contract_addr = ContractAddress::new(0, 0);
+        let validator = AccountAddress([1; 32]);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(
+            validator,
+            UserState {
+                is_curator: false,
+                is_validator: true,
+                curated_projects: Vec::new(),
+                validated_projects: vec![("TEST-PRJ".into(), project_contract_addr)],
+                ..UserState::default()
+            },
+        );
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr,
+            user,
+            curator_list: Vec::new(),
+            validator_list: vec![validator],
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        for (force, expected) in [(false, Err(Error::HasActiveProjects)), (true, Ok(()))] {
+            let mut ctx = TestReceiveContext::empty();
+            ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(10));
+            ctx.set_invoker(admin);
+
+            // create parameters
+            let params = RemoveValidatorParams {
+                addr: validator,
+                reason: None,
+                force,
+            };
+            let params_byte = to_bytes(&params);
+            ctx.set_parameter(&params_byte);
+
+            // invoke method
+            let mut logger = TestLogger::init();
+            let result = contract_remove_validator(&ctx, &mut host, &mut logger);
+            claim_eq!(result, expected);
+            let actual_state = host.state();
+            claim_eq!(
+                actual_state.user.get(&validator).unwrap().is_validator,
+                !force
+            );
+            claim_eq!(actual_state.validator_list.is_empty(), force);
+        }
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.view_users_by_grant_time orders users by the latest grant time.
+    fn test_contract_view_users_by_grant_time() {
+        let admin = AccountAddress([0; 32]);
+        let anyone = AccountAddress([100; 32]);
+        let curator = AccountAddress([1; 32]);
+        let validator = AccountAddress([2; 32]);
+        let both = AccountAddress([3; 32]);
+        let no_grant = AccountAddress([4; 32]);
+        let user_state = |curator_since: Option<u64>, validator_since: Option<u64>| UserState {
+            is_curator: curator_since.is_some(),
+            is_validator: validator_since.is_some(),
+            curated_projects: Vec::new(),
+            validated_projects: Vec::new(),
+            curator_since: curator_since.map(Timestamp::from_timestamp_millis),
+            validator_since: validator_since.map(Timestamp::from_timestamp_millis),
+            ..UserState::default()
+        };
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(curator, user_state(Some(20), None));
+        user.insert(validator, user_state(None, Some(10)));
+        user.insert(both, user_state(Some(5), Some(30)));
+        user.insert(no_grant, user_state(None, None));
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user,
+            curator_list: vec![curator, both],
+            validator_list: vec![validator, both],
+            ..default_test_state(&mut state_builder)
+        };
+        let host = TestHost::new(state, state_builder);
+
+        for (limit, expected) in [
+            (2, vec![both, curator]),
+            (10, vec![both, curator, validator, no_grant]),
+        ] {
+            let mut ctx = TestReceiveContext::empty();
+            ctx.set_invoker(anyone);
+
+            // create parameters
+            let params = ViewUsersByGrantTimeParams {
+                limit,
+                include_deleted: false,
+            };
+            let params_byte = to_bytes(&params);
+            ctx.set_parameter(&params_byte);
+
+            // invoke method
+            let result = contract_view_users_by_grant_time(&ctx, &host);
+            claim!(result.is_ok());
+            let addrs: Vec<AccountAddress> =
+                result.unwrap().into_iter().map(|(addr, _)| addr).collect();
+            claim_eq!(addrs, expected);
+        }
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.soft_delete_user clears the roles and projects but keeps the user.
+    fn test_contract_soft_delete_user() {
+        let admin = AccountAddress([0; 32]);
+        let project_contract_addr = ContractAddress::new(0, 0);
+        let user_addr = AccountAddress([1; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(
+            user_addr,
+            UserState {
+                is_curator: true,
+                is_validator: true,
+                curated_projects: vec!["TEST-PRJ1".into()],
+                validated_projects: vec![("TEST-PRJ2".into(), project_contract_addr)],
+                tags: vec!["tag".into()],
+                curator_since: Some(Timestamp::from_timestamp_millis(1)),
+                validator_since: Some(Timestamp::from_timestamp_millis(2)),
+                ..UserState::default()
+            },
+        );
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr,
+            user,
+            curator_list: vec![user_addr],
+            validator_list: vec![user_addr],
+            ..default_test_state(&mut state_builder)
+        };
+        let mut expected_user = state_builder.new_map();
+        expected_user.insert(
+            user_addr,
+            UserState {
+                is_curator: false,
+                is_validator: false,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+                tags: vec!["tag".into()],
+                deleted: true,
+                ..UserState::default()
+            },
+        );
+        let mut admin_action_counts = state_builder.new_map();
+        admin_action_counts.insert(String::from("soft_delete_user"), 1);
+        let expected_state = State {
+            admin: Address::Account(admin),
+            project_contract_addr,
+            user: expected_user,
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            state_seq: 1,
+            admin_action_counts,
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = SoftDeleteUserParams { addr: user_addr };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_soft_delete_user(&ctx, &mut host);
+        claim!(result.is_ok());
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+
+        // soft-deleting an unknown user is rejected
+        let params_byte = to_bytes(&SoftDeleteUserParams {
+            addr: AccountAddress([2; 32]),
+        });
+        ctx.set_parameter(&params_byte);
+        let result = contract_soft_delete_user(&ctx, &mut host);
+        claim_eq!(result, Err(Error::UserNotFound));
+    }
+
+    #[concordium_test]
+    /// Test that the user listing views include soft-deleted users only if requested.
+    fn test_contract_view_users_with_soft_deleted_user() {
+        let admin = AccountAddress([0; 32]);
+        let active = AccountAddress([1; 32]);
+        let deleted = AccountAddress([2; 32]);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(
+            active,
+            UserState {
+                is_curator: true,
+                ..UserState::default()
+            },
+        );
+        // the role flag is left on the stub to check that the role filter does not bypass the deleted flag
+        user.insert(
+            deleted,
+            UserState {
+                is_curator: true,
+                deleted: true,
+                ..UserState::default()
+            },
+        );
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user,
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let host = TestHost::new(state, state_builder);
+
+        for (include_deleted, expected) in [(false, vec![active]), (true, vec![active, deleted])] {
+            let mut ctx = TestReceiveContext::empty();
+            ctx.set_invoker(AccountAddress([100; 32]));
+
+            // invoke method
+            let result = if include_deleted {
+                contract_view_users_with_deleted(&ctx, &host)
+            } else {
+                contract_view_users(&ctx, &host)
+            };
+            claim!(result.is_ok());
+            let addrs: Vec<AccountAddress> =
+                result.unwrap().into_iter().map(|(addr, _)| addr).collect();
+            claim_eq!(addrs, expected);
+
+            let params_byte = to_bytes(&ViewUsersAfterParams {
+                after: None,
+                limit: 10,
+                include_deleted,
+            });
+            ctx.set_parameter(&params_byte);
+            let result = contract_view_users_after(&ctx, &host);
+            claim!(result.is_ok());
+            let addrs: Vec<AccountAddress> = result
+                .unwrap()
+                .users
+                .into_iter()
+                .map(|(addr, _)| addr)
+                .collect();
+            claim_eq!(addrs, expected);
+
+            let params_byte = to_bytes(&ViewUsersFilteredPagedParams {
+                role: RoleFilter::Curator,
+                start: 0,
+                limit: 10,
+                include_deleted,
+            });
+            ctx.set_parameter(&params_byte);
+            let result = contract_view_users_filtered_paged(&ctx, &host);
+            claim!(result.is_ok());
+            let addrs: Vec<AccountAddress> = result
+                .unwrap()
+                .users
+                .into_iter()
+                .map(|(addr, _)| addr)
+                .collect();
+            claim_eq!(addrs, expected);
+
+            let params_byte = to_bytes(&ViewUsersByGrantTimeParams {
+                limit: 10,
+                include_deleted,
+            });
+            ctx.set_parameter(&params_byte);
+            let result = contract_view_users_by_grant_time(&ctx, &host);
+            claim!(result.is_ok());
+            let addrs: Vec<AccountAddress> =
+                result.unwrap().into_iter().map(|(addr, _)| addr).collect();
+            claim_eq!(addrs, expected);
+        }
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.top_curators ranks the curators by the number of curated projects.
+    fn test_contract_top_curators() {
+        let admin = AccountAddress([0; 32]);
+        let curator1 = AccountAddress([1; 32]);
+        let curator2 = AccountAddress([2; 32]);
+        let curator3 = AccountAddress([3; 32]);
+        let validator = AccountAddress([4; 32]);
+        let user_state = |is_curator: bool, curated_count: usize| UserState {
+            is_curator,
+            curated_projects: (0..curated_count)
+                .map(|_| ProjectId::from("TEST-PRJ"))
+                .collect(),
+            ..UserState::default()
+        };
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(curator1, user_state(true, 1));
+        user.insert(curator2, user_state(true, 3));
+        user.insert(curator3, user_state(true, 2));
+        user.insert(validator, user_state(false, 5));
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user,
+            curator_list: vec![curator1, curator2, curator3],
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let host = TestHost::new(state, state_builder);
+
+        for (limit, expected) in [
+            (2, vec![(curator2, 3), (curator3, 2)]),
+            (10, vec![(curator2, 3), (curator3, 2), (curator1, 1)]),
+        ] {
+            let mut ctx = TestReceiveContext::empty();
+            ctx.set_invoker(AccountAddress([100; 32]));
+
+            // create parameters
+            let params = TopCuratorsParams { limit };
+            let params_byte = to_bytes(&params);
+            ctx.set_parameter(&params_byte);
+
+            // invoke method
+            let result = contract_top_curators(&ctx, &host);
+            claim!(result.is_ok());
+            claim_eq!(result.unwrap(), expected);
+        }
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.remove_curator logs no event when events_enabled is not set.
+    fn test_contract_remove_curator_with_events_disabled() {
+        let admin = AccountAddress([0; 32]);
+        let curator = AccountAddress([1; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(10));
+        ctx.set_invoker(admin);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(
+            curator,
+            UserState {
+                is_curator: true,
+                is_validator: false,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+                ..UserState::default()
+            },
+        );
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user,
+            curator_list: vec![curator],
+            validator_list: Vec::new(),
+            events_enabled: false,
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = RemoveCuratorParams {
+            addr: curator,
+            reason: Some("inactive".into()),
+            force: false,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let mut logger = TestLogger::init();
+        let result = contract_remove_curator(&ctx, &mut host, &mut logger);
+        claim!(result.is_ok());
+        claim!(logger.logs.is_empty());
+        let actual_state = host.state();
+        claim!(!actual_state.user.get(&curator).unwrap().is_curator);
+        claim!(actual_state.curator_list.is_empty());
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.is_project_referenced finds curated and validated project ids.
+    fn test_contract_is_project_referenced() {
+        let admin = AccountAddress([0; 32]);
+        let project_contract_addr = ContractAddress::new(0, 0);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(
+            AccountAddress([1; 32]),
+            UserState {
+                is_curator: true,
+                is_validator: true,
+                curated_projects: vec!["TEST-PRJ1".into()],
+                validated_projects: vec![("TEST-PRJ2".into(), project_contract_addr)],
+                ..UserState::default()
+            },
+        );
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr,
+            user,
+            curator_list: vec![AccountAddress([1; 32])],
+            validator_list: vec![AccountAddress([1; 32])],
+            ..default_test_state(&mut state_builder)
+        };
+        let host = TestHost::new(state, state_builder);
+
+        for (project_id, expected) in [
+            ("TEST-PRJ1", true),
+            ("TEST-PRJ2", true),
+            ("TEST-PRJ3", false),
+        ] {
+            let mut ctx = TestReceiveContext::empty();
+            ctx.set_invoker(AccountAddress([100; 32]));
+
+            // create parameters
+            let params: IsProjectReferencedParams = project_id.into();
+            let params_byte = to_bytes(&params);
+            ctx.set_parameter(&params_byte);
+
+            // invoke method
+            let result = contract_is_project_referenced(&ctx, &host);
+            claim_eq!(result, Ok(expected));
+        }
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.purge_project_id removes the project id and its scores from all
+    /// users.
+    fn test_contract_purge_project_id() {
+        let admin = AccountAddress([0; 32]);
+        let project_contract_addr = ContractAddress::new(0, 0);
+        let curator = AccountAddress([1; 32]);
+        let validator = AccountAddress([2; 32]);
+        let both = AccountAddress([3; 32]);
+        let unrelated = AccountAddress([4; 32]);
+        let user_state = |curated: Vec<&str>, validated: Vec<&str>| UserState {
+            is_curator: !curated.is_empty(),
+            is_validator: !validated.is_empty(),
+            curated_projects: curated
+                .into_iter()
+                .map(|project_id| ProjectId::from(project_id))
+                .collect(),
+            validated_projects: validated
+                .into_iter()
+                .map(|project_id| (project_id.into(), project_contract_addr))
+                .collect(),
+            ..UserState::default()
+        };
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(curator, user_state(vec!["TEST-PRJ1", "TEST-PRJ2"], vec![]));
+        user.insert(validator, user_state(vec![], vec!["TEST-PRJ1"]));
+        user.insert(
+            both,
+            user_state(vec!["TEST-PRJ1"], vec!["TEST-PRJ1", "TEST-PRJ2"]),
+        );
+        user.insert(unrelated, user_state(vec!["TEST-PRJ2"], vec![]));
+        let mut curation_scores = state_builder.new_map();
+        curation_scores.insert(
+            curator,
+            vec![("TEST-PRJ1".into(), 50), ("TEST-PRJ2".into(), 60)],
+        );
+        curation_scores.insert(both, vec![("TEST-PRJ1".into(), 70)]);
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr,
+            user,
+            curator_list: vec![curator, both, unrelated],
+            validator_list: vec![validator, both],
+            curation_scores,
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params: PurgeProjectIdParams = "TEST-PRJ1".into();
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_purge_project_id(&ctx, &mut host);
+        claim_eq!(result, Ok(3));
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state.user.get(&curator).unwrap(),
+            user_state(vec!["TEST-PRJ2"], vec![])
+        );
+        claim_eq!(
+            actual_state
+                .user
+                .get(&validator)
+                .unwrap()
+                .validated_projects,
+            Vec::new()
+        );
+        claim_eq!(
+            *actual_state.user.get(&both).unwrap(),
+            user_state(vec![], vec!["TEST-PRJ2"])
+        );
+        claim_eq!(
+            *actual_state.user.get(&unrelated).unwrap(),
+            user_state(vec!["TEST-PRJ2"], vec![])
+        );
+        // the scores of the purged project are removed as well
+        claim_eq!(
+            actual_state
+                .curation_scores
+                .get(&curator)
+                .map(|scores| scores.clone()),
+            Some(vec![("TEST-PRJ2".into(), 60)])
+        );
+        claim!(actual_state.curation_scores.get(&both).is_none());
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.view_readiness reports ready only after add_project_contract.
+    fn test_contract_view_readiness() {
+        let admin = AccountAddress([1; 32]);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(AccountAddress([100; 32]));
+        let result = contract_view_readiness(&ctx, &host);
+        claim!(result.is_ok());
+        let response = result.unwrap();
+        claim!(response.admin_set);
+        claim!(!response.project_contract_set);
+        claim!(!response.ready);
+
+        // set the project contract address
+        let params = AddProjectContractParams {
+            project_contract_addr: ContractAddress::new(1, 0),
+        };
+        let params_byte = to_bytes(&params);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        ctx.set_parameter(&params_byte);
+        let result = contract_add_project_contract(&ctx, &mut host);
+        claim!(result.is_ok());
+
+        let result = contract_view_readiness(&ctx, &host);
+        claim!(result.is_ok());
+        let response = result.unwrap();
+        claim!(response.admin_set);
+        claim!(response.project_contract_set);
+        claim!(response.ready);
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.allow_project and disallow_project update the whitelist.
+    fn test_contract_allow_and_disallow_project() {
+        let admin = AccountAddress([0; 32]);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        let params: AllowProjectParams = "TEST-PRJ".into();
+        let params_byte = to_bytes(&params);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        ctx.set_parameter(&params_byte);
+
+        let result = contract_allow_project(&ctx, &mut host);
+        claim!(result.is_ok());
+        claim!(host.state().allowed_projects.contains(&params));
+
+        let result = contract_disallow_project(&ctx, &mut host);
+        claim!(result.is_ok());
+        claim!(host.state().allowed_projects.is_empty());
+
+        // non-admin can not change the whitelist
+        ctx.set_invoker(AccountAddress([100; 32]));
+        let result = contract_allow_project(&ctx, &mut host);
+        claim_eq!(result, Err(Error::InvalidCaller));
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.curate and validate accept only whitelisted project ids
+    /// unless the whitelist is empty.
+    fn test_contract_curate_and_validate_with_allowed_projects() {
+        let admin = AccountAddress([0; 32]);
+        let project_contract_addr = ContractAddress::new(1, 0);
+        let user_addr = AccountAddress([1; 32]);
+        for (allowed, project_id, expected) in [
+            (vec!["TEST-PRJ1"], "TEST-PRJ1", Ok(())),
+            (
+                vec!["TEST-PRJ1"],
+                "TEST-PRJ2",
+                Err(Error::ProjectNotAllowed),
+            ),
+            (vec![], "TEST-PRJ2", Ok(())),
+        ] {
+            // setup state
+            let mut state_builder = TestStateBuilder::new();
+            let mut user = state_builder.new_map();
+            user.insert(
+                user_addr,
+                UserState {
+                    is_curator: true,
+                    is_validator: true,
+                    curated_projects: Vec::new(),
+                    validated_projects: Vec::new(),
+                    ..UserState::default()
+                },
+            );
+            let mut allowed_projects = state_builder.new_set();
+            for allowed_project in allowed {
+                allowed_projects.insert(ProjectId::from(allowed_project));
+            }
+            let state = State {
+                admin: Address::Account(admin),
+                project_contract_addr,
+                user,
+                curator_list: vec![user_addr],
+                validator_list: vec![user_addr],
+                allowed_projects,
+                ..default_test_state(&mut state_builder)
+            };
+            let mut host = TestHost::new(state, state_builder);
+
+            // create parameters
+            let params = CurateParams {
+                addr: user_addr,
+                project_id: project_id.into(),
+            };
+            let params_byte = to_bytes(&params);
+            let mut ctx = TestReceiveContext::empty();
+            ctx.set_sender(Address::Contract(project_contract_addr));
+            ctx.set_parameter(&params_byte);
+
+            // invoke methods
+            let result = contract_curate(&ctx, &mut host);
+            claim_eq!(result, expected);
+            let result = contract_validate(&ctx, &mut host);
+            claim_eq!(result.map(|_| ()), expected);
+        }
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.view_activity_span returns the earliest and latest grant times.
+    fn test_contract_view_activity_span() {
+        let admin = AccountAddress([0; 32]);
+        let user_state = |curator_since: Option<u64>, validator_since: Option<u64>| UserState {
+            is_curator: curator_since.is_some(),
+            is_validator: validator_since.is_some(),
+            curator_since: curator_since.map(Timestamp::from_timestamp_millis),
+            validator_since: validator_since.map(Timestamp::from_timestamp_millis),
+            ..UserState::default()
+        };
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(AccountAddress([100; 32]));
+
+        // no grant times
+        host.state_mut()
+            .user
+            .insert(AccountAddress([1; 32]), user_state(None, None));
+        let result = contract_view_activity_span(&ctx, &host);
+        claim!(result.is_ok());
+        let response = result.unwrap();
+        claim_eq!(response.first_grant, None);
+        claim_eq!(response.last_grant, None);
+
+        host.state_mut()
+            .user
+            .insert(AccountAddress([2; 32]), user_state(Some(20), Some(5)));
+        host.state_mut()
+            .user
+            .insert(AccountAddress([3; 32]), user_state(Some(10), None));
+        host.state_mut()
+            .user
+            .insert(AccountAddress([4; 32]), user_state(None, Some(30)));
+        let result = contract_view_activity_span(&ctx, &host);
+        claim!(result.is_ok());
+        let response = result.unwrap();
+        claim_eq!(
+            response.first_grant,
+            Some(Timestamp::from_timestamp_millis(5))
+        );
+        claim_eq!(
+            response.last_grant,
+            Some(Timestamp::from_timestamp_millis(30))
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.handover updates the admin and the project contract together.
+    fn test_contract_handover() {
+        let admin = AccountAddress([0; 32]);
+        let new_admin = AccountAddress([1; 32]);
+        let new_project_contract = ContractAddress::new(5, 0);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(10));
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut admin_action_counts = state_builder.new_map();
+        admin_action_counts.insert(String::from("handover"), 1);
+        let expected_state = State {
+            admin: Address::Account(new_admin),
+            project_contract_addr: new_project_contract,
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            last_admin_change: Some((
+                Timestamp::from_timestamp_millis(10),
+                Address::Account(admin),
+            )),
+            state_seq: 1,
+            admin_action_counts,
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = HandoverParams {
+            new_admin: Address::Account(new_admin),
+            new_project_contract,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_handover(&ctx, &mut host);
+        claim!(result.is_ok());
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+
+        // the previous admin can not hand over anymore
+        let result = contract_handover(&ctx, &mut host);
+        claim_eq!(result, Err(Error::InvalidCaller));
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.view_users_filtered_paged pages through the users of one role.
+    fn test_contract_view_users_filtered_paged() {
+        let admin = AccountAddress([0; 32]);
+        let curators: Vec<AccountAddress> = (1..=5).map(|i| AccountAddress([i * 2; 32])).collect();
+        let validators: Vec<AccountAddress> =
+            (1..=5).map(|i| AccountAddress([i * 2 + 1; 32])).collect();
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        for curator in curators.iter() {
+            user.insert(
+                *curator,
+                UserState {
+                    is_curator: true,
+                    ..UserState::default()
+                },
+            );
+        }
+        for validator in validators.iter() {
+            user.insert(
+                *validator,
+                UserState {
+                    is_validator: true,
+                    ..UserState::default()
+                },
+            );
+        }
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user,
+            curator_list: curators.clone(),
+            validator_list: validators.clone(),
+            ..default_test_state(&mut state_builder)
+        };
+        let host = TestHost::new(state, state_builder);
+
+        for (start, expected, expected_has_more) in [
+            (0, curators[0..2].to_vec(), true),
+            (2, curators[2..4].to_vec(), true),
+            (4, curators[4..5].to_vec(), false),
+            (6, Vec::new(), false),
+        ] {
+            let mut ctx = TestReceiveContext::empty();
+            ctx.set_invoker(AccountAddress([100; 32]));
+
+            // create parameters
+            let params = ViewUsersFilteredPagedParams {
+                role: RoleFilter::Curator,
+                start,
+                limit: 2,
+                include_deleted: false,
+            };
+            let params_byte = to_bytes(&params);
+            ctx.set_parameter(&params_byte);
+
+            // invoke method
+            let result = contract_view_users_filtered_paged(&ctx, &host);
+            claim!(result.is_ok());
+            let response = result.unwrap();
+            let addrs: Vec<AccountAddress> =
+                response.users.into_iter().map(|(addr, _)| addr).collect();
+            claim_eq!(addrs, expected);
+            claim_eq!(response.has_more, expected_has_more);
+        }
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.remove_curator returns LogError when the event can not be logged.
+    fn test_contract_remove_curator_with_full_log() {
+        let admin = AccountAddress([0; 32]);
+        let curator = AccountAddress([1; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(10));
+        ctx.set_invoker(admin);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(
+            curator,
+            UserState {
+                is_curator: true,
+                is_validator: false,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+                ..UserState::default()
+            },
+        );
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user,
+            curator_list: vec![curator],
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = RemoveCuratorParams {
+            addr: curator,
+            reason: None,
+            force: false,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method with the logger already holding the maximum number of logs
+        let mut logger = TestLogger::init();
+        logger.logs = vec![Vec::new(); 64];
+        let result = contract_remove_curator(&ctx, &mut host, &mut logger);
+        claim_eq!(result, Err(Error::LogError));
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.view_admin_detailed counts the projects of each list member.
+    fn test_contract_view_admin_detailed() {
+        let admin = AccountAddress([0; 32]);
+        let project_contract_addr = ContractAddress::new(0, 0);
+        let curator = AccountAddress([1; 32]);
+        let validator = AccountAddress([2; 32]);
+        let absent = AccountAddress([3; 32]);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(
+            curator,
+            UserState {
+                is_curator: true,
+                is_validator: false,
+                curated_projects: vec!["TEST-PRJ1".into(), "TEST-PRJ2".into()],
+                validated_projects: vec![("TEST-PRJ3".into(), project_contract_addr)],
+                ..UserState::default()
+            },
+        );
+        user.insert(
+            validator,
+            UserState {
+                is_curator: false,
+                is_validator: true,
+                curated_projects: Vec::new(),
+                validated_projects: vec![("TEST-PRJ1".into(), project_contract_addr)],
+                ..UserState::default()
+            },
+        );
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr,
+            user,
+            curator_list: vec![curator, absent],
+            validator_list: vec![validator, absent],
+            ..default_test_state(&mut state_builder)
+        };
+        let host = TestHost::new(state, state_builder);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        let result = contract_view_admin_detailed(&ctx, &host);
+        claim!(result.is_ok());
+        let response = result.unwrap();
+        claim_eq!(response.curators, vec![(curator, 2), (absent, 0)]);
+        claim_eq!(response.validators, vec![(validator, 1), (absent, 0)]);
+
+        // non-admin can not view it
+        ctx.set_invoker(AccountAddress([100; 32]));
+        let result = contract_view_admin_detailed(&ctx, &host);
+        claim_eq!(result.err(), Some(Error::InvalidCaller));
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.switch_role moves a user between the curator and validator roles.
+    fn test_contract_switch_role() {
+        let admin = AccountAddress([0; 32]);
+        let user_addr = AccountAddress([1; 32]);
+        let now = Timestamp::from_timestamp_millis(10);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(
+            user_addr,
+            UserState {
+                is_curator: false,
+                is_validator: true,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+                validator_since: Some(Timestamp::from_timestamp_millis(1)),
+                ..UserState::default()
+            },
+        );
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user,
+            curator_list: Vec::new(),
+            validator_list: vec![user_addr],
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+        let mut logger = TestLogger::init();
+
+        for (to_curator, expected) in [
+            (true, Ok(())),
+            (true, Err(Error::InvalidArgument)),
+            (false, Ok(())),
+        ] {
+            let params_byte = to_bytes(&SwitchRoleParams {
+                addr: user_addr,
+                to_curator,
+                force: false,
+            });
+            let mut ctx = TestReceiveContext::empty();
+            ctx.set_invoker(admin);
+            ctx.set_metadata_slot_time(now);
+            ctx.set_parameter(&params_byte);
+
+            // invoke method
+            let result = contract_switch_role(&ctx, &mut host, &mut logger);
+            claim_eq!(result, expected);
+            let actual_state = host.state();
+            let user_state = actual_state.user.get(&user_addr).unwrap();
+            claim_eq!(user_state.is_curator, to_curator);
+            claim_eq!(user_state.is_validator, !to_curator);
+            claim_eq!(user_state.curator_since, to_curator.then_some(now));
+            claim_eq!(user_state.validator_since, (!to_curator).then_some(now));
+            claim_eq!(user_state.last_role_change, Some(now));
+            if to_curator {
+                claim_eq!(actual_state.curator_list, vec![user_addr]);
+                claim!(actual_state.validator_list.is_empty());
+            } else {
+                claim!(actual_state.curator_list.is_empty());
+                claim_eq!(actual_state.validator_list, vec![user_addr]);
+            }
+        }
+        claim_eq!(
+            logger.logs,
+            vec![
+                to_bytes(&Event::RemoveValidator(RemoveRoleEvent {
+                    event_seq: 0,
+                    addr: user_addr,
+                    reason: None,
+                })),
+                to_bytes(&Event::RemoveCurator(RemoveRoleEvent {
+                    event_seq: 1,
+                    addr: user_addr,
+                    reason: None,
+                })),
+            ]
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.users_created_since counts the users created at or after the cutoff.
+    fn test_contract_users_created_since() {
+        let admin = AccountAddress([0; 32]);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+        // a user inserted before the creation time was recorded
+        host.state_mut()
+            .user
+            .insert(AccountAddress([1; 32]), UserState::default());
+
+        // add users at different times
+        for (i, time) in [(2u8, 10u64), (3, 20), (4, 30)] {
+            let params_byte = to_bytes(&RegisterUserParams {
+                addr: AccountAddress([i; 32]),
+            });
+            let mut ctx = TestReceiveContext::empty();
+            ctx.set_invoker(admin);
+            ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(time));
+            ctx.set_parameter(&params_byte);
+            let result = contract_register_user(&ctx, &mut host);
+            claim!(result.is_ok());
+        }
+
+        for (since, expected) in [(0, 3), (20, 2), (21, 1), (31, 0)] {
+            let params_byte = to_bytes(&Timestamp::from_timestamp_millis(since));
+            let mut ctx = TestReceiveContext::empty();
+            ctx.set_invoker(AccountAddress([100; 32]));
+            ctx.set_parameter(&params_byte);
+
+            // invoke method
+            let result = contract_users_created_since(&ctx, &host);
+            claim_eq!(result, Ok(expected));
+        }
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.import_curators imports curators with their deduplicated projects.
+    fn test_contract_import_curators() {
+        let admin = AccountAddress([0; 32]);
+        let existing_user = AccountAddress([1; 32]);
+        let new_curator = AccountAddress([2; 32]);
+        let now = Timestamp::from_timestamp_millis(10);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        ctx.set_metadata_slot_time(now);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(
+            existing_user,
+            UserState {
+                is_curator: false,
+                is_validator: true,
+                curated_projects: vec!["TEST-PRJ1".into()],
+                validated_projects: Vec::new(),
+                ..UserState::default()
+            },
+        );
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user,
+            curator_list: Vec::new(),
+            validator_list: vec![existing_user],
+            ..default_test_state(&mut state_builder)
+        };
+        let mut expected_user = state_builder.new_map();
+        expected_user.insert(
+            existing_user,
+            UserState {
+                is_curator: true,
+                is_validator: true,
+                curated_projects: vec!["TEST-PRJ1".into(), "TEST-PRJ2".into()],
+                validated_projects: Vec::new(),
+                curator_since: Some(now),
+                was_ever_curator: true,
+                last_role_change: Some(now),
+                ..UserState::default()
+            },
+        );
+        expected_user.insert(
+            new_curator,
+            UserState {
+                is_curator: true,
+                is_validator: false,
+                curated_projects: vec!["TEST-PRJ3".into(), "TEST-PRJ4".into()],
+                validated_projects: Vec::new(),
+                curator_since: Some(now),
+                created_at: Some(now),
+                was_ever_curator: true,
+                last_role_change: Some(now),
+                ..UserState::default()
+            },
+        );
+        let mut admin_action_counts = state_builder.new_map();
+        admin_action_counts.insert(String::from("import_curators"), 1);
+        let expected_state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: expected_user,
+            curator_list: vec![existing_user, new_curator],
+            validator_list: vec![existing_user],
+            cached_counts: CachedCounts {
+                user_count: 1,
+                curator_count: 2,
+                validator_count: 1,
+            },
+            state_seq: 1,
+            admin_action_counts,
+            event_seq: 1,
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = ImportCuratorsParams {
+            curators: vec![
+                (existing_user, vec!["TEST-PRJ1".into(), "TEST-PRJ2".into()]),
+                (
+                    new_curator,
+                    vec!["TEST-PRJ3".into(), "TEST-PRJ4".into(), "TEST-PRJ3".into()],
+                ),
+            ],
+            skip_admin: None,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let mut logger = TestLogger::init();
+        let result = contract_import_curators(&ctx, &mut host, &mut logger);
+        claim_eq!(result, Ok(false));
+        claim_eq!(
+            logger.logs,
+            vec![to_bytes(&Event::BatchRoleChange(BatchRoleChangeEvent {
+                event_seq: 0,
+                curators_added: 2,
+                curators_removed: 0,
+                validators_added: 0,
+                validators_removed: 0,
+            }))]
+        );
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+
+        // too large batch is rejected
+        let params = ImportCuratorsParams {
+            curators: (0..=MAX_IMPORT_CURATORS)
+                .map(|i| (AccountAddress([i as u8; 32]), Vec::new()))
+                .collect(),
+            skip_admin: None,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = contract_import_curators(&ctx, &mut host, &mut logger);
+        claim_eq!(result, Err(Error::InvalidArgument));
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.was_ever_curator and was_ever_validator stay true after the role is removed.
+    fn test_contract_was_ever_curator_and_validator() {
+        let admin = AccountAddress([0; 32]);
+        let user_addr = AccountAddress([1; 32]);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+        let params_byte = to_bytes(&AddrParams { addr: user_addr });
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(10));
+        ctx.set_parameter(&params_byte);
+
+        // never granted
+        claim_eq!(contract_was_ever_curator(&ctx, &host), Ok(false));
+        claim_eq!(contract_was_ever_validator(&ctx, &host), Ok(false));
+
+        for _ in 0..2 {
+            // grant the curator role
+            claim!(contract_add_curator(&ctx, &mut host).is_ok());
+            claim_eq!(contract_was_ever_curator(&ctx, &host), Ok(true));
+            claim_eq!(contract_was_ever_validator(&ctx, &host), Ok(false));
+
+            // revoke the curator role
+            let remove_params_byte = to_bytes(&RemoveCuratorParams {
+                addr: user_addr,
+                reason: None,
+                force: false,
+            });
+            let mut remove_ctx = TestReceiveContext::empty();
+            remove_ctx.set_invoker(admin);
+            remove_ctx.set_parameter(&remove_params_byte);
+            let mut logger = TestLogger::init();
+            claim!(contract_remove_curator(&remove_ctx, &mut host, &mut logger).is_ok());
+            claim!(!host.state().user.get(&user_addr).unwrap().is_curator);
+            claim_eq!(contract_was_ever_curator(&ctx, &host), Ok(true));
+        }
+
+        // grant and revoke the validator role
+        claim!(contract_add_validator(&ctx, &mut host).is_ok());
+        let remove_params_byte = to_bytes(&RemoveValidatorParams {
+            addr: user_addr,
+            reason: None,
+            force: false,
+        });
+        let mut remove_ctx = TestReceiveContext::empty();
+        remove_ctx.set_invoker(admin);
+        remove_ctx.set_parameter(&remove_params_byte);
+        let mut logger = TestLogger::init();
+        claim!(contract_remove_validator(&remove_ctx, &mut host, &mut logger).is_ok());
+        claim_eq!(contract_was_ever_curator(&ctx, &host), Ok(true));
+        claim_eq!(contract_was_ever_validator(&ctx, &host), Ok(true));
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.audit_state reports each broken invariant.
+    fn test_contract_audit_state() {
+        let admin = AccountAddress([0; 32]);
+        let curator = AccountAddress([1; 32]);
+        let validator = AccountAddress([2; 32]);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(
+            curator,
+            UserState {
+                is_curator: true,
+                is_validator: false,
+                curated_projects: vec!["TEST-PRJ1".into()],
+                validated_projects: Vec::new(),
+                ..UserState::default()
+            },
+        );
+        user.insert(
+            validator,
+            UserState {
+                is_curator: false,
+                is_validator: true,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+                ..UserState::default()
+            },
+        );
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(1, 0),
+            user,
+            curator_list: vec![curator],
+            validator_list: vec![validator],
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(AccountAddress([100; 32]));
+
+        // consistent state
+        let result = contract_audit_state(&ctx, &host);
+        claim!(result.is_ok());
+        let response = result.unwrap();
+        claim!(response.lists_match_map);
+        claim!(response.no_duplicate_list_entries);
+        claim!(response.no_empty_project_ids);
+        claim!(response.project_contract_configured);
+
+        // break the invariants one by one
+        host.state_mut().validator_list.push(validator);
+        let response = contract_audit_state(&ctx, &host).unwrap();
+        claim!(response.lists_match_map);
+        claim!(!response.no_duplicate_list_entries);
+
+        host.state_mut().curator_list.push(validator);
+        let response = contract_audit_state(&ctx, &host).unwrap();
+        claim!(!response.lists_match_map);
+        claim!(response.no_empty_project_ids);
+
+        host.state_mut()
+            .user
+            .entry(curator)
+            .and_modify(|user_state| user_state.curated_projects.push(ProjectId::new()));
+        host.state_mut().project_contract_addr = ContractAddress::new(0, 0);
+        let response = contract_audit_state(&ctx, &host).unwrap();
+        claim!(!response.no_empty_project_ids);
+        claim!(!response.project_contract_configured);
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.validate_batch_users validates for validators and returns the skipped users.
+    fn test_contract_validate_batch_users() {
+        let admin = AccountAddress([0; 32]);
+        let project_contract_addr = ContractAddress::new(1, 0);
+        let validator1 = AccountAddress([1; 32]);
+        let validator2 = AccountAddress([2; 32]);
+        let curator = AccountAddress([3; 32]);
+        let unknown = AccountAddress([4; 32]);
+        let user_state = |is_curator: bool, is_validator: bool| UserState {
+            is_curator,
+            is_validator,
+            ..UserState::default()
+        };
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_sender(Address::Contract(project_contract_addr));
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(validator1, user_state(false, true));
+        user.insert(validator2, user_state(false, true));
+        user.insert(curator, user_state(true, false));
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr,
+            user,
+            curator_list: vec![curator],
+            validator_list: vec![validator1, validator2],
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // create parameters
+        let params = ValidateBatchUsersParams {
+            project_id: "TEST-PRJ".into(),
+            addrs: vec![validator1, curator, validator2, unknown],
+            strict: false,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_validate_batch_users(&ctx, &mut host);
+        claim_eq!(result, Ok(vec![curator, unknown]));
+        let actual_state = host.state();
+        for validator in [validator1, validator2] {
+            claim_eq!(
+                actual_state
+                    .user
+                    .get(&validator)
+                    .unwrap()
+                    .validated_projects,
+                vec![("TEST-PRJ".into(), project_contract_addr)]
+            );
+        }
+        claim!(actual_state
+            .user
+            .get(&curator)
+            .unwrap()
+            .validated_projects
+            .is_empty());
+        claim!(actual_state.user.get(&unknown).is_none());
+
+        // only the associated project contract can call it
+        ctx.set_sender(Address::Account(admin));
+        let result = contract_validate_batch_users(&ctx, &mut host);
+        claim_eq!(result, Err(Error::InvalidCaller));
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.view_user_role maps the role flags to the enum.
+    fn test_contract_view_user_role() {
+        let admin = AccountAddress([0; 32]);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        for (i, is_curator, is_validator) in [
+            (1, false, false),
+            (2, true, false),
+            (3, false, true),
+            (4, true, true),
+        ] {
+            user.insert(
+                AccountAddress([i; 32]),
+                UserState {
+                    is_curator,
+                    is_validator,
+                    ..UserState::default()
+                },
+            );
+        }
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user,
+            curator_list: vec![AccountAddress([2; 32]), AccountAddress([4; 32])],
+            validator_list: vec![AccountAddress([3; 32]), AccountAddress([4; 32])],
+            ..default_test_state(&mut state_builder)
+        };
+        let host = TestHost::new(state, state_builder);
+
+        for (i, expected) in [
+            (1, UserRole::None),
+            (2, UserRole::Curator),
+            (3, UserRole::Validator),
+            (4, UserRole::Both),
+            (5, UserRole::None),
+        ] {
+            let params_byte = to_bytes(&ViewUserRoleParams {
+                addr: AccountAddress([i; 32]),
+            });
+            let mut ctx = TestReceiveContext::empty();
+            ctx.set_invoker(AccountAddress([100; 32]));
+            ctx.set_parameter(&params_byte);
+
+            // invoke method
+            let result = contract_view_user_role(&ctx, &host);
+            claim_eq!(result, Ok(expected));
+        }
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.set_user_roles_batch skips the admin account unless skip_admin is false.
+    fn test_contract_set_user_roles_batch_with_admin_entry() {
+        let admin = AccountAddress([0; 32]);
+        let other = AccountAddress([1; 32]);
+        for (skip_admin, expected_changed_count, expected_admin_skipped) in [
+            (None, 1, true),
+            (Some(true), 1, true),
+            (Some(false), 2, false),
+        ] {
+            let mut ctx = TestReceiveContext::empty();
+            ctx.set_invoker(admin);
+            ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(10));
+            // setup state
+            let mut state_builder = TestStateBuilder::new();
+            let mut user = state_builder.new_map();
+            user.insert(
+                admin,
+                UserState {
+                    is_curator: true,
+                    is_validator: false,
+                    curated_projects: Vec::new(),
+                    validated_projects: Vec::new(),
+                    ..UserState::default()
+                },
+            );
+            let state = State {
+                admin: Address::Account(admin),
+                project_contract_addr: ContractAddress::new(0, 0),
+                user,
+                curator_list: vec![admin],
+                validator_list: Vec::new(),
+                ..default_test_state(&mut state_builder)
+            };
+            let mut host = TestHost::new(state, state_builder);
+
+            // create parameters
+            let params = SetUserRolesBatchParams {
+                users: [admin, other]
+                    .into_iter()
+                    .map(|addr| UserRolesParams {
+                        addr,
+                        is_curator: false,
+                        is_validator: true,
+                    })
+                    .collect(),
+                skip_admin,
+            };
+            let params_byte = to_bytes(&params);
+            ctx.set_parameter(&params_byte);
+
+            // invoke method
+            let mut logger = TestLogger::init();
+            let result = contract_set_user_roles_batch(&ctx, &mut host, &mut logger);
+            claim!(result.is_ok());
+            let response = result.unwrap();
+            claim_eq!(response.changed_count, expected_changed_count);
+            claim_eq!(response.admin_skipped, expected_admin_skipped);
+            claim_eq!(
+                host.state().user.get(&admin).unwrap().is_curator,
+                expected_admin_skipped
+            );
+        }
+    }
+
+    #[concordium_test]
+    /// Test that the state sequence number increments on mutations and changed_since reports staleness.
+    fn test_contract_changed_since() {
+        let admin = AccountAddress([0; 32]);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        let params_byte = to_bytes(&0u64);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(AccountAddress([100; 32]));
+        ctx.set_parameter(&params_byte);
+        let result = contract_changed_since(&ctx, &host);
+        claim_eq!(
+            result,
+            Ok(ChangedSinceResponse {
+                stale: false,
+                state_seq: 0,
+            })
+        );
+
+        for users_view_public in [false, true] {
+            let params_byte = to_bytes(&SetUsersViewPublicParams { users_view_public });
+            let mut ctx = TestReceiveContext::empty();
+            ctx.set_invoker(admin);
+            ctx.set_parameter(&params_byte);
+            let result = contract_set_users_view_public(&ctx, &mut host);
+            claim!(result.is_ok());
+        }
+        claim_eq!(host.state().state_seq, 2);
+
+        // a failed call does not bump the sequence number
+        let params_byte = to_bytes(&SetUsersViewPublicParams {
+            users_view_public: true,
+        });
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(AccountAddress([100; 32]));
+        ctx.set_parameter(&params_byte);
+        let result = contract_set_users_view_public(&ctx, &mut host);
+        claim_eq!(result, Err(Error::InvalidCaller));
+        claim_eq!(host.state().state_seq, 2);
+
+        // a call that leaves the state unchanged does not bump the sequence number
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        ctx.set_parameter(&params_byte);
+        let result = contract_set_users_view_public(&ctx, &mut host);
+        claim!(result.is_ok());
+        claim_eq!(host.state().state_seq, 2);
+
+        for (last_seen, stale) in [(0u64, true), (1, true), (2, false)] {
+            let params_byte = to_bytes(&last_seen);
+            let mut ctx = TestReceiveContext::empty();
+            ctx.set_invoker(AccountAddress([100; 32]));
+            ctx.set_parameter(&params_byte);
+            let result = contract_changed_since(&ctx, &host);
+            claim_eq!(
+                result,
+                Ok(ChangedSinceResponse {
+                    stale,
+                    state_seq: 2,
+                })
+            );
+        }
+    }
+
+    #[concordium_test]
+    /// Test that the calls leaving the state unchanged do not bump `state_seq`.
+    fn test_contract_no_op_calls_keep_state_seq() {
+        let admin = AccountAddress([0; 32]);
+        let user = AccountAddress([1; 32]);
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(10));
+
+        let params_byte = to_bytes(&RegisterUserParams { addr: user });
+        ctx.set_parameter(&params_byte);
+        claim!(contract_register_user(&ctx, &mut host).is_ok());
+        claim_eq!(host.state().state_seq, 1);
+
+        // registering an existing user
+        claim!(contract_register_user(&ctx, &mut host).is_ok());
+        claim_eq!(host.state().state_seq, 1);
+
+        // dismissing an application that does not exist
+        let params_byte = to_bytes(&DismissApplicationParams {
+            addr: user,
+            role: Role::Curator,
+        });
+        ctx.set_parameter(&params_byte);
+        claim!(contract_dismiss_application(&ctx, &mut host).is_ok());
+        claim_eq!(host.state().state_seq, 1);
+
+        // adding a curator twice
+        let params_byte = to_bytes(&AddCuratorParams { addr: user });
+        ctx.set_parameter(&params_byte);
+        claim!(contract_add_curator(&ctx, &mut host).is_ok());
+        claim_eq!(host.state().state_seq, 2);
+        claim!(contract_add_curator(&ctx, &mut host).is_ok());
+        claim_eq!(host.state().state_seq, 2);
+
+        // setting a flag to its current value
+        let params_byte = to_bytes(&SetEventsEnabledParams {
+            events_enabled: true,
+        });
+        ctx.set_parameter(&params_byte);
+        claim!(contract_set_events_enabled(&ctx, &mut host).is_ok());
+        claim_eq!(host.state().state_seq, 2);
+
+        // removing an observer that does not exist
+        let params_byte = to_bytes(&RemoveObserverParams { addr: user });
+        ctx.set_parameter(&params_byte);
+        claim!(contract_remove_observer(&ctx, &mut host).is_ok());
+        claim_eq!(host.state().state_seq, 2);
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.reset_governance_flags resets all governance flags and is restricted to the admin.
+    fn test_contract_reset_governance_flags() {
+        let admin = AccountAddress([0; 32]);
+        let suspicious = AccountAddress([1; 32]);
+
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(1, 2),
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            config_locked: true,
+            curators_frozen: true,
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        // invoke method by non-admin account
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(suspicious);
+        let result = contract_reset_governance_flags(&ctx, &mut host);
+        claim_eq!(result, Err(Error::InvalidCaller));
+        claim!(host.state().config_locked);
+        claim!(host.state().curators_frozen);
+
+        // invoke method by admin account
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        let result = contract_reset_governance_flags(&ctx, &mut host);
+        claim!(result.is_ok());
+        claim!(!host.state().config_locked);
+        claim!(!host.state().curators_frozen);
+        claim_eq!(host.state().state_seq, 1);
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.view_user_project_gaps returns the project ids only curated or only validated.
+    fn test_contract_view_user_project_gaps() {
+        let user_addr = AccountAddress([1; 32]);
+        let not_existing_user = AccountAddress([2; 32]);
+        let project_contract_addr = ContractAddress::new(1, 2);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(
+            user_addr,
+            UserState {
+                is_curator: true,
+                is_validator: true,
+                curated_projects: vec!["TEST-PRJ1".into(), "TEST-PRJ2".into()],
+                validated_projects: vec![
+                    ("TEST-PRJ2".into(), project_contract_addr),
+                    ("TEST-PRJ3".into(), project_contract_addr),
+                ],
+                ..UserState::default()
+            },
+        );
+        let state = State {
+            admin: Address::Account(AccountAddress([0; 32])),
+            project_contract_addr,
+            user,
+            curator_list: vec![user_addr],
+            validator_list: vec![user_addr],
+            ..default_test_state(&mut state_builder)
+        };
+        let host = TestHost::new(state, state_builder);
+
+        for (addr, expected_curated, expected_validated) in [
+            (
+                user_addr,
+                vec![ProjectId::from("TEST-PRJ1")],
+                vec![ProjectId::from("TEST-PRJ3")],
+            ),
+            (not_existing_user, Vec::new(), Vec::new()),
+        ] {
+            let params_byte = to_bytes(&ViewUserProjectGapsParams { addr });
+            let mut ctx = TestReceiveContext::empty();
+            ctx.set_invoker(AccountAddress([100; 32]));
+            ctx.set_parameter(&params_byte);
+
+            // invoke method
+            let result = contract_view_user_project_gaps(&ctx, &host);
+            claim_eq!(
+                result,
+                Ok(ViewUserProjectGapsResponse {
+                    curated_not_validated: expected_curated,
+                    validated_not_curated: expected_validated,
+                })
+            );
+        }
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.filter_known_users returns only the known accounts in the input order.
+    fn test_contract_filter_known_users() {
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        for i in [3, 7] {
+            user.insert(AccountAddress([i; 32]), UserState::default());
+        }
+        let state = State {
+            admin: Address::Account(AccountAddress([0; 32])),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user,
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let host = TestHost::new(state, state_builder);
+
+        let params: FilterKnownUsersParams = [9, 1, 7, 2, 4, 3, 5, 6]
+            .into_iter()
+            .map(|i| AccountAddress([i; 32]))
+            .collect();
+        let params_byte = to_bytes(&params);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(AccountAddress([100; 32]));
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_filter_known_users(&ctx, &host);
+        claim_eq!(
+            result,
+            Ok(vec![AccountAddress([7; 32]), AccountAddress([3; 32])])
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.curate rejects an unknown user unless curate_auto_create is set.
+    fn test_contract_curate_auto_create() {
+        let admin = AccountAddress([0; 32]);
+        let project_contract_addr = ContractAddress::new(1, 2);
+        let new_user = AccountAddress([1; 32]);
+
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr,
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+
+        let params_byte = to_bytes(&CurateParams {
+            addr: new_user,
+            project_id: "TEST-PRJ1".into(),
+        });
+
+        // strict mode
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_sender(Address::Contract(project_contract_addr));
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(10));
+        ctx.set_parameter(&params_byte);
+        let result = contract_curate(&ctx, &mut host);
+        claim_eq!(result, Err(Error::UserNotFound));
+        claim!(host.state().user.get(&new_user).is_none());
+        claim!(host.state().curator_list.is_empty());
+
+        // only the admin can set curate_auto_create
+        let set_params_byte = to_bytes(&SetCurateAutoCreateParams {
+            curate_auto_create: true,
+        });
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(new_user);
+        ctx.set_parameter(&set_params_byte);
+        let result = contract_set_curate_auto_create(&ctx, &mut host);
+        claim_eq!(result, Err(Error::InvalidCaller));
+        claim!(!host.state().curate_auto_create);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        ctx.set_parameter(&set_params_byte);
+        let result = contract_set_curate_auto_create(&ctx, &mut host);
+        claim!(result.is_ok());
+        claim!(host.state().curate_auto_create);
+
+        // auto-create mode
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_sender(Address::Contract(project_contract_addr));
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(10));
+        ctx.set_parameter(&params_byte);
+        let result = contract_curate(&ctx, &mut host);
+        claim!(result.is_ok());
+        let user_state = host
+            .state()
+            .user
+            .get(&new_user)
+            .map(|user_state| user_state.clone());
+        claim_eq!(
+            user_state,
+            Some(UserState {
+                is_curator: true,
+                curated_projects: vec!["TEST-PRJ1".into()],
+                curator_since: Some(Timestamp::from_timestamp_millis(10)),
+                created_at: Some(Timestamp::from_timestamp_millis(10)),
+                was_ever_curator: true,
+                last_role_change: Some(Timestamp::from_timestamp_millis(10)),
+                ..UserState::default()
+            })
+        );
+        claim_eq!(host.state().curator_list, vec![new_user]);
+        claim!(host.state().validator_list.is_empty());
+        claim_eq!(host.state().state_seq, 2);
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.view_role_histogram counts the users by role combination.
+    fn test_contract_view_role_histogram() {
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        for (i, is_curator, is_validator) in [
+            (1, false, false),
+            (2, true, false),
+            (3, true, false),
+            (4, false, true),
+            (5, true, true),
+            (6, true, true),
+            (7, true, true),
+        ] {
+            user.insert(
+                AccountAddress([i; 32]),
+                UserState {
+                    is_curator,
+                    is_validator,
+                    ..UserState::default()
+                },
+            );
+        }
+        let state = State {
+            admin: Address::Account(AccountAddress([0; 32])),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user,
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let host = TestHost::new(state, state_builder);
 
-    fn ne(&self, other: &Self) -> bool {
-        !self.eq(other)
-    }
-}
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(AccountAddress([100; 32]));
 
-/// implements Debug for UserState inside test functions.
-/// this implementation will be build only when `concordium-std/wasm-test` feature is active.
-/// (e.g. when launched by `cargo concordium test`)
-#[concordium_cfg_test]
-impl Debug for UserState {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "is_curator: {}, is_validator: {}, curated_projects: {:?}, validated_projects: {:?}",
-            self.is_curator, self.is_validator, self.curated_projects, self.validated_projects
-        )
+        // invoke method
+        let result = contract_view_role_histogram(&ctx, &host);
+        claim_eq!(
+            result,
+            Ok(ViewRoleHistogramResponse {
+                none: 1,
+                curator_only: 2,
+                validator_only: 1,
+                both: 3,
+            })
+        );
     }
-}
 
-/// implements PartialEq for `claim_eq` inside test functions.
-/// this implementation will be build only when `concordium-std/wasm-test` feature is active.
-/// (e.g. when launched by `cargo concordium test`)
-#[concordium_cfg_test]
-impl PartialEq for UserState {
-    fn eq(&self, other: &Self) -> bool {
-        if self.is_curator != other.is_curator {
-            return false;
-        }
-        if self.is_validator != other.is_validator {
-            return false;
-        }
-        if self.curated_projects != other.curated_projects {
-            return false;
-        }
-        if self.validated_projects != other.validated_projects {
-            return false;
+    #[concordium_test]
+    /// Test that overlay-users.curate_bulk curates the valid pairs and returns the failed ones.
+    fn test_contract_curate_bulk() {
+        let project_contract_addr = ContractAddress::new(1, 2);
+        let curator1 = AccountAddress([1; 32]);
+        let curator2 = AccountAddress([2; 32]);
+        let validator = AccountAddress([3; 32]);
+        let not_existing_user = AccountAddress([4; 32]);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        for addr in [curator1, curator2] {
+            user.insert(
+                addr,
+                UserState {
+                    is_curator: true,
+                    curated_projects: vec!["TEST-PRJ1".into()],
+                    ..UserState::default()
+                },
+            );
         }
-        true
-    }
+        user.insert(
+            validator,
+            UserState {
+                is_validator: true,
+                ..UserState::default()
+            },
+        );
+        let state = State {
+            admin: Address::Account(AccountAddress([0; 32])),
+            project_contract_addr,
+            user,
+            curator_list: vec![curator1, curator2],
+            validator_list: vec![validator],
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
 
-    fn ne(&self, other: &Self) -> bool {
-        !self.eq(other)
+        let params = CurateBulkParams {
+            pairs: vec![
+                (curator1, "TEST-PRJ2".into()),
+                (validator, "TEST-PRJ2".into()),
+                (curator2, "TEST-PRJ1".into()),
+                (not_existing_user, "TEST-PRJ3".into()),
+                (curator2, "TEST-PRJ3".into()),
+            ],
+            strict: false,
+        };
+        let params_byte = to_bytes(&params);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_sender(Address::Contract(project_contract_addr));
+        ctx.set_parameter(&params_byte);
+
+        // invoke method
+        let result = contract_curate_bulk(&ctx, &mut host);
+        claim_eq!(
+            result,
+            Ok(vec![
+                (validator, ProjectId::from("TEST-PRJ2")),
+                (not_existing_user, ProjectId::from("TEST-PRJ3")),
+            ])
+        );
+        let curated = |addr| {
+            host.state().user.get(&addr).map(|user_state| {
+                user_state
+                    .curated_projects
+                    .iter()
+                    .map(|(project_id, _)| project_id.clone())
+                    .collect::<Vec<_>>()
+            })
+        };
+        claim_eq!(
+            curated(curator1),
+            Some(vec![
+                ProjectId::from("TEST-PRJ1"),
+                ProjectId::from("TEST-PRJ2")
+            ])
+        );
+        claim_eq!(
+            curated(curator2),
+            Some(vec![
+                ProjectId::from("TEST-PRJ1"),
+                ProjectId::from("TEST-PRJ3")
+            ])
+        );
+        claim_eq!(curated(validator), Some(Vec::new()));
+        claim!(host.state().user.get(&not_existing_user).is_none());
+
+        // too many pairs
+        let curator1_projects = host
+            .state()
+            .user
+            .get(&curator1)
+            .map(|user_state| user_state.curated_projects.clone());
+        let params = CurateBulkParams {
+            pairs: (0..=MAX_CURATE_BULK)
+                .map(|_| (curator1, ProjectId::from("TEST-PRJ4")))
+                .collect(),
+            strict: false,
+        };
+        let params_byte = to_bytes(&params);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_sender(Address::Contract(project_contract_addr));
+        ctx.set_parameter(&params_byte);
+        let result = contract_curate_bulk(&ctx, &mut host);
+        claim_eq!(result, Err(Error::InvalidArgument));
+        claim_eq!(
+            host.state()
+                .user
+                .get(&curator1)
+                .map(|user_state| user_state.curated_projects.clone()),
+            curator1_projects
+        );
+
+        // only the overlay-projects contract can call this function
+        let params = CurateBulkParams {
+            pairs: vec![(curator1, "TEST-PRJ4".into())],
+            strict: false,
+        };
+        let params_byte = to_bytes(&params);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_sender(Address::Account(curator1));
+        ctx.set_parameter(&params_byte);
+        let result = contract_curate_bulk(&ctx, &mut host);
+        claim_eq!(result, Err(Error::InvalidCaller));
     }
-}
 
-#[concordium_cfg_test]
-mod tests {
-    use super::*;
-    use concordium_std::hashes::HashBytes;
-    use test_infrastructure::*;
+    #[concordium_test]
+    /// Test that overlay-users.shared_projects returns the project ids shared by two users.
+    fn test_contract_shared_projects() {
+        let project_contract_addr = ContractAddress::new(1, 2);
+        let curator = AccountAddress([1; 32]);
+        let validator = AccountAddress([2; 32]);
+        let other_curator = AccountAddress([3; 32]);
+        let not_existing_user = AccountAddress([4; 32]);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(
+            curator,
+            UserState {
+                is_curator: true,
+                curated_projects: vec!["TEST-PRJ3".into(), "TEST-PRJ1".into(), "TEST-PRJ2".into()],
+                ..UserState::default()
+            },
+        );
+        user.insert(
+            validator,
+            UserState {
+                is_curator: true,
+                is_validator: true,
+                curated_projects: vec!["TEST-PRJ3".into()],
+                validated_projects: vec![
+                    ("TEST-PRJ1".into(), project_contract_addr),
+                    ("TEST-PRJ4".into(), project_contract_addr),
+                ],
+                ..UserState::default()
+            },
+        );
+        user.insert(
+            other_curator,
+            UserState {
+                is_curator: true,
+                curated_projects: vec!["TEST-PRJ5".into()],
+                ..UserState::default()
+            },
+        );
+        let state = State {
+            admin: Address::Account(AccountAddress([0; 32])),
+            project_contract_addr,
+            user,
+            curator_list: vec![curator, validator, other_curator],
+            validator_list: vec![validator],
+            ..default_test_state(&mut state_builder)
+        };
+        let host = TestHost::new(state, state_builder);
+
+        for (a, b, expected) in [
+            (
+                curator,
+                validator,
+                vec![ProjectId::from("TEST-PRJ1"), ProjectId::from("TEST-PRJ3")],
+            ),
+            (curator, other_curator, Vec::new()),
+            (curator, not_existing_user, Vec::new()),
+        ] {
+            let params_byte = to_bytes(&SharedProjectsParams { a, b });
+            let mut ctx = TestReceiveContext::empty();
+            ctx.set_invoker(AccountAddress([100; 32]));
+            ctx.set_parameter(&params_byte);
+
+            // invoke method
+            let result = contract_shared_projects(&ctx, &host);
+            claim_eq!(result, Ok(expected));
+        }
+    }
 
     #[concordium_test]
-    /// Test that init succeeds.
-    fn test_init() {
-        // invoker will be an admin
-        let invoker = AccountAddress([0; 32]);
-        let mut ctx = TestInitContext::empty();
-        ctx.set_init_origin(invoker);
+    /// Test that the role changes of a user are rejected within the role cooldown.
+    fn test_contract_role_cooldown() {
+        let admin = AccountAddress([0; 32]);
+        let user_addr = AccountAddress([1; 32]);
 
         let mut state_builder = TestStateBuilder::new();
-
-        let expected_state = State {
-            admin: invoker,
+        let state = State {
+            admin: Address::Account(admin),
             project_contract_addr: ContractAddress::new(0, 0),
             user: state_builder.new_map(),
             curator_list: Vec::new(),
             validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
         };
+        let mut host = TestHost::new(state, state_builder);
+        let mut logger = TestLogger::init();
 
-        // execute init
-        let result = contract_init(&ctx, &mut state_builder);
+        // only the admin can set the cooldown
+        let params_byte = to_bytes(&SetRoleCooldownParams {
+            role_cooldown: Duration::from_millis(100),
+        });
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(user_addr);
+        ctx.set_parameter(&params_byte);
+        let result = contract_set_role_cooldown(&ctx, &mut host);
+        claim_eq!(result, Err(Error::InvalidCaller));
+        claim_eq!(host.state().role_cooldown, Duration::from_millis(0));
 
-        // check init result
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        ctx.set_parameter(&params_byte);
+        let result = contract_set_role_cooldown(&ctx, &mut host);
         claim!(result.is_ok());
-        let actual_state = result.unwrap();
+        claim_eq!(host.state().role_cooldown, Duration::from_millis(100));
+
+        let add_params_byte = to_bytes(&AddrParams { addr: user_addr });
+        let remove_params_byte = to_bytes(&RemoveCuratorParams {
+            addr: user_addr,
+            reason: None,
+            force: false,
+        });
+
+        // the first role change is not restricted
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(10));
+        ctx.set_parameter(&add_params_byte);
+        let result = contract_add_curator(&ctx, &mut host);
+        claim!(result.is_ok());
+
+        // within the cooldown
+        for (slot_time, expected) in [
+            (50, Err(Error::CooldownActive)),
+            (109, Err(Error::CooldownActive)),
+            (110, Ok(())),
+        ] {
+            let is_rejected = expected.is_err();
+            let mut ctx = TestReceiveContext::empty();
+            ctx.set_invoker(admin);
+            ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(slot_time));
+            ctx.set_parameter(&remove_params_byte);
+            let result = contract_remove_curator(&ctx, &mut host, &mut logger);
+            claim_eq!(result, expected);
+            claim_eq!(
+                host.state().user.get(&user_addr).unwrap().is_curator,
+                is_rejected
+            );
+        }
         claim_eq!(
-            actual_state,
-            expected_state,
-            "state has been changed unexpectedly..."
+            host.state().user.get(&user_addr).unwrap().last_role_change,
+            Some(Timestamp::from_timestamp_millis(110))
+        );
+
+        // the cooldown applies to the other role as well
+        for (slot_time, expected) in [(150, Err(Error::CooldownActive)), (210, Ok(()))] {
+            let is_accepted = expected.is_ok();
+            let mut ctx = TestReceiveContext::empty();
+            ctx.set_invoker(admin);
+            ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(slot_time));
+            ctx.set_parameter(&add_params_byte);
+            let result = contract_add_validator(&ctx, &mut host);
+            claim_eq!(result, expected);
+            claim_eq!(
+                host.state().user.get(&user_addr).unwrap().is_validator,
+                is_accepted
+            );
+        }
+
+        // calls leaving the roles unchanged are not subject to the cooldown
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(220));
+        ctx.set_parameter(&add_params_byte);
+        claim_eq!(contract_add_validator(&ctx, &mut host), Ok(()));
+        ctx.set_parameter(&remove_params_byte);
+        claim_eq!(
+            contract_remove_curator(&ctx, &mut host, &mut logger),
+            Ok(())
+        );
+        claim_eq!(
+            host.state().user.get(&user_addr).unwrap().last_role_change,
+            Some(Timestamp::from_timestamp_millis(210))
         );
     }
 
     #[concordium_test]
-    /// Test that overlay-users.transfer_admin was successfully invoked by admin account.
-    fn test_contract_transfer_admin_invoked_by_admin() {
+    /// Test that overlay-users.view_state_digest is stable and changes after a mutation.
+    fn test_contract_view_state_digest() {
         let admin = AccountAddress([0; 32]);
-        let try_to_transfer_to = AccountAddress([2; 32]);
+        let curator = AccountAddress([1; 32]);
+        let new_curator = AccountAddress([2; 32]);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(
+            curator,
+            UserState {
+                is_curator: true,
+                curated_projects: vec!["TEST-PRJ1".into()],
+                ..UserState::default()
+            },
+        );
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(1, 2),
+            user,
+            curator_list: vec![curator],
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+        let crypto_primitives = TestCryptoPrimitives::new();
 
-        let mut ctx = TestReceiveContext::empty();
-        ctx.set_invoker(admin);
+        let ctx = TestReceiveContext::empty();
+        let result = contract_view_state_digest(&ctx, &host, &crypto_primitives);
+        claim!(result.is_ok());
+        let digest = result.unwrap();
+        claim_eq!(
+            contract_view_state_digest(&ctx, &host, &crypto_primitives),
+            Ok(digest)
+        );
+
+        // a view call does not change the digest
+        let params_byte = to_bytes(&AddrParams { addr: curator });
+        let mut view_ctx = TestReceiveContext::empty();
+        view_ctx.set_parameter(&params_byte);
+        claim!(contract_view_user(&view_ctx, &host).is_ok());
+        claim_eq!(
+            contract_view_state_digest(&ctx, &host, &crypto_primitives),
+            Ok(digest)
+        );
+
+        // a mutation changes the digest
+        let params_byte = to_bytes(&AddCuratorParams { addr: new_curator });
+        let mut add_ctx = TestReceiveContext::empty();
+        add_ctx.set_invoker(admin);
+        add_ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(10));
+        add_ctx.set_parameter(&params_byte);
+        claim!(contract_add_curator(&add_ctx, &mut host).is_ok());
+        let result = contract_view_state_digest(&ctx, &host, &crypto_primitives);
+        claim!(result.is_ok());
+        let mut last_digest = result.unwrap();
+        claim!(last_digest != digest);
+
+        // each of the configuration fields and sets is covered by the digest
+        host.state_mut().observers.insert(AccountAddress([3; 32]));
+        let next_digest = contract_view_state_digest(&ctx, &host, &crypto_primitives).unwrap();
+        claim!(next_digest != last_digest);
+        last_digest = next_digest;
+        host.state_mut().recovery_account = Some(AccountAddress([4; 32]));
+        let next_digest = contract_view_state_digest(&ctx, &host, &crypto_primitives).unwrap();
+        claim!(next_digest != last_digest);
+        last_digest = next_digest;
+        host.state_mut().admin_inactivity_timeout = Duration::from_millis(100);
+        let next_digest = contract_view_state_digest(&ctx, &host, &crypto_primitives).unwrap();
+        claim!(next_digest != last_digest);
+        last_digest = next_digest;
+        host.state_mut().curator_weight = 2;
+        let next_digest = contract_view_state_digest(&ctx, &host, &crypto_primitives).unwrap();
+        claim!(next_digest != last_digest);
+        last_digest = next_digest;
+        host.state_mut().validator_weight = 3;
+        let next_digest = contract_view_state_digest(&ctx, &host, &crypto_primitives).unwrap();
+        claim!(next_digest != last_digest);
+        last_digest = next_digest;
+        host.state_mut().strict_removals = true;
+        let next_digest = contract_view_state_digest(&ctx, &host, &crypto_primitives).unwrap();
+        claim!(next_digest != last_digest);
+        last_digest = next_digest;
+        host.state_mut()
+            .pending_curators
+            .insert(AccountAddress([5; 32]));
+        let next_digest = contract_view_state_digest(&ctx, &host, &crypto_primitives).unwrap();
+        claim!(next_digest != last_digest);
+        last_digest = next_digest;
+        host.state_mut()
+            .pending_validators
+            .insert(AccountAddress([6; 32]));
+        let next_digest = contract_view_state_digest(&ctx, &host, &crypto_primitives).unwrap();
+        claim!(next_digest != last_digest);
+        last_digest = next_digest;
+
+        // moving an address between the pending sets changes the digest without the cached counts
+        host.state_mut()
+            .pending_curators
+            .remove(&AccountAddress([5; 32]));
+        host.state_mut()
+            .pending_validators
+            .insert(AccountAddress([5; 32]));
+        let next_digest = contract_view_state_digest(&ctx, &host, &crypto_primitives).unwrap();
+        claim!(next_digest != last_digest);
+    }
+
+    #[concordium_test]
+    /// Test that view_state_digest serializes the project ids of different lengths in ascending order
+    /// with their count.
+    fn test_contract_view_state_digest_orders_project_ids() {
         let mut state_builder = TestStateBuilder::new();
+        let mut allowed_projects = state_builder.new_set();
+        allowed_projects.insert(ProjectId::from("TEST-PRJ2"));
+        allowed_projects.insert(ProjectId::from("TEST-PRJ10"));
         let state = State {
-            admin,
+            admin: Address::Account(AccountAddress([0; 32])),
             project_contract_addr: ContractAddress::new(0, 0),
             user: state_builder.new_map(),
             curator_list: Vec::new(),
             validator_list: Vec::new(),
+            allowed_projects,
+            ..default_test_state(&mut state_builder)
         };
-        let expected_state = State {
-            admin: try_to_transfer_to,
+
+        let contains =
+            |bytes: &[u8], part: &[u8]| bytes.windows(part.len()).any(|window| window == part);
+        let bytes = serial_state_digest(&state);
+        let mut ascending = to_bytes(&2u32);
+        ascending.extend(to_bytes(&ProjectId::from("TEST-PRJ10")));
+        ascending.extend(to_bytes(&ProjectId::from("TEST-PRJ2")));
+        claim!(contains(&bytes, &ascending));
+        let mut key_order = to_bytes(&ProjectId::from("TEST-PRJ2"));
+        key_order.extend(to_bytes(&ProjectId::from("TEST-PRJ10")));
+        claim!(!contains(&bytes, &key_order));
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.view_pending_applications and pending_count return the pending applications.
+    fn test_contract_view_pending_applications() {
+        let admin = AccountAddress([0; 32]);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut pending_curators = state_builder.new_set();
+        for i in [3, 1, 2] {
+            pending_curators.insert(AccountAddress([i; 32]));
+        }
+        let mut pending_validators = state_builder.new_set();
+        for i in [5, 4] {
+            pending_validators.insert(AccountAddress([i; 32]));
+        }
+        let state = State {
+            admin: Address::Account(admin),
             project_contract_addr: ContractAddress::new(0, 0),
             user: state_builder.new_map(),
             curator_list: Vec::new(),
             validator_list: Vec::new(),
+            pending_curators,
+            pending_validators,
+            pending_curator_count: 3,
+            pending_validator_count: 2,
+            ..default_test_state(&mut state_builder)
         };
-        let mut host = TestHost::new(state, state_builder);
+        let host = TestHost::new(state, state_builder);
 
-        // create parameters
-        let params = TransferAdminParams {
-            admin: try_to_transfer_to,
-        };
-        let params_byte = to_bytes(&params);
-        ctx.set_parameter(&params_byte);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        let result = contract_view_pending_applications(&ctx, &host);
+        claim_eq!(
+            result,
+            Ok(ViewPendingApplicationsResponse {
+                curators: vec![
+                    AccountAddress([1; 32]),
+                    AccountAddress([2; 32]),
+                    AccountAddress([3; 32]),
+                ],
+                validators: vec![AccountAddress([4; 32]), AccountAddress([5; 32])],
+            })
+        );
 
-        // invoke method
-        let result = contract_transfer_admin(&ctx, &mut host);
-        claim!(result.is_ok());
-        let actual_state = host.state();
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(AccountAddress([1; 32]));
+        let result = contract_view_pending_applications(&ctx, &host);
+        claim_eq!(result, Err(Error::InvalidCaller));
+        let result = contract_pending_count(&ctx, &host);
         claim_eq!(
-            *actual_state,
-            expected_state,
-            "state has been changed unexpectedly..."
+            result,
+            Ok(PendingCountResponse {
+                curators: 3,
+                validators: 2,
+            })
         );
     }
 
     #[concordium_test]
-    /// Test that overlay-users.transfer_admin was invoked by non-admin account.
-    fn test_contract_transfer_admin_invoked_by_non_admin() {
+    /// Test that overlay-users.apply_for_role queues an application until the role is granted.
+    fn test_contract_apply_for_role() {
         let admin = AccountAddress([0; 32]);
-        let suspicious = AccountAddress([1; 32]);
-        let try_to_transfer_to = AccountAddress([2; 32]);
+        let applicant = AccountAddress([1; 32]);
 
-        let mut ctx = TestReceiveContext::empty();
-        ctx.set_invoker(suspicious);
         let mut state_builder = TestStateBuilder::new();
         let state = State {
-            admin,
+            admin: Address::Account(admin),
             project_contract_addr: ContractAddress::new(0, 0),
             user: state_builder.new_map(),
             curator_list: Vec::new(),
             validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
         };
         let mut host = TestHost::new(state, state_builder);
 
-        // create parameters
-        let params = TransferAdminParams {
-            admin: try_to_transfer_to,
-        };
-        let params_byte = to_bytes(&params);
+        let params_byte = to_bytes(&Role::Curator);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_sender(Address::Contract(ContractAddress::new(1, 2)));
         ctx.set_parameter(&params_byte);
+        let result = contract_apply_for_role(&ctx, &mut host);
+        claim_eq!(result, Err(Error::InvalidCaller));
 
-        // invoke method
-        let result = contract_transfer_admin(&ctx, &mut host);
-        claim!(result.is_err());
-        claim_eq!(result.err(), Some(Error::InvalidCaller));
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_sender(Address::Account(applicant));
+        ctx.set_parameter(&params_byte);
+        let result = contract_apply_for_role(&ctx, &mut host);
+        claim!(result.is_ok());
+        claim!(host.state().pending_curators.contains(&applicant));
+
+        // the admin grants the role
+        let add_params_byte = to_bytes(&AddCuratorParams { addr: applicant });
+        let mut add_ctx = TestReceiveContext::empty();
+        add_ctx.set_invoker(admin);
+        add_ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(10));
+        add_ctx.set_parameter(&add_params_byte);
+        let result = contract_add_curator(&add_ctx, &mut host);
+        claim!(result.is_ok());
+        claim!(host.state().pending_curators.is_empty());
+
+        // a curator can not apply for the curator role again
+        let result = contract_apply_for_role(&ctx, &mut host);
+        claim_eq!(result, Err(Error::InvalidArgument));
+        claim!(host.state().pending_curators.is_empty());
     }
 
     #[concordium_test]
-    /// Test that overlay-users.add_project_contract was successfully invoked by admin account.
-    fn test_contract_add_project_contract_invoked_by_admin() {
-        let admin = AccountAddress([0; 32]);
-        let project_contract_addr_to_be_set = ContractAddress::new(1, 2);
-
-        let mut ctx = TestReceiveContext::empty();
-        ctx.set_invoker(admin);
+    /// Test that overlay-users.apply_for_role rejects a new application once the role has
+    /// `MAX_PENDING_APPLICATIONS` applications.
+    fn test_contract_apply_for_role_too_many_pending_applications() {
+        let applicant = AccountAddress([1; 32]);
         let mut state_builder = TestStateBuilder::new();
         let state = State {
-            admin,
+            admin: Address::Account(AccountAddress([0; 32])),
             project_contract_addr: ContractAddress::new(0, 0),
             user: state_builder.new_map(),
             curator_list: Vec::new(),
             validator_list: Vec::new(),
-        };
-        let expected_state = State {
-            admin,
-            project_contract_addr: project_contract_addr_to_be_set,
-            user: state_builder.new_map(),
-            curator_list: Vec::new(),
-            validator_list: Vec::new(),
+            pending_curator_count: MAX_PENDING_APPLICATIONS as u32,
+            ..default_test_state(&mut state_builder)
         };
         let mut host = TestHost::new(state, state_builder);
 
-        // create parameters
-        let params = AddProjectContractParams {
-            project_contract_addr: project_contract_addr_to_be_set,
-        };
-        let params_byte = to_bytes(&params);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_sender(Address::Account(applicant));
+        let params_byte = to_bytes(&Role::Curator);
         ctx.set_parameter(&params_byte);
+        let result = contract_apply_for_role(&ctx, &mut host);
+        claim_eq!(result, Err(Error::TooManyPendingApplications));
+        claim!(!host.state().pending_curators.contains(&applicant));
 
-        // invoke method
-        let result = contract_add_project_contract(&ctx, &mut host);
+        // the limit applies to each role separately
+        let params_byte = to_bytes(&Role::Validator);
+        ctx.set_parameter(&params_byte);
+        let result = contract_apply_for_role(&ctx, &mut host);
         claim!(result.is_ok());
-        let actual_state = host.state();
-        claim_eq!(
-            *actual_state,
-            expected_state,
-            "state has been changed unexpectedly..."
-        );
+        claim!(host.state().pending_validators.contains(&applicant));
     }
 
     #[concordium_test]
-    /// Test that overlay-users.add_project_contract was invoked by non-admin account.
-    fn test_contract_add_project_contract_invoked_by_non_admin() {
+    /// Test that overlay-users.inactive_admin_takeover succeeds only after the admin has been inactive.
+    fn test_contract_inactive_admin_takeover() {
         let admin = AccountAddress([0; 32]);
-        let suspicious = AccountAddress([1; 32]);
+        let recovery = AccountAddress([1; 32]);
+        let suspicious = AccountAddress([2; 32]);
 
-        let mut ctx = TestReceiveContext::empty();
-        ctx.set_invoker(suspicious);
         let mut state_builder = TestStateBuilder::new();
         let state = State {
-            admin,
+            admin: Address::Account(admin),
             project_contract_addr: ContractAddress::new(0, 0),
             user: state_builder.new_map(),
             curator_list: Vec::new(),
             validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
         };
         let mut host = TestHost::new(state, state_builder);
 
-        // create parameters
+        // the takeover is disabled until a recovery account is set
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_sender(Address::Account(recovery));
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(1000));
+        let result = contract_inactive_admin_takeover(&ctx, &mut host);
+        claim_eq!(result, Err(Error::InvalidCaller));
+
+        let params_byte = to_bytes(&SetRecoveryConfigParams {
+            recovery_account: Some(recovery),
+            admin_inactivity_timeout: Duration::from_millis(100),
+        });
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(10));
+        ctx.set_parameter(&params_byte);
+        let result = contract_set_recovery_config(&ctx, &mut host);
+        claim!(result.is_ok());
+        claim_eq!(
+            host.state().last_admin_activity,
+            Some(Timestamp::from_timestamp_millis(10))
+        );
+
+        // an admin-gated call is recorded as activity
+        let params_byte = to_bytes(&SetEventsEnabledParams {
+            events_enabled: false,
+        });
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(50));
+        ctx.set_parameter(&params_byte);
+        let result = contract_set_events_enabled(&ctx, &mut host);
+        claim!(result.is_ok());
+        claim_eq!(
+            host.state().last_admin_activity,
+            Some(Timestamp::from_timestamp_millis(50))
+        );
+
+        // within the timeout
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_sender(Address::Account(recovery));
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(149));
+        let result = contract_inactive_admin_takeover(&ctx, &mut host);
+        claim_eq!(result, Err(Error::AdminActive));
+        claim_eq!(host.state().admin, Address::Account(admin));
+
+        // only the recovery account can take over
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_sender(Address::Account(suspicious));
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(150));
+        let result = contract_inactive_admin_takeover(&ctx, &mut host);
+        claim_eq!(result, Err(Error::InvalidCaller));
+
+        // beyond the timeout
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_sender(Address::Account(recovery));
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(150));
+        let result = contract_inactive_admin_takeover(&ctx, &mut host);
+        claim!(result.is_ok());
+        claim_eq!(host.state().admin, Address::Account(recovery));
+        claim_eq!(
+            host.state().last_admin_change,
+            Some((
+                Timestamp::from_timestamp_millis(150),
+                Address::Account(admin)
+            ))
+        );
+        // the recovery configuration of the previous admin is cleared
+        claim_eq!(host.state().recovery_account, None);
+        claim_eq!(host.state().last_admin_activity, None);
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.project_curation_counts tallies the curators of each project.
+    fn test_contract_project_curation_counts() {
         let project_contract_addr = ContractAddress::new(1, 2);
-        let params = AddProjectContractParams {
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        for (i, curated_projects) in [
+            (1, vec!["TEST-PRJ1", "TEST-PRJ2"]),
+            (2, vec!["TEST-PRJ2", "TEST-PRJ3"]),
+            (3, vec!["TEST-PRJ2"]),
+            (4, Vec::new()),
+        ] {
+            user.insert(
+                AccountAddress([i; 32]),
+                UserState {
+                    is_curator: true,
+                    curated_projects: curated_projects
+                        .into_iter()
+                        .map(|project_id| ProjectId::from(project_id))
+                        .collect(),
+                    validated_projects: vec![("TEST-PRJ4".into(), project_contract_addr)],
+                    ..UserState::default()
+                },
+            );
+        }
+        let state = State {
+            admin: Address::Account(AccountAddress([0; 32])),
             project_contract_addr,
+            user,
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
         };
-        let params_byte = to_bytes(&params);
-        ctx.set_parameter(&params_byte);
+        let host = TestHost::new(state, state_builder);
 
-        // invoke method
-        let result = contract_add_project_contract(&ctx, &mut host);
-        claim!(result.is_err());
-        claim_eq!(result.err(), Some(Error::InvalidCaller));
+        let ctx = TestReceiveContext::empty();
+        let result = contract_project_curation_counts(&ctx, &host);
+        claim_eq!(
+            result,
+            Ok(vec![
+                (ProjectId::from("TEST-PRJ1"), 1),
+                (ProjectId::from("TEST-PRJ2"), 3),
+                (ProjectId::from("TEST-PRJ3"), 1),
+            ])
+        );
     }
 
     #[concordium_test]
-    /// Test that overlay-users.add_curator handle new user entry.
-    fn test_contract_add_new_curator_invoked_by_admin() {
-        let admin = AccountAddress([0; 32]);
-        let project_contract_addr = ContractAddress::new(0, 0);
-        let existing_user = AccountAddress([1; 32]);
-        let curator = AccountAddress([2; 32]);
-        let mut ctx = TestReceiveContext::empty();
-        ctx.set_invoker(admin);
+    /// Test that overlay-users.project_validation_counts tallies the validators of each project.
+    fn test_contract_project_validation_counts() {
+        let project_contract_addr = ContractAddress::new(1, 2);
         // setup state
         let mut state_builder = TestStateBuilder::new();
         let mut user = state_builder.new_map();
-        user.insert(
-            existing_user,
-            UserState {
-                is_curator: false,
-                is_validator: false,
-                curated_projects: Vec::new(),
-                validated_projects: Vec::new(),
-            },
-        );
+        for (i, validated_projects) in [
+            (1, vec!["TEST-PRJ1", "TEST-PRJ2"]),
+            (2, vec!["TEST-PRJ2"]),
+            (3, Vec::new()),
+        ] {
+            user.insert(
+                AccountAddress([i; 32]),
+                UserState {
+                    is_curator: true,
+                    is_validator: true,
+                    curated_projects: vec!["TEST-PRJ1".into(), "TEST-PRJ3".into()],
+                    validated_projects: validated_projects
+                        .into_iter()
+                        .map(|project_id| (ProjectId::from(project_id), project_contract_addr))
+                        .collect(),
+                    ..UserState::default()
+                },
+            );
+        }
         let state = State {
-            admin,
+            admin: Address::Account(AccountAddress([0; 32])),
             project_contract_addr,
             user,
             curator_list: Vec::new(),
             validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
         };
-        let mut expected_user = state_builder.new_map();
-        expected_user.insert(
-            existing_user,
-            UserState {
-                is_curator: false,
-                is_validator: false,
-                curated_projects: Vec::new(),
-                validated_projects: Vec::new(),
-            },
+        let host = TestHost::new(state, state_builder);
+
+        let ctx = TestReceiveContext::empty();
+        let result = contract_project_validation_counts(&ctx, &host);
+        claim_eq!(
+            result,
+            Ok(vec![
+                (ProjectId::from("TEST-PRJ1"), 1),
+                (ProjectId::from("TEST-PRJ2"), 2),
+            ])
         );
-        expected_user.insert(
+        let result = contract_project_curation_counts(&ctx, &host);
+        claim_eq!(
+            result,
+            Ok(vec![
+                (ProjectId::from("TEST-PRJ1"), 3),
+                (ProjectId::from("TEST-PRJ3"), 3),
+            ])
+        );
+    }
+
+    #[concordium_test]
+    /// Test that the batch endpoints reject the whole call in strict mode and skip invalid
+    /// entries otherwise.
+    fn test_contract_batch_strict_mode() {
+        let project_contract_addr = ContractAddress::new(1, 2);
+        let curator = AccountAddress([1; 32]);
+        let validator = AccountAddress([2; 32]);
+        let unknown = AccountAddress([3; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_sender(Address::Contract(project_contract_addr));
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(
             curator,
             UserState {
                 is_curator: true,
-                is_validator: false,
-                curated_projects: Vec::new(),
-                validated_projects: Vec::new(),
+                ..UserState::default()
             },
         );
-        let expected_state = State {
-            admin,
+        user.insert(
+            validator,
+            UserState {
+                is_validator: true,
+                ..UserState::default()
+            },
+        );
+        let state = State {
+            admin: Address::Account(AccountAddress([0; 32])),
             project_contract_addr,
-            user: expected_user,
+            user,
             curator_list: vec![curator],
-            validator_list: Vec::new(),
+            validator_list: vec![validator],
+            ..default_test_state(&mut state_builder)
         };
         let mut host = TestHost::new(state, state_builder);
 
-        // create parameters
-        let params = AddCuratorParams { addr: curator };
+        // strict mode rejects the whole call without updating the state
+        let params = CurateBatchUsersParams {
+            project_id: "TEST-PRJ1".into(),
+            addrs: vec![curator, unknown],
+            strict: true,
+        };
         let params_byte = to_bytes(&params);
         ctx.set_parameter(&params_byte);
+        let result = contract_curate_batch_users(&ctx, &mut host);
+        claim_eq!(result, Err(Error::UserNotFound));
+
+        let params = CurateBulkParams {
+            pairs: vec![
+                (curator, "TEST-PRJ1".into()),
+                (validator, "TEST-PRJ1".into()),
+            ],
+            strict: true,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = contract_curate_bulk(&ctx, &mut host);
+        claim_eq!(result, Err(Error::NotACurator));
+
+        let params = ValidateBatchUsersParams {
+            project_id: "TEST-PRJ1".into(),
+            addrs: vec![validator, curator],
+            strict: true,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = contract_validate_batch_users(&ctx, &mut host);
+        claim_eq!(result, Err(Error::NotAValidator));
+
+        claim!(host
+            .state()
+            .user
+            .get(&curator)
+            .unwrap()
+            .curated_projects
+            .is_empty());
+        claim!(host
+            .state()
+            .user
+            .get(&validator)
+            .unwrap()
+            .validated_projects
+            .is_empty());
+        claim_eq!(host.state().state_seq, 0);
+
+        // non-strict mode applies the valid entries and skips the rest
+        let params = CurateBatchUsersParams {
+            project_id: "TEST-PRJ1".into(),
+            addrs: vec![curator, unknown],
+            strict: false,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = contract_curate_batch_users(&ctx, &mut host);
+        claim_eq!(result, Ok(vec![unknown]));
+
+        let params = ValidateBatchUsersParams {
+            project_id: "TEST-PRJ1".into(),
+            addrs: vec![validator, curator],
+            strict: false,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = contract_validate_batch_users(&ctx, &mut host);
+        claim_eq!(result, Ok(vec![curator]));
 
-        // invoke method
-        let result = contract_add_curator(&ctx, &mut host);
-        claim!(result.is_ok());
-        let actual_state = host.state();
         claim_eq!(
-            *actual_state,
-            expected_state,
-            "state has been changed unexpectedly..."
+            host.state().user.get(&curator).unwrap().curated_projects,
+            vec![ProjectId::from("TEST-PRJ1")]
+        );
+        claim_eq!(
+            host.state()
+                .user
+                .get(&validator)
+                .unwrap()
+                .validated_projects,
+            vec![(ProjectId::from("TEST-PRJ1"), project_contract_addr)]
         );
+        claim_eq!(host.state().state_seq, 2);
     }
 
     #[concordium_test]
-    /// Test that overlay-users.add_curator handle existing user entry.
-    fn test_contract_modify_curator_invoked_by_admin() {
+    /// Test that overlay-users.curator_rank ranks the curators by the number of curated projects.
+    fn test_contract_curator_rank() {
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        for (i, is_curator, curated_count) in [
+            (1, true, 2),
+            (2, true, 5),
+            (3, true, 2),
+            (4, true, 0),
+            (5, false, 9),
+        ] {
+            user.insert(
+                AccountAddress([i; 32]),
+                UserState {
+                    is_curator,
+                    curated_projects: (0..curated_count)
+                        .map(|n| ProjectId::from(format!("TEST-PRJ{}", n)))
+                        .collect(),
+                    ..UserState::default()
+                },
+            );
+        }
+        let state = State {
+            admin: Address::Account(AccountAddress([0; 32])),
+            project_contract_addr: ContractAddress::new(1, 2),
+            user,
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let host = TestHost::new(state, state_builder);
+
+        for (i, expected) in [
+            (1, Some(2)),
+            (2, Some(1)),
+            (3, Some(2)),
+            (4, Some(4)),
+            (5, None),
+            (6, None),
+        ] {
+            let params = CuratorRankParams {
+                addr: AccountAddress([i; 32]),
+            };
+            let params_byte = to_bytes(&params);
+            let mut ctx = TestReceiveContext::empty();
+            ctx.set_parameter(&params_byte);
+            let result = contract_curator_rank(&ctx, &host);
+            claim_eq!(result, Ok(expected));
+        }
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.trim_user_projects keeps the last entries of each project list.
+    fn test_contract_trim_user_projects() {
         let admin = AccountAddress([0; 32]);
-        let project_contract_addr = ContractAddress::new(0, 0);
-        let existing_user = AccountAddress([1; 32]);
+        let project_contract_addr = ContractAddress::new(1, 2);
+        let user1 = AccountAddress([1; 32]);
+        let user2 = AccountAddress([2; 32]);
         let mut ctx = TestReceiveContext::empty();
         ctx.set_invoker(admin);
         // setup state
         let mut state_builder = TestStateBuilder::new();
         let mut user = state_builder.new_map();
         user.insert(
-            existing_user,
+            user1,
             UserState {
-                is_curator: false,
-                is_validator: false,
-                curated_projects: Vec::new(),
-                validated_projects: Vec::new(),
+                is_curator: true,
+                is_validator: true,
+                curated_projects: vec!["TEST-PRJ1".into(), "TEST-PRJ2".into(), "TEST-PRJ3".into()],
+                validated_projects: vec![("TEST-PRJ4".into(), project_contract_addr)],
+                ..UserState::default()
             },
         );
-        let state = State {
-            admin,
-            project_contract_addr,
-            user,
-            curator_list: Vec::new(),
-            validator_list: Vec::new(),
-        };
-        let mut expected_user = state_builder.new_map();
-        expected_user.insert(
-            existing_user,
+        user.insert(
+            user2,
             UserState {
-                is_curator: true,
-                is_validator: false,
-                curated_projects: Vec::new(),
-                validated_projects: Vec::new(),
+                is_validator: true,
+                validated_projects: vec![
+                    ("TEST-PRJ1".into(), project_contract_addr),
+                    ("TEST-PRJ2".into(), project_contract_addr),
+                    ("TEST-PRJ3".into(), project_contract_addr),
+                    ("TEST-PRJ4".into(), project_contract_addr),
+                ],
+                ..UserState::default()
             },
         );
-        let expected_state = State {
-            admin,
+        let state = State {
+            admin: Address::Account(admin),
             project_contract_addr,
-            user: expected_user,
-            curator_list: vec![existing_user],
-            validator_list: Vec::new(),
+            user,
+            curator_list: vec![user1],
+            validator_list: vec![user1, user2],
+            ..default_test_state(&mut state_builder)
         };
         let mut host = TestHost::new(state, state_builder);
 
         // create parameters
-        let params = AddCuratorParams {
-            addr: existing_user,
-        };
+        let params: TrimUserProjectsParams = 2;
         let params_byte = to_bytes(&params);
         ctx.set_parameter(&params_byte);
 
         // invoke method
-        let result = contract_add_curator(&ctx, &mut host);
-        claim!(result.is_ok());
-        let actual_state = host.state();
+        let result = contract_trim_user_projects(&ctx, &mut host);
+        claim_eq!(result, Ok(3));
+        let state = host.state();
+        let user1_state = state.user.get(&user1).unwrap();
         claim_eq!(
-            *actual_state,
-            expected_state,
-            "state has been changed unexpectedly..."
+            user1_state.curated_projects,
+            vec![ProjectId::from("TEST-PRJ2"), ProjectId::from("TEST-PRJ3")]
+        );
+        claim_eq!(
+            user1_state.validated_projects,
+            vec![(ProjectId::from("TEST-PRJ4"), project_contract_addr)]
+        );
+        let user2_state = state.user.get(&user2).unwrap();
+        claim_eq!(
+            user2_state.validated_projects,
+            vec![
+                (ProjectId::from("TEST-PRJ3"), project_contract_addr),
+                (ProjectId::from("TEST-PRJ4"), project_contract_addr),
+            ]
         );
+        claim_eq!(state.state_seq, 1);
     }
 
     #[concordium_test]
-    /// Test that overlay-users.contract_add_curator was invoked by non-admin account.
-    fn test_contract_add_curator_invoked_by_non_admin() {
+    /// Test that overlay-users.view_admin accepts observer accounts until they are removed.
+    fn test_contract_view_admin_invoked_by_observer() {
         let admin = AccountAddress([0; 32]);
-        let suspicious = AccountAddress([1; 32]);
-
-        let mut ctx = TestReceiveContext::empty();
-        ctx.set_invoker(suspicious);
+        let observer = AccountAddress([1; 32]);
+        let stranger = AccountAddress([2; 32]);
+        // setup state
         let mut state_builder = TestStateBuilder::new();
         let state = State {
-            admin,
-            project_contract_addr: ContractAddress::new(0, 0),
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(1, 2),
             user: state_builder.new_map(),
             curator_list: Vec::new(),
-            validator_list: Vec::new(),
-        };
-        let mut host = TestHost::new(state, state_builder);
-
-        // create parameters
-        let params = AddCuratorParams {
-            addr: AccountAddress([2; 32]),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
         };
+        let mut host = TestHost::new(state, state_builder);
+        let params = AddObserverParams { addr: observer };
         let params_byte = to_bytes(&params);
+
+        // only the admin can add an observer
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(observer);
         ctx.set_parameter(&params_byte);
+        let result = contract_add_observer(&ctx, &mut host);
+        claim_eq!(result, Err(Error::InvalidCaller));
+        let result = contract_view_admin(&ctx, &host);
+        claim!(result.is_err());
 
-        // invoke method
-        let result = contract_add_curator(&ctx, &mut host);
+        let mut admin_ctx = TestReceiveContext::empty();
+        admin_ctx.set_invoker(admin);
+        admin_ctx.set_parameter(&params_byte);
+        let result = contract_add_observer(&admin_ctx, &mut host);
+        claim_eq!(result, Ok(()));
+
+        // the observer is granted access
+        let result = contract_view_admin(&ctx, &host);
+        claim!(result.is_ok());
+        claim_eq!(result.unwrap().admin, Address::Account(admin));
+
+        // a random account is still rejected
+        let mut stranger_ctx = TestReceiveContext::empty();
+        stranger_ctx.set_invoker(stranger);
+        let result = contract_view_admin(&stranger_ctx, &host);
         claim!(result.is_err());
-        claim_eq!(result.err(), Some(Error::InvalidCaller));
+
+        // the access is revoked
+        let result = contract_remove_observer(&admin_ctx, &mut host);
+        claim_eq!(result, Ok(()));
+        let result = contract_view_admin(&ctx, &host);
+        claim!(result.is_err());
+        claim_eq!(host.state().state_seq, 2);
     }
 
     #[concordium_test]
-    /// Test that overlay-users.contract_remove_curator successfully remove the input
-    fn test_contract_remove_curator_invoked_by_admin() {
+    /// Test that overlay-users.view_user_score weights the curated and validated projects.
+    fn test_contract_view_user_score() {
         let admin = AccountAddress([0; 32]);
-        let project_contract_addr = ContractAddress::new(0, 0);
-        let existing_user = AccountAddress([1; 32]);
-        let mut ctx = TestReceiveContext::empty();
-        ctx.set_invoker(admin);
+        let project_contract_addr = ContractAddress::new(1, 2);
+        let user1 = AccountAddress([1; 32]);
+        let unknown = AccountAddress([2; 32]);
         // setup state
         let mut state_builder = TestStateBuilder::new();
         let mut user = state_builder.new_map();
         user.insert(
-            existing_user,
+            user1,
             UserState {
                 is_curator: true,
-                is_validator: false,
-                curated_projects: Vec::new(),
-                validated_projects: Vec::new(),
+                is_validator: true,
+                curated_projects: vec!["TEST-PRJ1".into(), "TEST-PRJ2".into()],
+                validated_projects: vec![
+                    ("TEST-PRJ3".into(), project_contract_addr),
+                    ("TEST-PRJ4".into(), project_contract_addr),
+                    ("TEST-PRJ5".into(), project_contract_addr),
+                ],
+                ..UserState::default()
             },
         );
         let state = State {
-            admin,
+            admin: Address::Account(admin),
             project_contract_addr,
             user,
-            curator_list: vec![existing_user],
-            validator_list: Vec::new(),
-        };
-        let mut expected_user = state_builder.new_map();
-        expected_user.insert(
-            existing_user,
-            UserState {
-                is_curator: false,
-                is_validator: false,
-                curated_projects: Vec::new(),
-                validated_projects: Vec::new(),
-            },
-        );
-        let expected_state = State {
-            admin,
-            project_contract_addr,
-            user: expected_user,
-            curator_list: Vec::new(),
-            validator_list: Vec::new(),
+            curator_list: vec![user1],
+            validator_list: vec![user1],
+            ..default_test_state(&mut state_builder)
         };
         let mut host = TestHost::new(state, state_builder);
 
-        // create parameters
-        let params = RemoveCuratorParams {
-            addr: existing_user,
+        fn view_score<S: HasStateApi>(
+            host: &impl HasHost<State<S>, StateApiType = S>,
+            addr: AccountAddress,
+        ) -> ContractResult<u64> {
+            let params = ViewUserScoreParams { addr };
+            let params_byte = to_bytes(&params);
+            let mut ctx = TestReceiveContext::empty();
+            ctx.set_parameter(&params_byte);
+            contract_view_user_score(&ctx, host)
+        }
+        claim_eq!(view_score(&host, user1), Ok(5));
+        claim_eq!(view_score(&host, unknown), Ok(0));
+
+        // only the admin can change the weights
+        let params = SetScoreWeightsParams {
+            curator_weight: 3,
+            validator_weight: 10,
         };
         let params_byte = to_bytes(&params);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(user1);
         ctx.set_parameter(&params_byte);
+        let result = contract_set_score_weights(&ctx, &mut host);
+        claim_eq!(result, Err(Error::InvalidCaller));
+        claim_eq!(view_score(&host, user1), Ok(5));
 
-        // invoke method
-        let result = contract_remove_curator(&ctx, &mut host);
-        claim!(result.is_ok());
-        let actual_state = host.state();
-        claim_eq!(
-            *actual_state,
-            expected_state,
-            "state has been changed unexpectedly..."
-        );
+        ctx.set_invoker(admin);
+        let result = contract_set_score_weights(&ctx, &mut host);
+        claim_eq!(result, Ok(()));
+        claim_eq!(view_score(&host, user1), Ok(36));
+        claim_eq!(view_score(&host, unknown), Ok(0));
     }
 
     #[concordium_test]
-    /// Test that overlay-users.contract_remove_curator succeeds even if the parameter user is not
-    /// curator
-    fn test_contract_remove_curator_with_no_effect_invoked_by_admin() {
-        let admin = AccountAddress([0; 32]);
-        let project_contract_addr = ContractAddress::new(0, 0);
-        let existing_user = AccountAddress([1; 32]);
-        let not_curator = AccountAddress([2; 32]);
-        let mut ctx = TestReceiveContext::empty();
-        ctx.set_invoker(admin);
+    /// Test that overlay-users.which_projects_referenced reports each project id in the input order.
+    fn test_contract_which_projects_referenced() {
+        let project_contract_addr = ContractAddress::new(1, 2);
         // setup state
         let mut state_builder = TestStateBuilder::new();
         let mut user = state_builder.new_map();
         user.insert(
-            existing_user,
+            AccountAddress([1; 32]),
             UserState {
                 is_curator: true,
-                is_validator: false,
-                curated_projects: Vec::new(),
-                validated_projects: Vec::new(),
+                curated_projects: vec!["TEST-PRJ1".into()],
+                ..UserState::default()
             },
         );
-        let state = State {
-            admin,
-            project_contract_addr,
-            user,
-            curator_list: vec![existing_user],
-            validator_list: Vec::new(),
-        };
-        let mut expected_user = state_builder.new_map();
-        expected_user.insert(
-            existing_user,
+        user.insert(
+            AccountAddress([2; 32]),
             UserState {
-                is_curator: true,
-                is_validator: false,
-                curated_projects: Vec::new(),
-                validated_projects: Vec::new(),
+                is_validator: true,
+                validated_projects: vec![("TEST-PRJ3".into(), project_contract_addr)],
+                ..UserState::default()
             },
         );
-        let expected_state = State {
-            admin,
+        let state = State {
+            admin: Address::Account(AccountAddress([0; 32])),
             project_contract_addr,
-            user: expected_user,
-            curator_list: vec![existing_user],
+            user,
+            curator_list: Vec::new(),
             validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
         };
-        let mut host = TestHost::new(state, state_builder);
+        let host = TestHost::new(state, state_builder);
 
         // create parameters
-        let params = RemoveCuratorParams { addr: not_curator };
+        let params: WhichProjectsReferencedParams = vec![
+            "TEST-PRJ4".into(),
+            "TEST-PRJ3".into(),
+            "TEST-PRJ2".into(),
+            "TEST-PRJ1".into(),
+        ];
         let params_byte = to_bytes(&params);
+        let mut ctx = TestReceiveContext::empty();
         ctx.set_parameter(&params_byte);
 
         // invoke method
-        let result = contract_remove_curator(&ctx, &mut host);
-        claim!(result.is_ok());
-        let actual_state = host.state();
+        let result = contract_which_projects_referenced(&ctx, &host);
         claim_eq!(
-            *actual_state,
-            expected_state,
-            "state has been changed unexpectedly..."
+            result,
+            Ok(vec![
+                (ProjectId::from("TEST-PRJ4"), false),
+                (ProjectId::from("TEST-PRJ3"), true),
+                (ProjectId::from("TEST-PRJ2"), false),
+                (ProjectId::from("TEST-PRJ1"), true),
+            ])
         );
     }
 
     #[concordium_test]
-    /// Test that overlay-users.contract_remove_curator was invoked by non-admin account.
-    fn test_contract_remove_curator_invoked_by_non_admin() {
+    /// Test that overlay-users.restore_snapshot rolls back the changes made after snapshot_users.
+    fn test_contract_snapshot_and_restore_users() {
         let admin = AccountAddress([0; 32]);
-        let suspicious = AccountAddress([1; 32]);
-
+        let curator = AccountAddress([1; 32]);
+        let validator = AccountAddress([2; 32]);
+        let new_user = AccountAddress([3; 32]);
         let mut ctx = TestReceiveContext::empty();
-        ctx.set_invoker(suspicious);
+        ctx.set_invoker(admin);
+        // setup state
         let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        let curator_state = UserState {
+            is_curator: true,
+            curated_projects: vec!["TEST-PRJ1".into()],
+            ..UserState::default()
+        };
+        let validator_state = UserState {
+            is_validator: true,
+            ..UserState::default()
+        };
+        user.insert(curator, curator_state.clone());
+        user.insert(validator, validator_state.clone());
         let state = State {
-            admin,
-            project_contract_addr: ContractAddress::new(0, 0),
-            user: state_builder.new_map(),
-            curator_list: Vec::new(),
-            validator_list: Vec::new(),
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(1, 2),
+            user,
+            curator_list: vec![curator],
+            validator_list: vec![validator],
+            ..default_test_state(&mut state_builder)
         };
         let mut host = TestHost::new(state, state_builder);
 
-        // create parameters
-        let params = RemoveCuratorParams {
-            addr: AccountAddress([2; 32]),
-        };
-        let params_byte = to_bytes(&params);
-        ctx.set_parameter(&params_byte);
+        // restoring before any snapshot keeps the users
+        let result = contract_restore_snapshot(&ctx, &mut host);
+        claim_eq!(result, Err(Error::NoSnapshot));
+        claim_eq!(host.state().user.iter().count(), 2);
 
-        // invoke method
-        let result = contract_remove_curator(&ctx, &mut host);
-        claim!(result.is_err());
-        claim_eq!(result.err(), Some(Error::InvalidCaller));
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(10));
+        let result = contract_snapshot_users(&ctx, &mut host);
+        claim_eq!(result, Ok(2));
+        claim_eq!(
+            host.state().last_snapshot_time,
+            Some(Timestamp::from_timestamp_millis(10))
+        );
+
+        // change the users after the snapshot
+        {
+            let state = host.state_mut();
+            state.user.entry(curator).and_modify(|user_state| {
+                user_state.is_curator = false;
+                user_state.curated_projects.clear();
+            });
+            state.user.remove(&validator);
+            state.user.insert(
+                new_user,
+                UserState {
+                    is_curator: true,
+                    ..UserState::default()
+                },
+            );
+            state.curator_list = vec![new_user];
+            state.validator_list = Vec::new();
+        }
+
+        let result = contract_restore_snapshot(&ctx, &mut host);
+        claim_eq!(result, Ok(()));
+        let state = host.state();
+        claim_eq!(state.user.iter().count(), 2);
+        claim_eq!(*state.user.get(&curator).unwrap(), curator_state);
+        claim_eq!(*state.user.get(&validator).unwrap(), validator_state);
+        claim!(state.user.get(&new_user).is_none());
+        claim_eq!(state.curator_list, vec![curator]);
+        claim_eq!(state.validator_list, vec![validator]);
     }
 
     #[concordium_test]
-    /// Test that overlay-users.add_validator handle new user entry.
-    fn test_contract_add_new_validator_invoked_by_admin() {
+    /// Test that overlay-users.snapshot_users rejects more than MAX_SNAPSHOT_USERS users.
+    fn test_contract_snapshot_users_too_many_users() {
         let admin = AccountAddress([0; 32]);
-        let project_contract_addr = ContractAddress::new(0, 0);
-        let existing_user = AccountAddress([1; 32]);
-        let validator = AccountAddress([2; 32]);
         let mut ctx = TestReceiveContext::empty();
         ctx.set_invoker(admin);
         // setup state
         let mut state_builder = TestStateBuilder::new();
         let mut user = state_builder.new_map();
-        user.insert(
-            existing_user,
-            UserState {
-                is_curator: false,
-                is_validator: false,
-                curated_projects: Vec::new(),
-                validated_projects: Vec::new(),
-            },
-        );
+        for i in 0..=MAX_SNAPSHOT_USERS as u16 {
+            let mut addr = [0; 32];
+            addr[..2].copy_from_slice(&(i + 1).to_le_bytes());
+            user.insert(AccountAddress(addr), UserState::default());
+        }
         let state = State {
-            admin,
-            project_contract_addr,
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(1, 2),
             user,
             curator_list: Vec::new(),
             validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
         };
-        let mut expected_user = state_builder.new_map();
-        expected_user.insert(
-            existing_user,
+        let mut host = TestHost::new(state, state_builder);
+
+        let result = contract_snapshot_users(&ctx, &mut host);
+        claim_eq!(result, Err(Error::InvalidArgument));
+        claim!(host.state().last_snapshot.is_empty());
+        claim_eq!(host.state().last_snapshot_time, None);
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.view_stale_list_entries reports the list entries without the role.
+    fn test_contract_view_stale_list_entries() {
+        let curator = AccountAddress([1; 32]);
+        let flipped_curator = AccountAddress([2; 32]);
+        let validator = AccountAddress([3; 32]);
+        let unknown = AccountAddress([4; 32]);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(
+            curator,
             UserState {
-                is_curator: false,
-                is_validator: false,
-                curated_projects: Vec::new(),
-                validated_projects: Vec::new(),
+                is_curator: true,
+                ..UserState::default()
             },
         );
-        expected_user.insert(
+        user.insert(
+            flipped_curator,
+            UserState {
+                is_validator: true,
+                ..UserState::default()
+            },
+        );
+        user.insert(
             validator,
             UserState {
-                is_curator: false,
                 is_validator: true,
-                curated_projects: Vec::new(),
-                validated_projects: Vec::new(),
+                ..UserState::default()
             },
         );
-        let expected_state = State {
-            admin,
-            project_contract_addr,
-            user: expected_user,
-            curator_list: Vec::new(),
-            validator_list: vec![validator],
+        let state = State {
+            admin: Address::Account(AccountAddress([0; 32])),
+            project_contract_addr: ContractAddress::new(1, 2),
+            user,
+            curator_list: vec![flipped_curator, curator, unknown],
+            validator_list: vec![validator, curator],
+            ..default_test_state(&mut state_builder)
         };
-        let mut host = TestHost::new(state, state_builder);
+        let host = TestHost::new(state, state_builder);
 
-        // create parameters
-        let params = AddValidatorParams { addr: validator };
-        let params_byte = to_bytes(&params);
-        ctx.set_parameter(&params_byte);
-
-        // invoke method
-        let result = contract_add_validator(&ctx, &mut host);
+        let ctx = TestReceiveContext::empty();
+        let result = contract_view_stale_list_entries(&ctx, &host);
         claim!(result.is_ok());
-        let actual_state = host.state();
-        claim_eq!(
-            *actual_state,
-            expected_state,
-            "state has been changed unexpectedly..."
-        );
+        let response = result.unwrap();
+        claim_eq!(response.stale_curators, vec![flipped_curator, unknown]);
+        claim_eq!(response.stale_validators, vec![curator]);
     }
 
     #[concordium_test]
-    /// Test that overlay-users.add_validator handle existing user entry.
-    fn test_contract_modify_validator_invoked_by_admin() {
-        let admin = AccountAddress([0; 32]);
-        let project_contract_addr = ContractAddress::new(0, 0);
-        let existing_user = AccountAddress([1; 32]);
-        let mut ctx = TestReceiveContext::empty();
-        ctx.set_invoker(admin);
+    /// Test that overlay-users.curators_of_project_prefix matches the curated project ids by prefix.
+    fn test_contract_curators_of_project_prefix() {
+        let user1 = AccountAddress([1; 32]);
+        let user2 = AccountAddress([2; 32]);
+        let project_contract_addr = ContractAddress::new(1, 2);
         // setup state
         let mut state_builder = TestStateBuilder::new();
         let mut user = state_builder.new_map();
         user.insert(
-            existing_user,
+            user1,
             UserState {
-                is_curator: false,
-                is_validator: false,
-                curated_projects: Vec::new(),
-                validated_projects: Vec::new(),
+                is_curator: true,
+                curated_projects: vec!["ART-001".into(), "GAME-001".into(), "ART-002".into()],
+                ..UserState::default()
             },
         );
-        let state = State {
-            admin,
-            project_contract_addr,
-            user,
-            curator_list: Vec::new(),
-            validator_list: Vec::new(),
-        };
-        let mut expected_user = state_builder.new_map();
-        expected_user.insert(
-            existing_user,
+        user.insert(
+            user2,
             UserState {
-                is_curator: false,
+                is_curator: true,
                 is_validator: true,
-                curated_projects: Vec::new(),
-                validated_projects: Vec::new(),
+                curated_projects: vec!["GAME-002".into(), "ARTS-001".into()],
+                validated_projects: vec![("ART-003".into(), project_contract_addr)],
+                ..UserState::default()
             },
         );
-        let expected_state = State {
-            admin,
+        let state = State {
+            admin: Address::Account(AccountAddress([0; 32])),
             project_contract_addr,
-            user: expected_user,
+            user,
+            curator_list: vec![user1, user2],
+            validator_list: vec![user2],
+            ..default_test_state(&mut state_builder)
+        };
+        let host = TestHost::new(state, state_builder);
+
+        let params: CuratorsOfProjectPrefixParams = "ART-".into();
+        let params_byte = to_bytes(&params);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_parameter(&params_byte);
+        let result = contract_curators_of_project_prefix(&ctx, &host);
+        claim_eq!(
+            result,
+            Ok(vec![
+                (user1, ProjectId::from("ART-001")),
+                (user1, ProjectId::from("ART-002")),
+            ])
+        );
+
+        let params: CuratorsOfProjectPrefixParams = "NEWS".into();
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = contract_curators_of_project_prefix(&ctx, &host);
+        claim_eq!(result, Ok(Vec::new()));
+
+        let params: CuratorsOfProjectPrefixParams = "A".repeat(MAX_PROJECT_PREFIX_LEN + 1);
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = contract_curators_of_project_prefix(&ctx, &host);
+        claim_eq!(result, Err(Error::InvalidArgument));
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.view_limits reflects each configured limit.
+    fn test_contract_view_limits() {
+        let admin = AccountAddress([0; 32]);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(1, 2),
+            user: state_builder.new_map(),
             curator_list: Vec::new(),
-            validator_list: vec![existing_user],
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
         };
         let mut host = TestHost::new(state, state_builder);
 
-        // create parameters
-        let params = AddValidatorParams {
-            addr: existing_user,
-        };
-        let params_byte = to_bytes(&params);
+        // unset limits are reported with the sentinel values
+        let ctx = TestReceiveContext::empty();
+        let limits = contract_view_limits(&ctx, &host).unwrap();
+        claim_eq!(limits.max_projects_per_user, None);
+        claim_eq!(limits.role_cooldown, Duration::from_millis(0));
+        claim_eq!(limits.curator_weight, 1);
+        claim_eq!(limits.validator_weight, 1);
+        claim_eq!(limits.admin_inactivity_timeout, None);
+
+        // configure the limits
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(10));
+        let params_byte = to_bytes(&SetMaxProjectsPerUserParams {
+            max_projects_per_user: Some(5),
+        });
         ctx.set_parameter(&params_byte);
+        claim_eq!(contract_set_max_projects_per_user(&ctx, &mut host), Ok(()));
+        let params_byte = to_bytes(&SetRoleCooldownParams {
+            role_cooldown: Duration::from_millis(100),
+        });
+        ctx.set_parameter(&params_byte);
+        claim_eq!(contract_set_role_cooldown(&ctx, &mut host), Ok(()));
+        let params_byte = to_bytes(&SetScoreWeightsParams {
+            curator_weight: 2,
+            validator_weight: 3,
+        });
+        ctx.set_parameter(&params_byte);
+        claim_eq!(contract_set_score_weights(&ctx, &mut host), Ok(()));
+        let params_byte = to_bytes(&SetRecoveryConfigParams {
+            recovery_account: Some(AccountAddress([1; 32])),
+            admin_inactivity_timeout: Duration::from_millis(1000),
+        });
+        ctx.set_parameter(&params_byte);
+        claim_eq!(contract_set_recovery_config(&ctx, &mut host), Ok(()));
 
-        // invoke method
-        let result = contract_add_validator(&ctx, &mut host);
-        claim!(result.is_ok());
-        let actual_state = host.state();
+        let ctx = TestReceiveContext::empty();
+        let limits = contract_view_limits(&ctx, &host).unwrap();
+        claim_eq!(limits.max_projects_per_user, Some(5));
+        claim_eq!(limits.role_cooldown, Duration::from_millis(100));
+        claim_eq!(limits.curator_weight, 2);
+        claim_eq!(limits.validator_weight, 3);
         claim_eq!(
-            *actual_state,
-            expected_state,
-            "state has been changed unexpectedly..."
+            limits.admin_inactivity_timeout,
+            Some(Duration::from_millis(1000))
         );
     }
 
     #[concordium_test]
-    /// Test that overlay-users.contract_add_validator was invoked by non-admin account.
-    fn test_contract_add_validator_invoked_by_non_admin() {
+    /// Test that overlay-users.can_add_curator reports each condition blocking add_curator.
+    fn test_contract_can_add_curator() {
         let admin = AccountAddress([0; 32]);
-        let suspicious = AccountAddress([1; 32]);
-
-        let mut ctx = TestReceiveContext::empty();
-        ctx.set_invoker(suspicious);
+        let target = AccountAddress([1; 32]);
+        // setup state
         let mut state_builder = TestStateBuilder::new();
         let state = State {
-            admin,
-            project_contract_addr: ContractAddress::new(0, 0),
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(1, 2),
             user: state_builder.new_map(),
             curator_list: Vec::new(),
             validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
         };
         let mut host = TestHost::new(state, state_builder);
-
-        // create parameters
-        let params = AddValidatorParams {
-            addr: AccountAddress([2; 32]),
-        };
+        let params = CanAddCuratorParams { addr: target };
         let params_byte = to_bytes(&params);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(10));
         ctx.set_parameter(&params_byte);
 
-        // invoke method
-        let result = contract_add_validator(&ctx, &mut host);
-        claim!(result.is_err());
-        claim_eq!(result.err(), Some(Error::InvalidCaller));
+        // allowed
+        let response = contract_can_add_curator(&ctx, &host).unwrap();
+        claim!(response.allowed);
+        claim_eq!(response.reason, None);
+
+        // caller is not the admin
+        let mut other_ctx = TestReceiveContext::empty();
+        other_ctx.set_invoker(target);
+        other_ctx.set_parameter(&params_byte);
+        let response = contract_can_add_curator(&other_ctx, &host).unwrap();
+        claim!(!response.allowed);
+        claim_eq!(response.reason, Some(Error::InvalidCaller));
+
+        // the user is unknown while require_known_users is set
+        host.state_mut().require_known_users = true;
+        let response = contract_can_add_curator(&ctx, &host).unwrap();
+        claim!(!response.allowed);
+        claim_eq!(response.reason, Some(Error::UserNotFound));
+
+        // the roles of the user have been changed within role_cooldown
+        host.state_mut().role_cooldown = Duration::from_millis(100);
+        host.state_mut().user.insert(
+            target,
+            UserState {
+                last_role_change: Some(Timestamp::from_timestamp_millis(5)),
+                ..UserState::default()
+            },
+        );
+        let response = contract_can_add_curator(&ctx, &host).unwrap();
+        claim!(!response.allowed);
+        claim_eq!(response.reason, Some(Error::CooldownActive));
+
+        // the curator set is frozen
+        host.state_mut().curators_frozen = true;
+        let response = contract_can_add_curator(&ctx, &host).unwrap();
+        claim!(!response.allowed);
+        claim_eq!(response.reason, Some(Error::CuratorsFrozen));
+
+        claim!(!host.state().user.get(&target).unwrap().is_curator);
+        claim_eq!(host.state().state_seq, 0);
     }
 
     #[concordium_test]
-    /// Test that overlay-users.contract_remove_validator successfully remove the input
-    fn test_contract_remove_validator_invoked_by_admin() {
-        let admin = AccountAddress([0; 32]);
-        let project_contract_addr = ContractAddress::new(0, 0);
-        let existing_user = AccountAddress([1; 32]);
+    /// Test that overlay-users.curate_with_score records the score of the curation,
+    /// and view_curation_scores returns it with 0 for the curations without a score.
+    fn test_contract_curate_with_score() {
+        let project_contract_addr = ContractAddress::new(1, 2);
+        let curator = AccountAddress([1; 32]);
         let mut ctx = TestReceiveContext::empty();
-        ctx.set_invoker(admin);
+        ctx.set_sender(Address::Contract(project_contract_addr));
         // setup state
         let mut state_builder = TestStateBuilder::new();
         let mut user = state_builder.new_map();
         user.insert(
-            existing_user,
+            curator,
             UserState {
-                is_curator: false,
-                is_validator: true,
-                curated_projects: Vec::new(),
-                validated_projects: Vec::new(),
+                is_curator: true,
+                curated_projects: vec!["TEST-PRJ1".into()],
+                ..UserState::default()
             },
         );
         let state = State {
-            admin,
+            admin: Address::Account(AccountAddress([0; 32])),
             project_contract_addr,
             user,
-            curator_list: Vec::new(),
-            validator_list: vec![existing_user],
-        };
-        let mut expected_user = state_builder.new_map();
-        expected_user.insert(
-            existing_user,
-            UserState {
-                is_curator: false,
-                is_validator: false,
-                curated_projects: Vec::new(),
-                validated_projects: Vec::new(),
-            },
-        );
-        let expected_state = State {
-            admin,
-            project_contract_addr,
-            user: expected_user,
-            curator_list: Vec::new(),
+            curator_list: vec![curator],
             validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
         };
         let mut host = TestHost::new(state, state_builder);
 
-        // create parameters
-        let params = RemoveValidatorParams {
-            addr: existing_user,
+        let params = CurateWithScoreParams {
+            addr: curator,
+            project_id: "TEST-PRJ2".into(),
+            score: MAX_CURATION_SCORE,
         };
         let params_byte = to_bytes(&params);
         ctx.set_parameter(&params_byte);
+        let result = contract_curate_with_score(&ctx, &mut host);
+        claim_eq!(result, Ok(()));
+        claim_eq!(host.state().state_seq, 1);
 
-        // invoke method
-        let result = contract_remove_validator(&ctx, &mut host);
+        // curating the same project again keeps the recorded score
+        let params = CurateWithScoreParams {
+            addr: curator,
+            project_id: "TEST-PRJ2".into(),
+            score: 10,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = contract_curate_with_score(&ctx, &mut host);
+        claim_eq!(result, Ok(()));
+        claim_eq!(host.state().state_seq, 1);
+
+        // view_user keeps its layout
+        let params_byte = to_bytes(&ViewUserParams { addr: curator });
+        let mut view_ctx = TestReceiveContext::empty();
+        view_ctx.set_parameter(&params_byte);
+        let result = contract_view_user(&view_ctx, &host);
         claim!(result.is_ok());
-        let actual_state = host.state();
         claim_eq!(
-            *actual_state,
-            expected_state,
-            "state has been changed unexpectedly..."
+            result.unwrap().curated_projects,
+            vec![ProjectId::from("TEST-PRJ1"), ProjectId::from("TEST-PRJ2")]
+        );
+
+        let params_byte = to_bytes(&ViewCurationScoresParams { addr: curator });
+        view_ctx.set_parameter(&params_byte);
+        let result = contract_view_curation_scores(&view_ctx, &host);
+        claim_eq!(
+            result,
+            Ok(vec![
+                (ProjectId::from("TEST-PRJ1"), 0),
+                (ProjectId::from("TEST-PRJ2"), MAX_CURATION_SCORE)
+            ])
         );
+
+        // an unknown user has no scores
+        let params_byte = to_bytes(&ViewCurationScoresParams {
+            addr: AccountAddress([2; 32]),
+        });
+        view_ctx.set_parameter(&params_byte);
+        let result = contract_view_curation_scores(&view_ctx, &host);
+        claim_eq!(result, Ok(Vec::new()));
     }
 
     #[concordium_test]
-    /// Test that overlay-users.contract_remove_validator succeeds even if the parameter user is not
-    /// validator
-    fn test_contract_remove_validator_with_no_effect_invoked_by_admin() {
-        let admin = AccountAddress([0; 32]);
-        let project_contract_addr = ContractAddress::new(0, 0);
-        let existing_user = AccountAddress([1; 32]);
-        let not_validator = AccountAddress([2; 32]);
+    /// Test that overlay-users.curate_with_score rejects a score greater than MAX_CURATION_SCORE.
+    fn test_contract_curate_with_score_out_of_range() {
+        let project_contract_addr = ContractAddress::new(1, 2);
+        let curator = AccountAddress([1; 32]);
         let mut ctx = TestReceiveContext::empty();
-        ctx.set_invoker(admin);
+        ctx.set_sender(Address::Contract(project_contract_addr));
         // setup state
         let mut state_builder = TestStateBuilder::new();
         let mut user = state_builder.new_map();
         user.insert(
-            existing_user,
+            curator,
             UserState {
-                is_curator: false,
-                is_validator: true,
-                curated_projects: Vec::new(),
-                validated_projects: Vec::new(),
+                is_curator: true,
+                ..UserState::default()
             },
         );
         let state = State {
-            admin,
+            admin: Address::Account(AccountAddress([0; 32])),
             project_contract_addr,
             user,
-            curator_list: Vec::new(),
-            validator_list: vec![existing_user],
-        };
-        let mut expected_user = state_builder.new_map();
-        expected_user.insert(
-            existing_user,
-            UserState {
-                is_curator: false,
-                is_validator: true,
-                curated_projects: Vec::new(),
-                validated_projects: Vec::new(),
-            },
-        );
-        let expected_state = State {
-            admin,
-            project_contract_addr,
-            user: expected_user,
-            curator_list: Vec::new(),
-            validator_list: vec![existing_user],
+            curator_list: vec![curator],
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
         };
         let mut host = TestHost::new(state, state_builder);
 
-        // create parameters
-        let params = RemoveValidatorParams {
-            addr: not_validator,
+        let params = CurateWithScoreParams {
+            addr: curator,
+            project_id: "TEST-PRJ1".into(),
+            score: MAX_CURATION_SCORE + 1,
         };
         let params_byte = to_bytes(&params);
         ctx.set_parameter(&params_byte);
+        let result = contract_curate_with_score(&ctx, &mut host);
+        claim_eq!(result, Err(Error::InvalidArgument));
+        claim!(host
+            .state()
+            .user
+            .get(&curator)
+            .unwrap()
+            .curated_projects
+            .is_empty());
+        claim!(host.state().curation_scores.get(&curator).is_none());
+        claim_eq!(host.state().state_seq, 0);
+    }
 
-        // invoke method
-        let result = contract_remove_validator(&ctx, &mut host);
-        claim!(result.is_ok());
-        let actual_state = host.state();
+    #[concordium_test]
+    /// Test that overlay-users.view_all_role_holders lists each role holder once.
+    fn test_contract_view_all_role_holders() {
+        let curator = AccountAddress([1; 32]);
+        let validator = AccountAddress([2; 32]);
+        let both = AccountAddress([3; 32]);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Account(AccountAddress([0; 32])),
+            project_contract_addr: ContractAddress::new(1, 2),
+            user: state_builder.new_map(),
+            curator_list: vec![both, curator],
+            validator_list: vec![validator, both],
+            ..default_test_state(&mut state_builder)
+        };
+        let host = TestHost::new(state, state_builder);
+
+        let ctx = TestReceiveContext::empty();
+        let result = contract_view_all_role_holders(&ctx, &host);
+        claim_eq!(result, Ok(vec![both, curator, validator]));
+    }
+
+    #[concordium_test]
+    /// Test that overlay-users.view_admin_action_counts tallies the admin-gated calls per function.
+    fn test_contract_view_admin_action_counts() {
+        let admin = AccountAddress([0; 32]);
+        let other = AccountAddress([9; 32]);
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(1, 2),
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(10));
+
+        for i in 1..=2 {
+            let params_byte = to_bytes(&AddCuratorParams {
+                addr: AccountAddress([i; 32]),
+            });
+            ctx.set_parameter(&params_byte);
+            claim_eq!(contract_add_curator(&ctx, &mut host), Ok(()));
+        }
+        let params_byte = to_bytes(&SetUsersViewPublicParams {
+            users_view_public: false,
+        });
+        ctx.set_parameter(&params_byte);
+        claim_eq!(contract_set_users_view_public(&ctx, &mut host), Ok(()));
+
+        // a call rejected by the admin check is not counted
+        ctx.set_invoker(other);
         claim_eq!(
-            *actual_state,
-            expected_state,
-            "state has been changed unexpectedly..."
+            contract_set_users_view_public(&ctx, &mut host),
+            Err(Error::InvalidCaller)
+        );
+
+        let result = contract_view_admin_action_counts(&ctx, &host);
+        claim_eq!(
+            result,
+            Ok(vec![
+                (String::from("add_curator"), 2),
+                (String::from("set_users_view_public"), 1),
+            ])
         );
     }
 
     #[concordium_test]
-    /// Test that overlay-users.contract_remove_validator was invoked by non-admin account.
-    fn test_contract_remove_validator_invoked_by_non_admin() {
+    /// Test that overlay-users.remove_curator and remove_validator reject unknown users only
+    /// while strict_removals is set.
+    fn test_contract_remove_roles_with_strict_removals() {
         let admin = AccountAddress([0; 32]);
-        let suspicious = AccountAddress([1; 32]);
-
+        let unknown = AccountAddress([1; 32]);
         let mut ctx = TestReceiveContext::empty();
-        ctx.set_invoker(suspicious);
+        ctx.set_invoker(admin);
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(10));
+        let mut logger = TestLogger::init();
+        // setup state
         let mut state_builder = TestStateBuilder::new();
         let state = State {
-            admin,
-            project_contract_addr: ContractAddress::new(0, 0),
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(1, 2),
             user: state_builder.new_map(),
             curator_list: Vec::new(),
             validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
         };
         let mut host = TestHost::new(state, state_builder);
+        let remove_curator_params = to_bytes(&RemoveCuratorParams {
+            addr: unknown,
+            reason: None,
+            force: false,
+        });
+        let remove_validator_params = to_bytes(&RemoveValidatorParams {
+            addr: unknown,
+            reason: None,
+            force: false,
+        });
 
-        // create parameters
-        let params = RemoveValidatorParams {
-            addr: AccountAddress([2; 32]),
-        };
-        let params_byte = to_bytes(&params);
+        // lenient by default
+        ctx.set_parameter(&remove_curator_params);
+        let result = contract_remove_curator(&ctx, &mut host, &mut logger);
+        claim_eq!(result, Ok(()));
+        ctx.set_parameter(&remove_validator_params);
+        let result = contract_remove_validator(&ctx, &mut host, &mut logger);
+        claim_eq!(result, Ok(()));
+        claim!(host.state().user.get(&unknown).is_none());
+
+        let params_byte = to_bytes(&SetStrictRemovalsParams {
+            strict_removals: true,
+        });
         ctx.set_parameter(&params_byte);
+        let result = contract_set_strict_removals(&ctx, &mut host);
+        claim_eq!(result, Ok(()));
 
-        // invoke method
-        let result = contract_remove_validator(&ctx, &mut host);
-        claim!(result.is_err());
-        claim_eq!(result.err(), Some(Error::InvalidCaller));
+        ctx.set_parameter(&remove_curator_params);
+        let result = contract_remove_curator(&ctx, &mut host, &mut logger);
+        claim_eq!(result, Err(Error::UserNotFound));
+        ctx.set_parameter(&remove_validator_params);
+        let result = contract_remove_validator(&ctx, &mut host, &mut logger);
+        claim_eq!(result, Err(Error::UserNotFound));
+        claim_eq!(host.state().state_seq, 1);
     }
 
     #[concordium_test]
-    /// Test that overlay-users.curate successfully add project id to user entry.
-    fn test_contract_curate() {
-        let admin = AccountAddress([0; 32]);
-        let project_contract_addr = ContractAddress::new(0, 0);
-        let existing_user = AccountAddress([1; 32]);
-        let project_id: ProjectId = "TEST-PRJ".into();
-
-        let mut ctx = TestReceiveContext::empty();
-        ctx.set_sender(Address::Contract(project_contract_addr));
+    /// Test that overlay-users.self_curated_and_validated detects a user who curated and validated
+    /// the same project.
+    fn test_contract_self_curated_and_validated() {
+        let project_contract_addr = ContractAddress::new(1, 2);
+        let overlapping = AccountAddress([1; 32]);
+        let separate = AccountAddress([2; 32]);
+        // setup state
         let mut state_builder = TestStateBuilder::new();
         let mut user = state_builder.new_map();
         user.insert(
-            existing_user,
+            overlapping,
             UserState {
                 is_curator: true,
-                is_validator: false,
-                curated_projects: Vec::new(),
-                validated_projects: Vec::new(),
+                is_validator: true,
+                curated_projects: vec!["TEST-PRJ1".into()],
+                validated_projects: vec![("TEST-PRJ1".into(), project_contract_addr)],
+                ..UserState::default()
             },
         );
-        let state = State {
-            admin,
-            project_contract_addr,
-            user,
-            curator_list: vec![existing_user],
-            validator_list: Vec::new(),
-        };
-        let mut expected_user = state_builder.new_map();
-        expected_user.insert(
-            existing_user,
+        user.insert(
+            separate,
             UserState {
                 is_curator: true,
-                is_validator: false,
-                curated_projects: vec![project_id.clone()],
-                validated_projects: Vec::new(),
+                is_validator: true,
+                curated_projects: vec!["TEST-PRJ1".into()],
+                validated_projects: vec![("TEST-PRJ2".into(), project_contract_addr)],
+                ..UserState::default()
             },
         );
-        let expected_state = State {
-            admin,
+        let state = State {
+            admin: Address::Account(AccountAddress([0; 32])),
             project_contract_addr,
-            user: expected_user,
-            curator_list: vec![existing_user],
-            validator_list: Vec::new(),
-        };
-        let mut host = TestHost::new(state, state_builder);
-
-        // create parameters
-        let params = CurateParams {
-            addr: existing_user,
-            project_id: project_id.clone(),
+            user,
+            curator_list: vec![overlapping, separate],
+            validator_list: vec![overlapping, separate],
+            ..default_test_state(&mut state_builder)
         };
-        let params_byte = to_bytes(&params);
-        ctx.set_parameter(&params_byte);
+        let host = TestHost::new(state, state_builder);
 
-        // invoke method
-        let result = contract_curate(&ctx, &mut host);
-        claim!(result.is_ok());
-        let actual_state = host.state();
-        claim_eq!(
-            *actual_state,
-            expected_state,
-            "state has been changed unexpectedly..."
-        );
+        for (addr, project_id, expected) in [
+            (overlapping, "TEST-PRJ1", true),
+            (separate, "TEST-PRJ1", false),
+            (separate, "TEST-PRJ2", false),
+            (AccountAddress([3; 32]), "TEST-PRJ1", false),
+        ] {
+            let params = SelfCuratedAndValidatedParams {
+                addr,
+                project_id: project_id.into(),
+            };
+            let params_byte = to_bytes(&params);
+            let mut ctx = TestReceiveContext::empty();
+            ctx.set_parameter(&params_byte);
+            let result = contract_self_curated_and_validated(&ctx, &host);
+            claim_eq!(result, Ok(expected));
+        }
     }
 
     #[concordium_test]
-    /// Test that overlay-users.curate fails if the input user has not been added as a curator.
-    fn test_contract_curate_fails_with_no_user() {
+    /// Test that overlay-users.setup_governance applies all the inputted governance settings.
+    fn test_contract_setup_governance() {
         let admin = AccountAddress([0; 32]);
-        let project_contract_addr = ContractAddress::new(0, 0);
-        let existing_user = AccountAddress([1; 32]);
+        let new_admin = AccountAddress([1; 32]);
+        let old_observer = AccountAddress([2; 32]);
+        let observer1 = AccountAddress([3; 32]);
+        let observer2 = AccountAddress([4; 32]);
+        let new_project_contract = ContractAddress::new(5, 6);
         let mut ctx = TestReceiveContext::empty();
-        ctx.set_sender(Address::Contract(project_contract_addr));
+        ctx.set_invoker(admin);
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(10));
+        // setup state
         let mut state_builder = TestStateBuilder::new();
+        let mut observers = state_builder.new_set();
+        observers.insert(old_observer);
         let state = State {
-            admin,
-            project_contract_addr,
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(1, 2),
             user: state_builder.new_map(),
             curator_list: Vec::new(),
             validator_list: Vec::new(),
+            observers,
+            ..default_test_state(&mut state_builder)
         };
         let mut host = TestHost::new(state, state_builder);
 
         // create parameters
-        let params = CurateParams {
-            addr: existing_user,
-            project_id: "TEST-PRJ".into(),
+        let params = SetupGovernanceParams {
+            new_admin: Some(Address::Account(new_admin)),
+            observers: vec![observer1, observer2],
+            project_contract: Some(new_project_contract),
         };
         let params_byte = to_bytes(&params);
         ctx.set_parameter(&params_byte);
 
         // invoke method
-        let result = contract_curate(&ctx, &mut host);
-        claim!(result.is_err());
-        claim_eq!(result.err(), Some(Error::InvalidArgument));
+        let result = contract_setup_governance(&ctx, &mut host);
+        claim_eq!(result, Ok(()));
+        let state = host.state();
+        claim_eq!(state.admin, Address::Account(new_admin));
+        claim_eq!(
+            state.last_admin_change,
+            Some((
+                Timestamp::from_timestamp_millis(10),
+                Address::Account(admin)
+            ))
+        );
+        claim_eq!(state.project_contract_addr, new_project_contract);
+        claim_eq!(state.observers.iter().count(), 2);
+        claim!(state.observers.contains(&observer1));
+        claim!(state.observers.contains(&observer2));
+        claim!(!state.observers.contains(&old_observer));
+
+        // the previous admin can not set up the governance anymore
+        let result = contract_setup_governance(&ctx, &mut host);
+        claim_eq!(result, Err(Error::InvalidCaller));
     }
 
     #[concordium_test]
-    /// Test that overlay-users.curate was invoked by non-project contract account.
-    fn test_contract_curate_invoked_by_non_project_contract_addr() {
-        let project_contract_addr = ContractAddress::new(0, 0);
-        let suspicious = ContractAddress::new(0, 1);
-
+    /// Test that overlay-users.setup_governance keeps the state when the config is locked.
+    fn test_contract_setup_governance_when_config_locked() {
+        let admin = AccountAddress([0; 32]);
         let mut ctx = TestReceiveContext::empty();
-        ctx.set_sender(Address::Contract(suspicious));
+        ctx.set_invoker(admin);
+        // setup state
         let mut state_builder = TestStateBuilder::new();
         let state = State {
-            admin: AccountAddress([0; 32]),
-            project_contract_addr,
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(1, 2),
             user: state_builder.new_map(),
             curator_list: Vec::new(),
             validator_list: Vec::new(),
+            config_locked: true,
+            ..default_test_state(&mut state_builder)
         };
         let mut host = TestHost::new(state, state_builder);
 
-        // create parameters
-        let params = CurateParams {
-            addr: AccountAddress([2; 32]),
-            project_id: "TEST-PRJ".into(),
+        let params = SetupGovernanceParams {
+            new_admin: Some(Address::Account(AccountAddress([1; 32]))),
+            observers: vec![AccountAddress([2; 32])],
+            project_contract: Some(ContractAddress::new(5, 6)),
         };
         let params_byte = to_bytes(&params);
         ctx.set_parameter(&params_byte);
-
-        // invoke method
-        let result = contract_curate(&ctx, &mut host);
-        claim!(result.is_err());
-        claim_eq!(result.err(), Some(Error::InvalidCaller));
+        let result = contract_setup_governance(&ctx, &mut host);
+        claim_eq!(result, Err(Error::ConfigLocked));
+        claim_eq!(host.state().admin, Address::Account(admin));
+        claim!(host.state().observers.iter().next().is_none());
     }
 
     #[concordium_test]
-    /// Test that overlay-users.validate successfully add project id to user entry.
-    fn test_contract_validate() {
-        let admin = AccountAddress([0; 32]);
-        let project_contract_addr = ContractAddress::new(0, 0);
-        let existing_user = AccountAddress([1; 32]);
-        let project_id: ProjectId = "TEST-PRJ".into();
-
-        let mut ctx = TestReceiveContext::empty();
-        ctx.set_sender(Address::Contract(project_contract_addr));
+    /// Test that overlay-users.view_orphan_validations reports the validations no one curated.
+    fn test_contract_view_orphan_validations() {
+        let project_contract_addr = ContractAddress::new(1, 2);
+        let curator = AccountAddress([1; 32]);
+        let validator = AccountAddress([2; 32]);
+        // setup state
         let mut state_builder = TestStateBuilder::new();
         let mut user = state_builder.new_map();
         user.insert(
-            existing_user,
+            curator,
             UserState {
-                is_curator: false,
-                is_validator: true,
-                curated_projects: Vec::new(),
-                validated_projects: Vec::new(),
+                is_curator: true,
+                curated_projects: vec!["TEST-PRJ1".into()],
+                ..UserState::default()
             },
         );
-        let state = State {
-            admin,
-            project_contract_addr,
-            user,
-            curator_list: Vec::new(),
-            validator_list: vec![existing_user],
-        };
-        let mut expected_user = state_builder.new_map();
-        expected_user.insert(
-            existing_user,
+        user.insert(
+            validator,
             UserState {
-                is_curator: false,
                 is_validator: true,
-                curated_projects: Vec::new(),
-                validated_projects: vec![project_id.clone()],
+                validated_projects: vec![
+                    ("TEST-PRJ2".into(), project_contract_addr),
+                    ("TEST-PRJ1".into(), project_contract_addr),
+                ],
+                ..UserState::default()
             },
         );
-        let expected_state = State {
-            admin,
+        let state = State {
+            admin: Address::Account(AccountAddress([0; 32])),
             project_contract_addr,
-            user: expected_user,
-            curator_list: Vec::new(),
-            validator_list: vec![existing_user],
-        };
-        let mut host = TestHost::new(state, state_builder);
-
-        // create parameters
-        let params = ValidateParams {
-            addr: existing_user,
-            project_id: project_id.clone(),
+            user,
+            curator_list: vec![curator],
+            validator_list: vec![validator],
+            ..default_test_state(&mut state_builder)
         };
-        let params_byte = to_bytes(&params);
-        ctx.set_parameter(&params_byte);
+        let host = TestHost::new(state, state_builder);
 
-        // invoke method
-        let result = contract_validate(&ctx, &mut host);
-        claim!(result.is_ok());
-        let actual_state = host.state();
-        claim_eq!(
-            *actual_state,
-            expected_state,
-            "state has been changed unexpectedly..."
-        );
+        for (addr, expected) in [
+            (validator, vec![ProjectId::from("TEST-PRJ2")]),
+            (curator, Vec::new()),
+            (AccountAddress([3; 32]), Vec::new()),
+        ] {
+            let params = ViewOrphanValidationsParams { addr };
+            let params_byte = to_bytes(&params);
+            let mut ctx = TestReceiveContext::empty();
+            ctx.set_parameter(&params_byte);
+            let result = contract_view_orphan_validations(&ctx, &host);
+            claim_eq!(result, Ok(expected));
+        }
     }
 
     #[concordium_test]
-    /// Test that overlay-users.validate fails if the input user has not been added as a validator.
-    fn test_contract_validate_fails_with_no_user() {
+    /// Test that overlay-users.resync_user fixes the list entries of the inputted user only.
+    fn test_contract_resync_user() {
         let admin = AccountAddress([0; 32]);
-        let project_contract_addr = ContractAddress::new(0, 0);
-        let existing_user = AccountAddress([1; 32]);
+        let drifted = AccountAddress([1; 32]);
+        let other = AccountAddress([2; 32]);
         let mut ctx = TestReceiveContext::empty();
-        ctx.set_sender(Address::Contract(project_contract_addr));
+        ctx.set_invoker(admin);
+        // setup state
         let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        // the flags have been flipped out-of-band: curator but unlisted, listed as a validator
+        user.insert(
+            drifted,
+            UserState {
+                is_curator: true,
+                ..UserState::default()
+            },
+        );
+        user.insert(
+            other,
+            UserState {
+                is_curator: true,
+                ..UserState::default()
+            },
+        );
         let state = State {
-            admin,
-            project_contract_addr,
-            user: state_builder.new_map(),
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(1, 2),
+            user,
             curator_list: Vec::new(),
-            validator_list: Vec::new(),
+            validator_list: vec![other, drifted],
+            ..default_test_state(&mut state_builder)
         };
         let mut host = TestHost::new(state, state_builder);
 
-        // create parameters
-        let params = ValidateParams {
-            addr: existing_user,
-            project_id: "TEST-PRJ".into(),
-        };
-        let params_byte = to_bytes(&params);
+        let params_byte = to_bytes(&ResyncUserParams { addr: drifted });
         ctx.set_parameter(&params_byte);
+        let result = contract_resync_user(&ctx, &mut host);
+        claim!(result.is_ok());
+        let response = result.unwrap();
+        claim!(response.curator_list_adjusted);
+        claim!(response.validator_list_adjusted);
+        claim_eq!(host.state().curator_list, vec![drifted]);
+        claim_eq!(host.state().validator_list, vec![other]);
 
-        // invoke method
-        let result = contract_validate(&ctx, &mut host);
-        claim!(result.is_err());
-        claim_eq!(result.err(), Some(Error::InvalidArgument));
+        // nothing to adjust anymore
+        let result = contract_resync_user(&ctx, &mut host);
+        claim!(result.is_ok());
+        let response = result.unwrap();
+        claim!(!response.curator_list_adjusted);
+        claim!(!response.validator_list_adjusted);
+        claim_eq!(host.state().curator_list, vec![drifted]);
+        claim_eq!(host.state().validator_list, vec![other]);
     }
 
     #[concordium_test]
-    /// Test that overlay-users.validate was invoked by non-project contract account.
-    fn test_contract_validate_invoked_by_non_project_contract_addr() {
-        let project_contract_addr = ContractAddress::new(0, 0);
-        let suspicious = ContractAddress::new(0, 1);
-
+    /// Test that the logged events carry consecutive sequence numbers across several mutations.
+    fn test_contract_events_carry_consecutive_seq() {
+        let admin = AccountAddress([0; 32]);
+        let curator = AccountAddress([1; 32]);
+        let validator = AccountAddress([2; 32]);
         let mut ctx = TestReceiveContext::empty();
-        ctx.set_sender(Address::Contract(suspicious));
+        ctx.set_invoker(admin);
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(10));
+        // setup state
         let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(
+            curator,
+            UserState {
+                is_curator: true,
+                ..UserState::default()
+            },
+        );
+        user.insert(
+            validator,
+            UserState {
+                is_validator: true,
+                ..UserState::default()
+            },
+        );
         let state = State {
-            admin: AccountAddress([0; 32]),
-            project_contract_addr,
-            user: state_builder.new_map(),
-            curator_list: Vec::new(),
-            validator_list: Vec::new(),
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user,
+            curator_list: vec![curator],
+            validator_list: vec![validator],
+            ..default_test_state(&mut state_builder)
         };
         let mut host = TestHost::new(state, state_builder);
+        let mut logger = TestLogger::init();
 
-        // create parameters
-        let params = ValidateParams {
-            addr: AccountAddress([2; 32]),
-            project_id: "TEST-PRJ".into(),
-        };
-        let params_byte = to_bytes(&params);
+        // remove the curator
+        let params_byte = to_bytes(&RemoveCuratorParams {
+            addr: curator,
+            reason: None,
+            force: false,
+        });
         ctx.set_parameter(&params_byte);
+        let result = contract_remove_curator(&ctx, &mut host, &mut logger);
+        claim_eq!(result, Ok(()));
 
-        // invoke method
-        let result = contract_validate(&ctx, &mut host);
-        claim!(result.is_err());
-        claim_eq!(result.err(), Some(Error::InvalidCaller));
+        // grant the curator role back by a batch operation
+        let params_byte = to_bytes(&SetUserRolesBatchParams {
+            users: vec![UserRolesParams {
+                addr: curator,
+                is_curator: true,
+                is_validator: false,
+            }],
+            skip_admin: None,
+        });
+        ctx.set_parameter(&params_byte);
+        let result = contract_set_user_roles_batch(&ctx, &mut host, &mut logger);
+        claim!(result.is_ok());
+
+        // remove the validator
+        let params_byte = to_bytes(&RemoveValidatorParams {
+            addr: validator,
+            reason: None,
+            force: false,
+        });
+        ctx.set_parameter(&params_byte);
+        let result = contract_remove_validator(&ctx, &mut host, &mut logger);
+        claim_eq!(result, Ok(()));
+
+        claim_eq!(
+            logger.logs,
+            vec![
+                to_bytes(&Event::RemoveCurator(RemoveRoleEvent {
+                    event_seq: 0,
+                    addr: curator,
+                    reason: None,
+                })),
+                to_bytes(&Event::BatchRoleChange(BatchRoleChangeEvent {
+                    event_seq: 1,
+                    curators_added: 1,
+                    curators_removed: 0,
+                    validators_added: 0,
+                    validators_removed: 0,
+                })),
+                to_bytes(&Event::RemoveValidator(RemoveRoleEvent {
+                    event_seq: 2,
+                    addr: validator,
+                    reason: None,
+                })),
+            ]
+        );
+        claim_eq!(host.state().event_seq, 3);
     }
 
     #[concordium_test]
-    /// Test that overlay-users.upgrade can not be invoked by non-admin.
-    fn test_contract_upgrade_invoked_by_non_admin() {
-        let owner = AccountAddress([0; 32]);
-        let suspicious = AccountAddress([1; 32]);
+    /// Test that the cached counts follow the mutations and match overlay-users.recompute_counts.
+    fn test_contract_cached_counts_follow_mutations() {
+        let admin = AccountAddress([0; 32]);
+        let user1 = AccountAddress([1; 32]);
+        let user2 = AccountAddress([2; 32]);
         let mut ctx = TestReceiveContext::empty();
-        ctx.set_owner(owner);
-        ctx.set_sender(Address::Account(suspicious));
+        ctx.set_invoker(admin);
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(10));
+        // setup state
         let mut state_builder = TestStateBuilder::new();
         let state = State {
-            admin: owner,
+            admin: Address::Account(admin),
             project_contract_addr: ContractAddress::new(0, 0),
             user: state_builder.new_map(),
             curator_list: Vec::new(),
             validator_list: Vec::new(),
+            auto_prune_on_remove: true,
+            ..default_test_state(&mut state_builder)
         };
         let mut host = TestHost::new(state, state_builder);
+        let mut logger = TestLogger::init();
 
-        // create parameters
-        let params = UpgradeParams {
-            module: HashBytes::new([0; 32]),
-            migrate: None,
-        };
-        let params_byte = to_bytes(&params);
+        let params_byte = to_bytes(&AddCuratorParams { addr: user1 });
         ctx.set_parameter(&params_byte);
+        claim!(contract_add_curator(&ctx, &mut host).is_ok());
+        let params_byte = to_bytes(&AddValidatorParams { addr: user1 });
+        ctx.set_parameter(&params_byte);
+        claim!(contract_add_validator(&ctx, &mut host).is_ok());
+        let params_byte = to_bytes(&AddValidatorParams { addr: user2 });
+        ctx.set_parameter(&params_byte);
+        claim!(contract_add_validator(&ctx, &mut host).is_ok());
+        claim_eq!(
+            contract_view_cached_counts(&ctx, &host),
+            Ok(CachedCounts {
+                user_count: 2,
+                curator_count: 1,
+                validator_count: 2,
+            })
+        );
 
-        // invoke method
-        let result = contract_upgrade(&ctx, &mut host);
-        claim!(result.is_err());
+        // removing the last role of user2 prunes the user
+        let params_byte = to_bytes(&RemoveValidatorParams {
+            addr: user2,
+            reason: None,
+            force: false,
+        });
+        ctx.set_parameter(&params_byte);
+        claim!(contract_remove_validator(&ctx, &mut host, &mut logger).is_ok());
+        let cached = contract_view_cached_counts(&ctx, &host);
+        claim_eq!(
+            cached,
+            Ok(CachedCounts {
+                user_count: 1,
+                curator_count: 1,
+                validator_count: 1,
+            })
+        );
+        claim_eq!(cached, contract_recompute_counts(&ctx, &mut host));
     }
 
     #[concordium_test]
-    /// Test that overlay-users.contract_view_admin returns administrative data.
-    fn test_contract_view_admin_invoked_by_admin() {
+    /// Test that overlay-users.force_curate cannot grant the curator role while the curators are frozen.
+    fn test_contract_force_curate_with_curators_frozen() {
         let admin = AccountAddress([0; 32]);
-        let project_contract_addr = ContractAddress::new(1, 2);
-        let curator = AccountAddress([1; 32]);
-        let validator = AccountAddress([2; 32]);
+        let user_addr = AccountAddress([1; 32]);
         let mut ctx = TestReceiveContext::empty();
         ctx.set_invoker(admin);
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(10));
         // setup state
         let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(user_addr, UserState::default());
         let state = State {
-            admin,
-            project_contract_addr,
-            user: state_builder.new_map(),
-            curator_list: vec![curator],
-            validator_list: vec![validator],
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user,
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            curators_frozen: true,
+            ..default_test_state(&mut state_builder)
         };
         let mut host = TestHost::new(state, state_builder);
 
-        // invoke method
-        let result = contract_view_admin(&ctx, &mut host);
-        claim!(result.is_ok());
-        let view = result.unwrap();
-        claim_eq!(view.admin, admin);
-        claim_eq!(view.project_contract_addr, project_contract_addr);
-        claim_eq!(view.curator_list, vec![curator]);
-        claim_eq!(view.validator_list, vec![validator]);
+        // granting the role is rejected
+        let params_byte = to_bytes(&ForceCurateParams {
+            addr: user_addr,
+            project_id: "TEST-PRJ".into(),
+            set_curator: true,
+        });
+        ctx.set_parameter(&params_byte);
+        let result = contract_force_curate(&ctx, &mut host);
+        claim_eq!(result, Err(Error::CuratorsFrozen));
+        claim!(host.state().curator_list.is_empty());
+
+        // recording the curation only is still allowed
+        let params_byte = to_bytes(&ForceCurateParams {
+            addr: user_addr,
+            project_id: "TEST-PRJ".into(),
+            set_curator: false,
+        });
+        ctx.set_parameter(&params_byte);
+        let result = contract_force_curate(&ctx, &mut host);
+        claim_eq!(result, Ok(()));
+        let user_state = host.state().user.get(&user_addr).unwrap();
+        claim!(!user_state.is_curator);
+        claim!(user_state.has_curated(&"TEST-PRJ".into()));
     }
 
     #[concordium_test]
-    /// Test that overlay-users.contract_view_admin should fail when invoked by non-admin
-    fn test_contract_view_admin_invoked_by_not_admin() {
+    /// Test that a soft-deleted user can not be given roles or projects again.
+    fn test_contract_soft_deleted_user_is_rejected() {
         let admin = AccountAddress([0; 32]);
-        let suspicious = AccountAddress([1; 32]);
+        let project_contract_addr = ContractAddress::new(1, 2);
+        let deleted = AccountAddress([1; 32]);
         let mut ctx = TestReceiveContext::empty();
-        ctx.set_invoker(suspicious);
+        ctx.set_invoker(admin);
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(10));
         // setup state
         let mut state_builder = TestStateBuilder::new();
+        let mut user = state_builder.new_map();
+        user.insert(
+            deleted,
+            UserState {
+                deleted: true,
+                ..UserState::default()
+            },
+        );
         let state = State {
-            admin,
-            project_contract_addr: ContractAddress::new(1, 2),
-            user: state_builder.new_map(),
+            admin: Address::Account(admin),
+            project_contract_addr,
+            user,
             curator_list: Vec::new(),
             validator_list: Vec::new(),
+            curate_auto_create: true,
+            ..default_test_state(&mut state_builder)
         };
         let mut host = TestHost::new(state, state_builder);
+        let mut logger = TestLogger::init();
 
-        // invoke method
-        let result = contract_view_admin(&ctx, &mut host);
-        claim!(result.is_err());
-        claim_eq!(result.err(), Some(Error::InvalidCaller));
+        let params_byte = to_bytes(&AddCuratorParams { addr: deleted });
+        ctx.set_parameter(&params_byte);
+        claim_eq!(
+            contract_add_curator(&ctx, &mut host),
+            Err(Error::UserDeleted)
+        );
+        claim_eq!(
+            contract_add_validator(&ctx, &mut host),
+            Err(Error::UserDeleted)
+        );
+        claim_eq!(
+            contract_register_user(&ctx, &mut host),
+            Err(Error::UserDeleted)
+        );
+
+        let params_byte = to_bytes(&ForceCurateParams {
+            addr: deleted,
+            project_id: "TEST-PRJ".into(),
+            set_curator: true,
+        });
+        ctx.set_parameter(&params_byte);
+        claim_eq!(
+            contract_force_curate(&ctx, &mut host),
+            Err(Error::UserDeleted)
+        );
+
+        let params_byte = to_bytes(&SetUserRolesBatchParams {
+            users: vec![UserRolesParams {
+                addr: deleted,
+                is_curator: true,
+                is_validator: true,
+            }],
+            skip_admin: None,
+        });
+        ctx.set_parameter(&params_byte);
+        claim_eq!(
+            contract_set_user_roles_batch(&ctx, &mut host, &mut logger).map(|_| ()),
+            Err(Error::UserDeleted)
+        );
+
+        let params_byte = to_bytes(&ImportCuratorsParams {
+            curators: vec![(deleted, Vec::new())],
+            skip_admin: None,
+        });
+        ctx.set_parameter(&params_byte);
+        claim_eq!(
+            contract_import_curators(&ctx, &mut host, &mut logger),
+            Err(Error::UserDeleted)
+        );
+
+        let params_byte = to_bytes(&SwitchRoleParams {
+            addr: deleted,
+            to_curator: true,
+            force: false,
+        });
+        ctx.set_parameter(&params_byte);
+        claim_eq!(
+            contract_switch_role(&ctx, &mut host, &mut logger),
+            Err(Error::UserDeleted)
+        );
+
+        // the user itself can neither register nor apply for a role
+        ctx.set_invoker(deleted);
+        ctx.set_sender(Address::Account(deleted));
+        let params_byte = to_bytes(&Role::Curator);
+        ctx.set_parameter(&params_byte);
+        claim_eq!(
+            contract_apply_for_role(&ctx, &mut host),
+            Err(Error::UserDeleted)
+        );
+
+        // the projects contract can neither curate nor validate, even with curate_auto_create
+        ctx.set_sender(Address::Contract(project_contract_addr));
+        let params_byte = to_bytes(&CurateParams {
+            addr: deleted,
+            project_id: "TEST-PRJ".into(),
+        });
+        ctx.set_parameter(&params_byte);
+        claim_eq!(contract_curate(&ctx, &mut host), Err(Error::UserDeleted));
+        let params_byte = to_bytes(&ValidateParams {
+            addr: deleted,
+            project_id: "TEST-PRJ".into(),
+        });
+        ctx.set_parameter(&params_byte);
+        claim_eq!(contract_validate(&ctx, &mut host), Err(Error::UserDeleted));
+
+        let user_state = host.state().user.get(&deleted).unwrap();
+        claim!(user_state.deleted);
+        claim!(!user_state.is_curator);
+        claim!(!user_state.is_validator);
+        claim!(user_state.curated_projects.is_empty());
+        claim!(!host.state().pending_curators.contains(&deleted));
     }
 
     #[concordium_test]
-    /// Test that overlay-users.contract_view_user returns single user data.
-    fn test_contract_view_user_for_existing_user() {
+    /// Test that overlay-users.switch_role keeps the validated projects guard and the role cooldown.
+    fn test_contract_switch_role_with_active_projects_and_cooldown() {
         let admin = AccountAddress([0; 32]);
-        let existing_user = AccountAddress([1; 32]);
-        let validated_project_id: ProjectId = "TEST-PRJ".into();
+        let user_addr = AccountAddress([1; 32]);
+        let former_validator = AccountAddress([2; 32]);
         let mut ctx = TestReceiveContext::empty();
         ctx.set_invoker(admin);
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(10));
         // setup state
         let mut state_builder = TestStateBuilder::new();
         let mut user = state_builder.new_map();
         user.insert(
-            existing_user,
+            user_addr,
             UserState {
-                is_curator: false,
                 is_validator: true,
-                curated_projects: Vec::new(),
-                validated_projects: vec![validated_project_id.clone()],
+                validated_projects: vec![("TEST-PRJ".into(), ContractAddress::new(0, 0))],
+                ..UserState::default()
+            },
+        );
+        user.insert(
+            former_validator,
+            UserState {
+                validated_projects: vec![("TEST-PRJ".into(), ContractAddress::new(0, 0))],
+                ..UserState::default()
             },
         );
         let state = State {
-            admin,
-            project_contract_addr: ContractAddress::new(1, 2),
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
             user,
-            curator_list: vec![],
-            validator_list: vec![existing_user],
+            curator_list: Vec::new(),
+            validator_list: vec![user_addr],
+            role_cooldown: Duration::from_millis(100),
+            ..default_test_state(&mut state_builder)
         };
         let mut host = TestHost::new(state, state_builder);
+        let mut logger = TestLogger::init();
 
-        // create parameters
-        let params = AddrParams {
-            addr: existing_user,
-        };
-        let params_byte = to_bytes(&params);
+        for (force, expected) in [(false, Err(Error::HasActiveProjects)), (true, Ok(()))] {
+            let params_byte = to_bytes(&SwitchRoleParams {
+                addr: user_addr,
+                to_curator: true,
+                force,
+            });
+            ctx.set_parameter(&params_byte);
+            let result = contract_switch_role(&ctx, &mut host, &mut logger);
+            claim_eq!(result, expected);
+        }
+        let roles = host
+            .state()
+            .user
+            .get(&user_addr)
+            .map(|user_state| (user_state.is_curator, user_state.last_role_change));
+        claim_eq!(
+            roles,
+            Some((true, Some(Timestamp::from_timestamp_millis(10))))
+        );
+
+        // the validated projects do not block a user who is not a validator
+        let params_byte = to_bytes(&SwitchRoleParams {
+            addr: former_validator,
+            to_curator: true,
+            force: false,
+        });
         ctx.set_parameter(&params_byte);
+        let result = contract_switch_role(&ctx, &mut host, &mut logger);
+        claim_eq!(result, Ok(()));
 
-        // invoke method
-        let result = contract_view_user(&ctx, &mut host);
-        claim!(result.is_ok());
-        let view = result.unwrap();
-        claim!(!view.is_curator);
-        claim!(view.is_validator);
-        claim!(view.curated_projects.is_empty());
-        claim_eq!(view.validated_projects, vec![validated_project_id]);
+        // switching back within the cooldown is rejected
+        let params_byte = to_bytes(&SwitchRoleParams {
+            addr: user_addr,
+            to_curator: false,
+            force: false,
+        });
+        ctx.set_parameter(&params_byte);
+        let result = contract_switch_role(&ctx, &mut host, &mut logger);
+        claim_eq!(result, Err(Error::CooldownActive));
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(110));
+        let result = contract_switch_role(&ctx, &mut host, &mut logger);
+        claim_eq!(result, Ok(()));
     }
 
     #[concordium_test]
-    /// Test that overlay-users.contract_view_user returns default user data.
-    fn test_contract_view_user_for_non_existing_user() {
+    /// Test that the batch and correction functions also respect the role cooldown.
+    fn test_contract_role_cooldown_in_batch_functions() {
         let admin = AccountAddress([0; 32]);
-        let anyone = AccountAddress([100; 32]);
-        let existing_user = AccountAddress([1; 32]);
-        let non_existing_user = AccountAddress([2; 32]);
-        let validated_project_id: ProjectId = "TEST-PRJ".into();
+        let user_addr = AccountAddress([1; 32]);
         let mut ctx = TestReceiveContext::empty();
-        // anyone can call this contract function.
-        ctx.set_invoker(anyone);
+        ctx.set_invoker(admin);
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(50));
         // setup state
         let mut state_builder = TestStateBuilder::new();
         let mut user = state_builder.new_map();
         user.insert(
-            existing_user,
+            user_addr,
             UserState {
-                is_curator: false,
                 is_validator: true,
-                curated_projects: Vec::new(),
-                validated_projects: vec![validated_project_id],
+                last_role_change: Some(Timestamp::from_timestamp_millis(10)),
+                ..UserState::default()
             },
         );
         let state = State {
-            admin,
-            project_contract_addr: ContractAddress::new(1, 2),
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
             user,
-            curator_list: vec![],
-            validator_list: vec![existing_user],
+            curator_list: Vec::new(),
+            validator_list: vec![user_addr],
+            role_cooldown: Duration::from_millis(100),
+            ..default_test_state(&mut state_builder)
         };
         let mut host = TestHost::new(state, state_builder);
+        let mut logger = TestLogger::init();
 
-        // create parameters
-        let params = AddrParams {
-            addr: non_existing_user,
-        };
-        let params_byte = to_bytes(&params);
+        // within the cooldown
+        let batch_params_byte = to_bytes(&SetUserRolesBatchParams {
+            users: vec![UserRolesParams {
+                addr: user_addr,
+                is_curator: true,
+                is_validator: true,
+            }],
+            skip_admin: None,
+        });
+        ctx.set_parameter(&batch_params_byte);
+        claim_eq!(
+            contract_set_user_roles_batch(&ctx, &mut host, &mut logger).map(|_| ()),
+            Err(Error::CooldownActive)
+        );
+        let params_byte = to_bytes(&ImportCuratorsParams {
+            curators: vec![(user_addr, Vec::new())],
+            skip_admin: None,
+        });
         ctx.set_parameter(&params_byte);
+        claim_eq!(
+            contract_import_curators(&ctx, &mut host, &mut logger),
+            Err(Error::CooldownActive)
+        );
+        let params_byte = to_bytes(&ForceCurateParams {
+            addr: user_addr,
+            project_id: "TEST-PRJ".into(),
+            set_curator: true,
+        });
+        ctx.set_parameter(&params_byte);
+        claim_eq!(
+            contract_force_curate(&ctx, &mut host),
+            Err(Error::CooldownActive)
+        );
 
-        // invoke method
-        let result = contract_view_user(&ctx, &mut host);
-        claim!(result.is_ok());
-        let view = result.unwrap();
-        claim!(!view.is_curator);
-        claim!(!view.is_validator);
-        claim!(view.curated_projects.is_empty());
-        claim!(view.validated_projects.is_empty());
+        // after the cooldown, the change is recorded and starts a new cooldown
+        let now = Timestamp::from_timestamp_millis(110);
+        ctx.set_metadata_slot_time(now);
+        ctx.set_parameter(&batch_params_byte);
+        claim!(contract_set_user_roles_batch(&ctx, &mut host, &mut logger).is_ok());
+        let last_role_change = host
+            .state()
+            .user
+            .get(&user_addr)
+            .and_then(|user_state| user_state.last_role_change);
+        claim_eq!(last_role_change, Some(now));
     }
 
     #[concordium_test]
-    /// Test that overlay-users.contract_view_users returns all user data.
-    fn test_contract_view_users() {
+    /// Test that the pending applications are cleared by every grant and delete path and by dismiss_application.
+    fn test_contract_pending_applications_cleared() {
         let admin = AccountAddress([0; 32]);
-        let anyone = AccountAddress([100; 32]);
-        let existing_user1 = (
-            AccountAddress([1; 32]),
-            UserState {
-                is_curator: false,
-                is_validator: true,
-                curated_projects: Vec::new(),
-                validated_projects: vec!["TEST-PRJ1".into()],
-            },
+        let batch_user = AccountAddress([1; 32]);
+        let imported_user = AccountAddress([2; 32]);
+        let dismissed_user = AccountAddress([3; 32]);
+        let deleted_user = AccountAddress([4; 32]);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(10));
+        // setup state
+        let mut state_builder = TestStateBuilder::new();
+        let state = State {
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(state, state_builder);
+        let mut logger = TestLogger::init();
+
+        // apply for the roles
+        for (applicant, role) in [
+            (batch_user, Role::Curator),
+            (imported_user, Role::Curator),
+            (dismissed_user, Role::Curator),
+            (deleted_user, Role::Validator),
+        ] {
+            let params_byte = to_bytes(&role);
+            let mut apply_ctx = TestReceiveContext::empty();
+            apply_ctx.set_sender(Address::Account(applicant));
+            apply_ctx.set_parameter(&params_byte);
+            claim!(contract_apply_for_role(&apply_ctx, &mut host).is_ok());
+        }
+        claim_eq!(
+            contract_pending_count(&ctx, &host),
+            Ok(PendingCountResponse {
+                curators: 3,
+                validators: 1,
+            })
         );
-        let existing_user2 = (
-            AccountAddress([2; 32]),
-            UserState {
+
+        let params_byte = to_bytes(&SetUserRolesBatchParams {
+            users: vec![UserRolesParams {
+                addr: batch_user,
                 is_curator: true,
                 is_validator: false,
-                curated_projects: vec!["TEST-PRJ2".into()],
-                validated_projects: Vec::new(),
-            },
+            }],
+            skip_admin: None,
+        });
+        ctx.set_parameter(&params_byte);
+        claim!(contract_set_user_roles_batch(&ctx, &mut host, &mut logger).is_ok());
+
+        let params_byte = to_bytes(&ImportCuratorsParams {
+            curators: vec![(imported_user, Vec::new())],
+            skip_admin: None,
+        });
+        ctx.set_parameter(&params_byte);
+        claim!(contract_import_curators(&ctx, &mut host, &mut logger).is_ok());
+
+        let params_byte = to_bytes(&DismissApplicationParams {
+            addr: dismissed_user,
+            role: Role::Curator,
+        });
+        ctx.set_parameter(&params_byte);
+        claim_eq!(contract_dismiss_application(&ctx, &mut host), Ok(()));
+
+        let params_byte = to_bytes(&RegisterUserParams { addr: deleted_user });
+        ctx.set_parameter(&params_byte);
+        claim!(contract_register_user(&ctx, &mut host).is_ok());
+        claim!(contract_soft_delete_user(&ctx, &mut host).is_ok());
+
+        claim_eq!(
+            contract_pending_count(&ctx, &host),
+            Ok(PendingCountResponse {
+                curators: 0,
+                validators: 0,
+            })
         );
-        let mut ctx = TestReceiveContext::empty();
-        // anyone can call this contract function.
-        ctx.set_invoker(anyone);
-        // setup state
+        claim!(host.state().pending_curators.is_empty());
+        claim!(host.state().pending_validators.is_empty());
+
+        // only the admin can dismiss an application
+        let params_byte = to_bytes(&DismissApplicationParams {
+            addr: dismissed_user,
+            role: Role::Curator,
+        });
+        ctx.set_invoker(dismissed_user);
+        ctx.set_parameter(&params_byte);
+        claim_eq!(
+            contract_dismiss_application(&ctx, &mut host),
+            Err(Error::InvalidCaller)
+        );
+    }
+
+    #[concordium_test]
+    /// Test that the recovery account of the previous admin can not take over after overlay-users.transfer_admin.
+    fn test_contract_transfer_admin_clears_recovery_config() {
+        let admin = AccountAddress([0; 32]);
+        let recovery = AccountAddress([1; 32]);
+        let new_admin = AccountAddress([2; 32]);
+
         let mut state_builder = TestStateBuilder::new();
-        let mut user = state_builder.new_map();
-        user.insert(existing_user1.0, existing_user1.1.clone());
-        user.insert(existing_user2.0, existing_user2.1.clone());
         let state = State {
-            admin,
-            project_contract_addr: ContractAddress::new(1, 2),
-            user,
-            curator_list: vec![existing_user2.0],
-            validator_list: vec![existing_user1.0],
+            admin: Address::Account(admin),
+            project_contract_addr: ContractAddress::new(0, 0),
+            user: state_builder.new_map(),
+            curator_list: Vec::new(),
+            validator_list: Vec::new(),
+            ..default_test_state(&mut state_builder)
         };
         let mut host = TestHost::new(state, state_builder);
 
-        // invoke method
-        let result = contract_view_users(&ctx, &mut host);
+        let params_byte = to_bytes(&SetRecoveryConfigParams {
+            recovery_account: Some(recovery),
+            admin_inactivity_timeout: Duration::from_millis(100),
+        });
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(10));
+        ctx.set_parameter(&params_byte);
+        let result = contract_set_recovery_config(&ctx, &mut host);
         claim!(result.is_ok());
-        let view = result.unwrap();
-        claim_eq!(view.len(), 2);
-        for (addr, state) in view {
-            if addr == existing_user1.0 {
-                claim_eq!(state, existing_user1.1.clone());
-            } else if addr == existing_user2.0 {
-                claim_eq!(state, existing_user2.1.clone());
-            } else {
-                fail!("unexpected user address returned...");
-            }
-        }
+
+        let params_byte = to_bytes(&TransferAdminParams {
+            admin: Address::Account(new_admin),
+        });
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(20));
+        ctx.set_parameter(&params_byte);
+        let result = contract_transfer_admin(&ctx, &mut host);
+        claim!(result.is_ok());
+        claim_eq!(host.state().recovery_account, None);
+        claim_eq!(
+            host.state().admin_inactivity_timeout,
+            Duration::from_millis(0)
+        );
+        claim_eq!(host.state().last_admin_activity, None);
+
+        // the old recovery account can not take over from the new admin
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_sender(Address::Account(recovery));
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(1000));
+        let result = contract_inactive_admin_takeover(&ctx, &mut host);
+        claim_eq!(result, Err(Error::InvalidCaller));
+        claim_eq!(host.state().admin, Address::Account(new_admin));
     }
 }